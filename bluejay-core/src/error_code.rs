@@ -0,0 +1,7 @@
+/// A stable, machine-readable identifier for a diagnostic, of the form `CATEGORY/Kind`,
+/// e.g. `GRAPHQL_VALIDATION_FAILED/FieldsOnCorrectType`.
+///
+/// Codes do not change when error messages are reworded, so they are safe to match on.
+pub trait ErrorCode {
+    fn code(&self) -> &'static str;
+}
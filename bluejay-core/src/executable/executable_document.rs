@@ -12,7 +12,10 @@ pub trait ExecutableDocument {
     type Arguments<const CONST: bool>: Arguments<CONST, Argument = Self::Argument<CONST>>;
     type Directive<const CONST: bool>: Directive<CONST, Arguments = Self::Arguments<CONST>>;
     type Directives<const CONST: bool>: Directives<CONST, Directive = Self::Directive<CONST>>;
-    type FragmentSpread: FragmentSpread<Directives = Self::Directives<false>>;
+    type FragmentSpread: FragmentSpread<
+        Arguments = Self::Arguments<false>,
+        Directives = Self::Directives<false>,
+    >;
     type Field: Field<
         Arguments = Self::Arguments<false>,
         Directives = Self::Directives<false>,
@@ -45,6 +48,7 @@ pub trait ExecutableDocument {
         ImplicitOperationDefinition = Self::ImplicitOperationDefinition,
     >;
     type FragmentDefinition: FragmentDefinition<
+        VariableDefinitions = Self::VariableDefinitions,
         Directives = Self::Directives<false>,
         SelectionSet = Self::SelectionSet,
     >;
@@ -1,12 +1,17 @@
-use crate::executable::SelectionSet;
+use crate::executable::{SelectionSet, VariableDefinitions};
 use crate::{Indexable, VariableDirectives};
 
 pub trait FragmentDefinition: Indexable {
+    type VariableDefinitions: VariableDefinitions;
     type Directives: VariableDirectives;
     type SelectionSet: SelectionSet;
 
     fn description(&self) -> Option<&str>;
     fn name(&self) -> &str;
+    /// The variables of the fragment, from the fragment arguments proposal. Uses of these
+    /// variables within the fragment refer to the arguments of the spread instead of to the
+    /// variables of the operation.
+    fn variable_definitions(&self) -> Option<&Self::VariableDefinitions>;
     fn type_condition(&self) -> &str;
     fn directives(&self) -> Option<&Self::Directives>;
     fn selection_set(&self) -> &Self::SelectionSet;
@@ -1,8 +1,12 @@
-use crate::VariableDirectives;
+use crate::{VariableArguments, VariableDirectives};
 
 pub trait FragmentSpread {
+    type Arguments: VariableArguments;
     type Directives: VariableDirectives;
 
     fn name(&self) -> &str;
+    /// The values of the variables of the spread fragment, from the fragment arguments
+    /// proposal.
+    fn arguments(&self) -> Option<&Self::Arguments>;
     fn directives(&self) -> Option<&Self::Directives>;
 }
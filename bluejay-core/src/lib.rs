@@ -3,6 +3,7 @@ mod as_iter;
 mod builtin_scalar_definition;
 pub mod definition;
 mod directive;
+mod error_code;
 pub mod executable;
 mod indexable;
 mod operation_type;
@@ -16,6 +17,7 @@ pub use builtin_scalar_definition::BuiltinScalarDefinition;
 pub use directive::{
     ConstDirective, ConstDirectives, Directive, Directives, VariableDirective, VariableDirectives,
 };
+pub use error_code::ErrorCode;
 pub use indexable::{Indexable, Indexed};
 pub use operation_type::OperationType;
 pub use strum::IntoEnumIterator;
@@ -0,0 +1,472 @@
+use crate::join_all::join_all;
+use crate::{ExecutionError, Resolver, Response};
+use bluejay_core::definition::{
+    BaseOutputTypeReference, EnumTypeDefinition, EnumValueDefinition, FieldDefinition,
+    ObjectTypeDefinition, OutputType, OutputTypeReference, SchemaDefinition,
+};
+use bluejay_core::executable::{ExecutableDocument, Field, OperationDefinition};
+use bluejay_core::{AsIter, BuiltinScalarDefinition, OperationType};
+use bluejay_validator::executable::{
+    operation::{
+        execution_plan::{ExecutionPlan, FieldGroup, GroupedFieldSet},
+        OperationDefinitionValueEvaluationExt, VariableValues,
+    },
+    Cache,
+};
+use bluejay_validator::JsonPath;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+
+const TYPENAME_FIELD: &str = "__typename";
+
+type BoxFuture<'f, T> = Pin<Box<dyn Future<Output = T> + 'f>>;
+
+/// Executes the operation named `operation_name` of `executable_document`, which must be valid
+/// against `schema_definition`. `variable_values` should already be coerced, e.g. with
+/// [`CoerceVariableValues`](bluejay_validator::executable::operation::analyzers::CoerceVariableValues).
+///
+/// Execution is single-threaded: the fields of a selection set are resolved concurrently by
+/// polling their futures in turn, not in parallel, and the returned future is not [`Send`], so
+/// it must be awaited on the thread that created it, e.g. with a local executor. Subscription
+/// operations are not supported, and result in a response without data.
+pub async fn execute<
+    E: ExecutableDocument,
+    S: SchemaDefinition,
+    VV: VariableValues,
+    R: Resolver,
+>(
+    executable_document: &E,
+    schema_definition: &S,
+    operation_name: Option<&str>,
+    variable_values: &VV,
+    resolver: &R,
+    root_value: &JsonValue,
+) -> Response {
+    let cache = Cache::new(executable_document, schema_definition);
+    let execution_plan = ExecutionPlan::new(
+        executable_document,
+        schema_definition,
+        operation_name,
+        variable_values,
+        &cache,
+    );
+    match execution_plan {
+        Ok(execution_plan) => {
+            execute_plan(
+                schema_definition,
+                &execution_plan,
+                variable_values,
+                resolver,
+                root_value,
+            )
+            .await
+        }
+        Err(error) => Response {
+            data: None,
+            errors: vec![ExecutionError {
+                message: error.message().into_owned(),
+                path: JsonPath::default(),
+            }],
+        },
+    }
+}
+
+/// Executes an [`ExecutionPlan`] built with the same `variable_values`. The root fields of
+/// mutations are executed serially, and all other fields concurrently on the current thread,
+/// as with [`execute`].
+pub async fn execute_plan<
+    'a,
+    'p: 'a,
+    E: ExecutableDocument,
+    S: SchemaDefinition,
+    VV: VariableValues,
+    R: Resolver,
+>(
+    schema_definition: &'a S,
+    execution_plan: &'a ExecutionPlan<'p, E, S>,
+    variable_values: &'a VV,
+    resolver: &'a R,
+    root_value: &'a JsonValue,
+) -> Response {
+    let operation_definition = execution_plan.operation_definition();
+    let operation_type = operation_definition.as_ref().operation_type();
+    if operation_type == OperationType::Subscription {
+        return Response {
+            data: None,
+            errors: vec![ExecutionError {
+                message: "Subscription operations are not supported".to_owned(),
+                path: JsonPath::default(),
+            }],
+        };
+    }
+
+    let executor = Executor {
+        schema_definition,
+        operation_definition,
+        variable_values,
+        resolver,
+        errors: RefCell::new(Vec::new()),
+    };
+
+    let data = executor
+        .execute_grouped_field_set(
+            execution_plan.root_type(),
+            root_value,
+            execution_plan.fields(),
+            JsonPath::default(),
+            operation_type == OperationType::Mutation,
+        )
+        .await;
+
+    Response {
+        data: Some(data.map_or(JsonValue::Null, JsonValue::Object)),
+        errors: executor.errors.into_inner(),
+    }
+}
+
+struct Executor<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues, R: Resolver> {
+    schema_definition: &'a S,
+    operation_definition: &'a E::OperationDefinition,
+    variable_values: &'a VV,
+    resolver: &'a R,
+    errors: RefCell<Vec<ExecutionError>>,
+}
+
+/// The field whose value is being completed.
+struct FieldContext<'a, 'p, E: ExecutableDocument, S: SchemaDefinition> {
+    parent_type_name: &'a str,
+    field_group: &'a FieldGroup<'p, E, S>,
+}
+
+impl<E: ExecutableDocument, S: SchemaDefinition> Clone for FieldContext<'_, '_, E, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E: ExecutableDocument, S: SchemaDefinition> Copy for FieldContext<'_, '_, E, S> {}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues, R: Resolver>
+    Executor<'a, E, S, VV, R>
+{
+    /// Executes the fields of an object, resolving to `None` when a field error propagates to
+    /// the object, which must then be null.
+    fn execute_grouped_field_set<'f, 'p: 'a>(
+        &'f self,
+        object_type: &'a S::ObjectTypeDefinition,
+        object_value: &'f JsonValue,
+        grouped_field_set: &'a GroupedFieldSet<'p, E, S>,
+        path: JsonPath,
+        serial: bool,
+    ) -> BoxFuture<'f, Option<JsonMap<String, JsonValue>>>
+    where
+        'a: 'f,
+    {
+        Box::pin(async move {
+            let field_futures = grouped_field_set.iter().map(|field_group| {
+                let field_path = path.with(field_group.response_key());
+                self.execute_field(object_type, object_value, field_group, field_path)
+            });
+
+            let values = if serial {
+                let mut values = Vec::with_capacity(grouped_field_set.len());
+                for field_future in field_futures {
+                    values.push(field_future.await);
+                }
+                values
+            } else {
+                join_all(field_futures.collect()).await
+            };
+
+            grouped_field_set
+                .iter()
+                .zip(values)
+                .map(|(field_group, value)| {
+                    value.map(|value| (field_group.response_key().to_owned(), value))
+                })
+                .collect()
+        })
+    }
+
+    fn execute_field<'f, 'p: 'a>(
+        &'f self,
+        object_type: &'a S::ObjectTypeDefinition,
+        object_value: &'f JsonValue,
+        field_group: &'a FieldGroup<'p, E, S>,
+        path: JsonPath,
+    ) -> BoxFuture<'f, Option<JsonValue>>
+    where
+        'a: 'f,
+    {
+        Box::pin(async move {
+            let field_definition = field_group.field_definition();
+            let field_context = FieldContext {
+                parent_type_name: object_type.name(),
+                field_group,
+            };
+
+            let value = if field_definition.name() == TYPENAME_FIELD {
+                Ok(JsonValue::String(object_type.name().to_owned()))
+            } else {
+                match argument_values(
+                    self.schema_definition,
+                    self.operation_definition,
+                    field_group,
+                    self.variable_values,
+                ) {
+                    Ok(arguments) => self
+                        .resolver
+                        .resolve(
+                            object_type.name(),
+                            field_definition.name(),
+                            object_value,
+                            &arguments,
+                        )
+                        .await
+                        .map_err(|error| error.message().to_owned()),
+                    Err(message) => Err(message),
+                }
+            };
+
+            match value {
+                Ok(value) => {
+                    self.complete_value(field_definition.r#type(), field_context, value, path)
+                        .await
+                }
+                Err(message) => self.field_error(message, path, field_definition.r#type()),
+            }
+        })
+    }
+
+    /// Implements [`CompleteValue`](https://spec.graphql.org/October2021/#CompleteValue()),
+    /// resolving to `None` when the value is null but its type is non-null.
+    fn complete_value<'f, 'p: 'a>(
+        &'f self,
+        field_type: &'a <S::FieldDefinition as FieldDefinition>::OutputType,
+        field_context: FieldContext<'a, 'p, E, S>,
+        value: JsonValue,
+        path: JsonPath,
+    ) -> BoxFuture<'f, Option<JsonValue>>
+    where
+        'a: 'f,
+    {
+        Box::pin(async move {
+            if value.is_null() {
+                return if field_type.is_required() {
+                    let field_definition = field_context.field_group.field_definition();
+                    self.field_error(
+                        format!(
+                            "Cannot return null for non-nullable field {}.{}",
+                            field_context.parent_type_name,
+                            field_definition.name(),
+                        ),
+                        path,
+                        field_type,
+                    )
+                } else {
+                    Some(JsonValue::Null)
+                };
+            }
+
+            match field_type.as_ref(self.schema_definition) {
+                OutputTypeReference::List(inner_type, _) => {
+                    let JsonValue::Array(items) = value else {
+                        return self.field_error(
+                            format!("Expected a list for type {}", field_type.display_name()),
+                            path,
+                            field_type,
+                        );
+                    };
+                    let item_futures = items
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, item)| {
+                            self.complete_value(inner_type, field_context, item, path.with(index))
+                        })
+                        .collect();
+                    // an error in a non-null item has already been reported
+                    match join_all(item_futures)
+                        .await
+                        .into_iter()
+                        .collect::<Option<Vec<_>>>()
+                    {
+                        Some(items) => Some(JsonValue::Array(items)),
+                        None => Self::null_or_propagate(field_type),
+                    }
+                }
+                OutputTypeReference::Base(base_type, _) => match base_type {
+                    BaseOutputTypeReference::BuiltinScalar(builtin_scalar) => {
+                        match serialize_builtin_scalar(builtin_scalar, value) {
+                            Ok(value) => Some(value),
+                            Err(message) => self.field_error(message, path, field_type),
+                        }
+                    }
+                    BaseOutputTypeReference::CustomScalar(_) => Some(value),
+                    BaseOutputTypeReference::Enum(etd) => {
+                        let is_enum_value = value.as_str().is_some_and(|name| {
+                            etd.enum_value_definitions()
+                                .iter()
+                                .any(|evd| evd.name() == name)
+                        });
+                        if is_enum_value {
+                            Some(value)
+                        } else {
+                            self.field_error(
+                                format!("Enum {} cannot represent value: {value}", etd.name()),
+                                path,
+                                field_type,
+                            )
+                        }
+                    }
+                    BaseOutputTypeReference::Object(otd) => {
+                        self.complete_object(otd, field_type, field_context, &value, path)
+                            .await
+                    }
+                    BaseOutputTypeReference::Interface(_) | BaseOutputTypeReference::Union(_) => {
+                        // `ResolveAbstractType` using the `__typename` of the value
+                        let object_type = value
+                            .get(TYPENAME_FIELD)
+                            .and_then(JsonValue::as_str)
+                            .and_then(|type_name| {
+                                field_context.field_group.selection().and_then(|selection| {
+                                    selection
+                                        .object_types()
+                                        .find(|object_type| object_type.name() == type_name)
+                                })
+                            });
+                        match object_type {
+                            Some(object_type) => {
+                                self.complete_object(
+                                    object_type,
+                                    field_type,
+                                    field_context,
+                                    &value,
+                                    path,
+                                )
+                                .await
+                            }
+                            None => self.field_error(
+                                format!(
+                                    "Could not resolve the object type of abstract type {} from `{TYPENAME_FIELD}` of value: {value}",
+                                    base_type.name(),
+                                ),
+                                path,
+                                field_type,
+                            ),
+                        }
+                    }
+                },
+            }
+        })
+    }
+
+    async fn complete_object<'p: 'a>(
+        &self,
+        object_type: &'a S::ObjectTypeDefinition,
+        field_type: &'a <S::FieldDefinition as FieldDefinition>::OutputType,
+        field_context: FieldContext<'a, 'p, E, S>,
+        value: &JsonValue,
+        path: JsonPath,
+    ) -> Option<JsonValue> {
+        let Some(grouped_field_set) = field_context
+            .field_group
+            .selection()
+            .and_then(|selection| selection.for_type(object_type.name()))
+        else {
+            return self.field_error(
+                format!("No selection set for object type {}", object_type.name()),
+                path,
+                field_type,
+            );
+        };
+
+        match self
+            .execute_grouped_field_set(object_type, value, grouped_field_set, path, false)
+            .await
+        {
+            Some(fields) => Some(JsonValue::Object(fields)),
+            // the error of the field that propagated has already been reported
+            None => Self::null_or_propagate(field_type),
+        }
+    }
+
+    /// Reports a field error, and nulls the field or propagates the null to its parent.
+    fn field_error(
+        &self,
+        message: String,
+        path: JsonPath,
+        field_type: &<S::FieldDefinition as FieldDefinition>::OutputType,
+    ) -> Option<JsonValue> {
+        self.errors
+            .borrow_mut()
+            .push(ExecutionError { message, path });
+        Self::null_or_propagate(field_type)
+    }
+
+    fn null_or_propagate(
+        field_type: &<S::FieldDefinition as FieldDefinition>::OutputType,
+    ) -> Option<JsonValue> {
+        if field_type.is_required() {
+            None
+        } else {
+            Some(JsonValue::Null)
+        }
+    }
+}
+
+/// Coerces the argument values of the field group, joining the messages of any errors.
+pub(crate) fn argument_values<E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues>(
+    schema_definition: &S,
+    operation_definition: &E::OperationDefinition,
+    field_group: &FieldGroup<'_, E, S>,
+    variable_values: &VV,
+) -> Result<JsonMap<String, JsonValue>, String> {
+    operation_definition
+        .coerce_argument_values(
+            schema_definition,
+            field_group.field_definition().arguments_definition(),
+            field_group.field().arguments(),
+            variable_values,
+        )
+        .map(|argument_values| {
+            argument_values
+                .into_inner()
+                .into_iter()
+                .map(|(name, value)| (name.to_owned(), value.into()))
+                .collect()
+        })
+        .map_err(|errors| {
+            errors
+                .iter()
+                .map(|error| error.message())
+                .collect::<Vec<_>>()
+                .join("; ")
+        })
+}
+
+/// Implements result coercion of the built-in scalars.
+fn serialize_builtin_scalar(
+    builtin_scalar: BuiltinScalarDefinition,
+    value: JsonValue,
+) -> Result<JsonValue, String> {
+    let serialized = match builtin_scalar {
+        BuiltinScalarDefinition::Int => value
+            .as_i64()
+            .and_then(|int| i32::try_from(int).ok())
+            .map(JsonValue::from),
+        BuiltinScalarDefinition::Float => value.as_f64().map(JsonValue::from),
+        BuiltinScalarDefinition::String => value.is_string().then(|| value.clone()),
+        BuiltinScalarDefinition::Boolean => value.is_boolean().then(|| value.clone()),
+        BuiltinScalarDefinition::ID => match &value {
+            JsonValue::String(_) => Some(value.clone()),
+            JsonValue::Number(number) if number.is_i64() || number.is_u64() => {
+                Some(JsonValue::String(number.to_string()))
+            }
+            _ => None,
+        },
+    };
+
+    serialized.ok_or_else(|| format!("{} cannot represent value: {value}", builtin_scalar.name()))
+}
@@ -0,0 +1,56 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Polls all of the futures concurrently, resolving to their outputs in order once all are
+/// ready.
+pub(crate) fn join_all<F: Future + Unpin>(futures: Vec<F>) -> JoinAll<F> {
+    let outputs = futures.iter().map(|_| None).collect();
+    JoinAll {
+        futures: futures.into_iter().map(Some).collect(),
+        outputs,
+    }
+}
+
+pub(crate) struct JoinAll<F: Future + Unpin> {
+    futures: Vec<Option<F>>,
+    outputs: Vec<Option<F::Output>>,
+}
+
+// the outputs are never pinned, so only the futures need to be `Unpin`
+impl<F: Future + Unpin> Unpin for JoinAll<F> {}
+
+impl<F: Future + Unpin> Future for JoinAll<F> {
+    type Output = Vec<F::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let mut pending = false;
+
+        this.futures
+            .iter_mut()
+            .zip(this.outputs.iter_mut())
+            .for_each(|(future, output)| {
+                if let Some(inner) = future {
+                    match Pin::new(inner).poll(cx) {
+                        Poll::Ready(value) => {
+                            *output = Some(value);
+                            *future = None;
+                        }
+                        Poll::Pending => pending = true,
+                    }
+                }
+            });
+
+        if pending {
+            Poll::Pending
+        } else {
+            Poll::Ready(
+                this.outputs
+                    .iter_mut()
+                    .map(|output| output.take().expect("Polled after completion"))
+                    .collect(),
+            )
+        }
+    }
+}
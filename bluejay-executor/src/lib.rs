@@ -0,0 +1,10 @@
+mod executor;
+mod join_all;
+mod mock;
+mod resolver;
+mod response;
+
+pub use executor::{execute, execute_plan};
+pub use mock::{mock_response, MockGenerator, MockOptions, MockRng};
+pub use resolver::{FieldError, Resolver};
+pub use response::{ExecutionError, Response};
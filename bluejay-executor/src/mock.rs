@@ -0,0 +1,301 @@
+use crate::executor::argument_values;
+use crate::{ExecutionError, Response};
+use bluejay_core::definition::{
+    BaseOutputTypeReference, EnumTypeDefinition, EnumValueDefinition, FieldDefinition,
+    ObjectTypeDefinition, OutputType, OutputTypeReference, ScalarTypeDefinition, SchemaDefinition,
+};
+use bluejay_core::executable::ExecutableDocument;
+use bluejay_core::{AsIter, BuiltinScalarDefinition};
+use bluejay_validator::executable::{
+    operation::{
+        execution_plan::{ExecutionPlan, FieldGroup, GroupedFieldSet},
+        VariableValues,
+    },
+    Cache,
+};
+use bluejay_validator::JsonPath;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+
+const TYPENAME_FIELD: &str = "__typename";
+const LIST_LENGTH_ARGUMENT_NAMES: [&str; 2] = ["first", "last"];
+
+/// Provides the values of custom scalars and fields for [`mock_response`]. Returning `None`
+/// falls back to the default value for the type.
+pub trait MockGenerator {
+    fn custom_scalar(&self, _type_name: &str, _rng: &mut MockRng) -> Option<JsonValue> {
+        None
+    }
+
+    /// Provides the value of the field with the schema coordinate `type_name.field_name`, which
+    /// is used as-is, so a value of a composite type must contain every selected response key.
+    fn field(
+        &self,
+        _type_name: &str,
+        _field_name: &str,
+        _arguments: &JsonMap<String, JsonValue>,
+        _rng: &mut MockRng,
+    ) -> Option<JsonValue> {
+        None
+    }
+}
+
+impl MockGenerator for () {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MockOptions {
+    pub seed: u64,
+    /// The maximum length of generated lists. A smaller `first` or `last` argument of a field is
+    /// the maximum length of its lists instead, and of the lists of the fields selected on it,
+    /// e.g. `edges` and `nodes` of a connection.
+    pub max_list_length: usize,
+}
+
+impl Default for MockOptions {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            max_list_length: 3,
+        }
+    }
+}
+
+/// A [SplitMix64](https://prng.di.unimi.it/splitmix64.c) generator, implemented here so that
+/// the values generated for a seed do not change with the version of a dependency.
+#[derive(Debug, Clone)]
+pub struct MockRng(u64);
+
+impl MockRng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A value in `0..bound`, which must not be empty.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+
+    pub fn next_bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        (!items.is_empty()).then(|| &items[self.below(items.len() as u64) as usize])
+    }
+}
+
+/// Generates a response to the operation named `operation_name` of `executable_document`,
+/// which must be valid against `schema_definition`. The `data` contains exactly the selected
+/// response keys, with a possible type picked for every value of an abstract type, and is the
+/// same for the same [`MockOptions`].
+pub fn mock_response<
+    E: ExecutableDocument,
+    S: SchemaDefinition,
+    VV: VariableValues,
+    G: MockGenerator,
+>(
+    executable_document: &E,
+    schema_definition: &S,
+    operation_name: Option<&str>,
+    variable_values: &VV,
+    options: MockOptions,
+    generator: &G,
+) -> Response {
+    let cache = Cache::new(executable_document, schema_definition);
+    let execution_plan = match ExecutionPlan::new(
+        executable_document,
+        schema_definition,
+        operation_name,
+        variable_values,
+        &cache,
+    ) {
+        Ok(execution_plan) => execution_plan,
+        Err(error) => {
+            return Response {
+                data: None,
+                errors: vec![ExecutionError {
+                    message: error.message().into_owned(),
+                    path: JsonPath::default(),
+                }],
+            }
+        }
+    };
+
+    let mut mocker = Mocker {
+        schema_definition,
+        operation_definition: execution_plan.operation_definition(),
+        variable_values,
+        generator,
+        max_list_length: options.max_list_length,
+        rng: MockRng::new(options.seed),
+    };
+    let data = mocker.mock_object(execution_plan.root_type(), execution_plan.fields(), None);
+
+    Response {
+        data: Some(JsonValue::Object(data)),
+        errors: Vec::new(),
+    }
+}
+
+struct Mocker<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues, G: MockGenerator>
+{
+    schema_definition: &'a S,
+    operation_definition: &'a E::OperationDefinition,
+    variable_values: &'a VV,
+    generator: &'a G,
+    max_list_length: usize,
+    rng: MockRng,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues, G: MockGenerator>
+    Mocker<'a, E, S, VV, G>
+{
+    /// `list_length_limit` is the `first` or `last` argument of the parent field, if any.
+    fn mock_object(
+        &mut self,
+        object_type: &'a S::ObjectTypeDefinition,
+        grouped_field_set: &GroupedFieldSet<'a, E, S>,
+        list_length_limit: Option<usize>,
+    ) -> JsonMap<String, JsonValue> {
+        grouped_field_set
+            .iter()
+            .map(|field_group| {
+                let value = self.mock_field(object_type, field_group, list_length_limit);
+                (field_group.response_key().to_owned(), value)
+            })
+            .collect()
+    }
+
+    fn mock_field(
+        &mut self,
+        object_type: &'a S::ObjectTypeDefinition,
+        field_group: &FieldGroup<'a, E, S>,
+        list_length_limit: Option<usize>,
+    ) -> JsonValue {
+        let field_definition = field_group.field_definition();
+        if field_definition.name() == TYPENAME_FIELD {
+            return JsonValue::String(object_type.name().to_owned());
+        }
+
+        let arguments = argument_values(
+            self.schema_definition,
+            self.operation_definition,
+            field_group,
+            self.variable_values,
+        )
+        .unwrap_or_default();
+
+        if let Some(value) = self.generator.field(
+            object_type.name(),
+            field_definition.name(),
+            &arguments,
+            &mut self.rng,
+        ) {
+            return value;
+        }
+
+        let own_list_length_limit = LIST_LENGTH_ARGUMENT_NAMES
+            .iter()
+            .find_map(|name| arguments.get(*name).and_then(JsonValue::as_u64))
+            .map(|limit| limit as usize);
+
+        self.mock_value(
+            field_definition.r#type(),
+            field_group,
+            own_list_length_limit.or(list_length_limit),
+            own_list_length_limit,
+        )
+    }
+
+    fn mock_value(
+        &mut self,
+        field_type: &'a <S::FieldDefinition as FieldDefinition>::OutputType,
+        field_group: &FieldGroup<'a, E, S>,
+        list_length_limit: Option<usize>,
+        child_list_length_limit: Option<usize>,
+    ) -> JsonValue {
+        match field_type.as_ref(self.schema_definition) {
+            OutputTypeReference::List(inner_type, _) => {
+                let max_length = list_length_limit.map_or(self.max_list_length, |limit| {
+                    limit.min(self.max_list_length)
+                });
+                let length = self.rng.below((max_length as u64).saturating_add(1));
+                (0..length)
+                    .map(|_| {
+                        self.mock_value(
+                            inner_type,
+                            field_group,
+                            list_length_limit,
+                            child_list_length_limit,
+                        )
+                    })
+                    .collect()
+            }
+            OutputTypeReference::Base(base_type, _) => match base_type {
+                BaseOutputTypeReference::BuiltinScalar(builtin_scalar) => {
+                    self.mock_builtin_scalar(builtin_scalar, field_group)
+                }
+                BaseOutputTypeReference::CustomScalar(cstd) => self
+                    .generator
+                    .custom_scalar(cstd.name(), &mut self.rng)
+                    .unwrap_or_else(|| {
+                        JsonValue::String(format!("{} {}", cstd.name(), self.rng.below(1000)))
+                    }),
+                BaseOutputTypeReference::Enum(etd) => {
+                    let enum_value_definitions =
+                        etd.enum_value_definitions().iter().collect::<Vec<_>>();
+                    self.rng
+                        .choose(&enum_value_definitions)
+                        .map_or(JsonValue::Null, |evd| {
+                            JsonValue::String(evd.name().to_owned())
+                        })
+                }
+                BaseOutputTypeReference::Object(_)
+                | BaseOutputTypeReference::Interface(_)
+                | BaseOutputTypeReference::Union(_) => {
+                    let possible_types = field_group
+                        .selection()
+                        .map(|selection| selection.iter().collect::<Vec<_>>())
+                        .unwrap_or_default();
+                    match self.rng.choose(&possible_types) {
+                        Some((object_type, grouped_field_set)) => {
+                            JsonValue::Object(self.mock_object(
+                                object_type,
+                                grouped_field_set,
+                                child_list_length_limit,
+                            ))
+                        }
+                        None => JsonValue::Null,
+                    }
+                }
+            },
+        }
+    }
+
+    fn mock_builtin_scalar(
+        &mut self,
+        builtin_scalar: BuiltinScalarDefinition,
+        field_group: &FieldGroup<'a, E, S>,
+    ) -> JsonValue {
+        match builtin_scalar {
+            BuiltinScalarDefinition::Int => JsonValue::from(self.rng.below(1000)),
+            BuiltinScalarDefinition::Float => {
+                JsonValue::from(self.rng.below(100_000) as f64 / 100.0)
+            }
+            BuiltinScalarDefinition::String => JsonValue::String(format!(
+                "{} {}",
+                field_group.field_definition().name(),
+                self.rng.below(1000),
+            )),
+            BuiltinScalarDefinition::Boolean => JsonValue::Bool(self.rng.next_bool()),
+            BuiltinScalarDefinition::ID => JsonValue::String(self.rng.below(1_000_000).to_string()),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use std::future::Future;
+
+/// Resolves the value of a field, keyed by the name of the object type it is selected on and
+/// the name of the field.
+///
+/// The value of an object field is passed as `parent` to the resolvers of its selections. The
+/// value of a field of an interface or union type must be an object with a `__typename` entry
+/// naming the object type it resolves to.
+///
+/// The futures returned are not required to be [`Send`], as execution happens on a single
+/// thread.
+pub trait Resolver {
+    fn resolve<'a>(
+        &'a self,
+        type_name: &'a str,
+        field_name: &'a str,
+        parent: &'a JsonValue,
+        arguments: &'a JsonMap<String, JsonValue>,
+    ) -> impl Future<Output = Result<JsonValue, FieldError>> + 'a;
+}
+
+/// An error raised by a [`Resolver`], which nulls the field and is added to the errors of the
+/// response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    message: String,
+}
+
+impl FieldError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl From<String> for FieldError {
+    fn from(message: String) -> Self {
+        Self { message }
+    }
+}
+
+impl From<&str> for FieldError {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
@@ -0,0 +1,46 @@
+use bluejay_validator::JsonPath;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+
+/// The result of executing an operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    /// `None` when the operation could not be executed at all, e.g. because no operation with
+    /// the given name exists. `Some(JsonValue::Null)` when a field error propagated to the root.
+    pub data: Option<JsonValue>,
+    pub errors: Vec<ExecutionError>,
+}
+
+impl From<Response> for JsonValue {
+    fn from(value: Response) -> Self {
+        let mut response = JsonMap::new();
+        if let Some(data) = value.data {
+            response.insert("data".to_owned(), data);
+        }
+        if !value.errors.is_empty() {
+            response.insert(
+                "errors".to_owned(),
+                value.errors.into_iter().map(JsonValue::from).collect(),
+            );
+        }
+        Self::Object(response)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutionError {
+    pub message: String,
+    /// The response path of the field that raised the error, empty for errors raised before
+    /// execution.
+    pub path: JsonPath,
+}
+
+impl From<ExecutionError> for JsonValue {
+    fn from(value: ExecutionError) -> Self {
+        let mut error = JsonMap::new();
+        error.insert("message".to_owned(), value.message.into());
+        if !value.path.is_empty() {
+            error.insert("path".to_owned(), JsonValue::from(&value.path));
+        }
+        Self::Object(error)
+    }
+}
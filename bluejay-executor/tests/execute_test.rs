@@ -0,0 +1,309 @@
+use bluejay_executor::{execute, FieldError, Resolver};
+use bluejay_parser::ast::{
+    definition::{DefinitionDocument, SchemaDefinition},
+    executable::ExecutableDocument,
+    Parse,
+};
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::{pin, Pin};
+use std::task::{Context, Poll, Waker};
+
+const TEST_SCHEMA: &str = r#"
+    type Query {
+      node(id: ID!): Node
+      search(term: String!): [SearchResult!]
+      product(id: ID!): Product
+      products(first: Int = 2): [Product]!
+      version: Int!
+    }
+    type Mutation {
+      increment(by: Int!): Int!
+    }
+    type Subscription {
+      version: Int!
+    }
+    interface Node {
+      id: ID!
+    }
+    type Product implements Node {
+      id: ID!
+      title: String!
+      status: Status
+    }
+    type Collection implements Node {
+      id: ID!
+      products: [Product!]!
+    }
+    union SearchResult = Product | Collection
+    enum Status {
+      ACTIVE
+      ARCHIVED
+    }
+    schema {
+      query: Query
+      mutation: Mutation
+      subscription: Subscription
+    }
+"#;
+
+/// A future that is pending for the given number of polls, waking itself every time.
+struct Yield(usize);
+
+impl Future for Yield {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 == 0 {
+            Poll::Ready(())
+        } else {
+            self.0 -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+#[derive(Default)]
+struct TestResolver {
+    counter: RefCell<i64>,
+    log: RefCell<Vec<String>>,
+}
+
+impl Resolver for TestResolver {
+    async fn resolve<'a>(
+        &'a self,
+        type_name: &'a str,
+        field_name: &'a str,
+        parent: &'a JsonValue,
+        arguments: &'a JsonMap<String, JsonValue>,
+    ) -> Result<JsonValue, FieldError> {
+        match (type_name, field_name) {
+            ("Query", "node" | "product") => {
+                match arguments["id"].as_str().expect("id must be a string") {
+                    "1" => Ok(json!({ "__typename": "Product", "id": 1, "title": "Hat" })),
+                    "2" => Ok(json!({ "__typename": "Collection", "id": "2", "products": [] })),
+                    "3" => Ok(json!({ "__typename": "Shop", "id": "3" })),
+                    "4" => Ok(json!({ "__typename": "Product", "id": "4", "title": null })),
+                    _ => Err(FieldError::new("Not found")),
+                }
+            }
+            ("Query", "search") => Ok(json!([
+                { "__typename": "Product", "id": "1", "title": arguments["term"] },
+                { "__typename": "Collection", "id": "2", "products": [
+                    { "id": "3", "title": "Scarf", "status": "ACTIVE" },
+                ] },
+            ])),
+            ("Query", "products") => Ok((0..arguments["first"].as_i64().unwrap())
+                .map(|id| json!({ "id": id, "title": null, "status": "DELETED" }))
+                .collect()),
+            ("Query", "version") => Ok(json!(4_294_967_296_i64)),
+            ("Mutation", "increment") => {
+                let by = arguments["by"].as_i64().unwrap();
+                // earlier fields take longer, so concurrent execution would reorder them
+                Yield(by as usize * 3).await;
+                let mut counter = self.counter.borrow_mut();
+                *counter += by;
+                self.log.borrow_mut().push(format!("increment({by})"));
+                Ok(json!(*counter))
+            }
+            _ => Ok(parent.get(field_name).cloned().unwrap_or(JsonValue::Null)),
+        }
+    }
+}
+
+fn execute_with(query: &str, variables: JsonValue, resolver: &TestResolver) -> JsonValue {
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(TEST_SCHEMA)
+        .result
+        .expect("Schema had parse errors");
+    let schema_definition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+    let executable_document = ExecutableDocument::parse(query)
+        .result
+        .expect("Document had parse errors");
+    let variables = variables.as_object().expect("Variables must be an object");
+
+    block_on(execute(
+        &executable_document,
+        &schema_definition,
+        None,
+        variables,
+        resolver,
+        &JsonValue::Null,
+    ))
+    .into()
+}
+
+fn execute_query(query: &str, variables: JsonValue) -> JsonValue {
+    execute_with(query, variables, &TestResolver::default())
+}
+
+#[test]
+fn test_arguments_and_result_coercion() {
+    assert_eq!(
+        json!({ "data": {
+            "product": { "id": "1", "name": "Hat", "__typename": "Product" },
+            "products": [{ "id": "0" }, { "id": "1" }, { "id": "2" }],
+        } }),
+        execute_query(
+            r#"
+            query($first: Int) {
+              product(id: 1) { id name: title __typename }
+              products(first: $first) { id }
+            }
+            "#,
+            json!({ "first": 3 }),
+        ),
+    );
+
+    assert_eq!(
+        json!({ "data": { "products": [{ "id": "0" }, { "id": "1" }] } }),
+        execute_query("{ products { id } }", json!({})),
+    );
+}
+
+#[test]
+fn test_null_propagation() {
+    // a null non-null field nulls its nullable parent
+    assert_eq!(
+        json!({
+            "data": { "product": null },
+            "errors": [{
+                "message": "Cannot return null for non-nullable field Product.title",
+                "path": ["product", "title"],
+            }],
+        }),
+        execute_query(r#"{ product(id: "4") { id title } }"#, json!({})),
+    );
+
+    // a field error in a nullable list item only nulls the item
+    assert_eq!(
+        json!({
+            "data": { "products": [null, null] },
+            "errors": [
+                {
+                    "message": "Cannot return null for non-nullable field Product.title",
+                    "path": ["products", 0, "title"],
+                },
+                {
+                    "message": "Cannot return null for non-nullable field Product.title",
+                    "path": ["products", 1, "title"],
+                },
+            ],
+        }),
+        execute_query("{ products { id title } }", json!({})),
+    );
+
+    // a value that cannot be coerced to the field type is a field error
+    assert_eq!(
+        json!({
+            "data": { "products": [{ "status": null }] },
+            "errors": [{
+                "message": "Enum Status cannot represent value: \"DELETED\"",
+                "path": ["products", 0, "status"],
+            }],
+        }),
+        execute_query("{ products(first: 1) { status } }", json!({})),
+    );
+
+    // a null propagating from a non-null root field nulls the data
+    assert_eq!(
+        json!({
+            "data": null,
+            "errors": [{
+                "message": "Int cannot represent value: 4294967296",
+                "path": ["version"],
+            }],
+        }),
+        execute_query("{ version products(first: 1) { id } }", json!({})),
+    );
+}
+
+#[test]
+fn test_abstract_type_resolution() {
+    assert_eq!(
+        json!({ "data": {
+            "product": { "__typename": "Product", "id": "1", "title": "Hat" },
+            "collection": { "__typename": "Collection", "id": "2", "products": [] },
+            "search": [
+                { "title": "Shirt" },
+                { "products": [{ "title": "Scarf", "status": "ACTIVE" }] },
+            ],
+        } }),
+        execute_query(
+            r#"
+            query($term: String!) {
+              product: node(id: "1") { __typename id ... on Product { title } }
+              collection: node(id: "2") { __typename id ...CollectionProducts }
+              search(term: $term) {
+                ... on Product { title }
+                ... on Collection { products { title status } }
+              }
+            }
+            fragment CollectionProducts on Collection { products { id } }
+            "#,
+            json!({ "term": "Shirt" }),
+        ),
+    );
+
+    assert_eq!(
+        json!({
+            "data": { "node": null },
+            "errors": [{
+                "message": "Could not resolve the object type of abstract type Node from `__typename` of value: {\"__typename\":\"Shop\",\"id\":\"3\"}",
+                "path": ["node"],
+            }],
+        }),
+        execute_query(r#"{ node(id: "3") { id } }"#, json!({})),
+    );
+}
+
+#[test]
+fn test_resolver_errors() {
+    assert_eq!(
+        json!({
+            "data": { "found": { "id": "1" }, "missing": null },
+            "errors": [{ "message": "Not found", "path": ["missing"] }],
+        }),
+        execute_query(
+            r#"{ found: product(id: "1") { id } missing: product(id: "5") { id } }"#,
+            json!({}),
+        ),
+    );
+}
+
+#[test]
+fn test_mutation_fields_execute_serially() {
+    let resolver = TestResolver::default();
+    assert_eq!(
+        json!({ "data": { "first": 3, "second": 5, "third": 6 } }),
+        execute_with(
+            "mutation { first: increment(by: 3) second: increment(by: 2) third: increment(by: 1) }",
+            json!({}),
+            &resolver,
+        ),
+    );
+    assert_eq!(
+        vec!["increment(3)", "increment(2)", "increment(1)"],
+        resolver.log.into_inner(),
+    );
+}
+
+#[test]
+fn test_subscriptions_are_rejected() {
+    assert_eq!(
+        json!({ "errors": [{ "message": "Subscription operations are not supported" }] }),
+        execute_query("subscription { version }", json!({})),
+    );
+}
@@ -0,0 +1,202 @@
+use bluejay_executor::{mock_response, MockGenerator, MockOptions, MockRng};
+use bluejay_parser::ast::{
+    definition::{DefinitionDocument, SchemaDefinition},
+    executable::ExecutableDocument,
+    Parse,
+};
+use bluejay_validator::executable::{
+    operation::{execution_plan::ExecutionPlan, validate_response},
+    Cache,
+};
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+
+const TEST_SCHEMA: &str = r#"
+    scalar DateTime
+    type Query {
+      node(id: ID!): Node
+      search(term: String!): [SearchResult!]!
+      products(first: Int, last: Int): ProductConnection!
+      tags: [String!]!
+    }
+    interface Node {
+      id: ID!
+    }
+    type Product implements Node {
+      id: ID!
+      title: String!
+      price: Float!
+      inventory: Int!
+      available: Boolean!
+      status: Status!
+      createdAt: DateTime!
+      tags: [String!]!
+    }
+    type Collection implements Node {
+      id: ID!
+      products(first: Int): ProductConnection!
+    }
+    type ProductConnection {
+      nodes: [Product!]!
+      edges: [ProductEdge!]!
+    }
+    type ProductEdge {
+      node: Product!
+    }
+    union SearchResult = Product | Collection
+    enum Status {
+      ACTIVE
+      ARCHIVED
+    }
+    schema {
+      query: Query
+    }
+"#;
+
+const TEST_QUERY: &str = r#"
+    query($term: String!, $withTags: Boolean!) {
+      node(id: "1") { __typename id ... on Collection { products(first: 1) { nodes { id } } } }
+      search(term: $term) {
+        ... on Product { ...ProductFields }
+        ... on Collection { id }
+      }
+      products(first: 2) { edges { node { ...ProductFields } } }
+      tags @include(if: $withTags)
+    }
+    fragment ProductFields on Product {
+      id title price inventory available status createdAt tags
+    }
+"#;
+
+struct TestGenerator;
+
+impl MockGenerator for TestGenerator {
+    fn custom_scalar(&self, type_name: &str, rng: &mut MockRng) -> Option<JsonValue> {
+        (type_name == "DateTime")
+            .then(|| json!(format!("2024-01-{:02}T00:00:00Z", rng.below(28) + 1)))
+    }
+
+    fn field(
+        &self,
+        type_name: &str,
+        field_name: &str,
+        _arguments: &JsonMap<String, JsonValue>,
+        rng: &mut MockRng,
+    ) -> Option<JsonValue> {
+        match (type_name, field_name) {
+            ("Product", "title") => rng.choose(&["Hat", "Scarf", "Gloves"]).map(|t| json!(t)),
+            _ => None,
+        }
+    }
+}
+
+/// Generates a response to `query`, asserting that it is valid for the operation.
+fn mock_data<G: MockGenerator>(query: &str, seed: u64, generator: &G) -> JsonValue {
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(TEST_SCHEMA)
+        .result
+        .expect("Schema had parse errors");
+    let schema_definition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+    let executable_document = ExecutableDocument::parse(query)
+        .result
+        .expect("Document had parse errors");
+    let variables = json!({ "term": "hat", "withTags": false });
+    let variables = variables.as_object().unwrap();
+
+    let response = mock_response(
+        &executable_document,
+        &schema_definition,
+        None,
+        variables,
+        MockOptions {
+            seed,
+            ..Default::default()
+        },
+        generator,
+    );
+    assert!(response.errors.is_empty());
+    let data = response.data.expect("Response had no data");
+
+    let cache = Cache::new(&executable_document, &schema_definition);
+    let execution_plan = ExecutionPlan::new(
+        &executable_document,
+        &schema_definition,
+        None,
+        variables,
+        &cache,
+    )
+    .expect("Execution plan could not be built");
+    let errors = validate_response(&schema_definition, &execution_plan, &data)
+        .err()
+        .unwrap_or_default()
+        .iter()
+        .map(|error| error.message().into_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(Vec::<String>::new(), errors, "for data: {data}");
+
+    data
+}
+
+#[test]
+fn test_mock_response_is_valid_and_stable() {
+    let data = mock_data(TEST_QUERY, 7, &());
+    assert_eq!(data, mock_data(TEST_QUERY, 7, &()));
+    assert!(!data.as_object().unwrap().contains_key("tags"));
+
+    let responses = (0..20)
+        .map(|seed| mock_data(TEST_QUERY, seed, &()))
+        .collect::<Vec<_>>();
+    assert!(responses.iter().any(|response| *response != data));
+
+    // every possible type of an abstract type is picked for some seed
+    ["Product", "Collection"].iter().for_each(|typename| {
+        assert!(responses
+            .iter()
+            .any(|response| response["node"]["__typename"] == *typename));
+    });
+}
+
+#[test]
+fn test_list_lengths_are_limited_by_arguments() {
+    (0..20).for_each(|seed| {
+        let data = mock_data(
+            "{ products(first: 2) { edges { node { tags } } } none: products(last: 0) { nodes { id } } many: products(first: 2147483647) { nodes { id } } }",
+            seed,
+            &(),
+        );
+        assert!(data["products"]["edges"].as_array().unwrap().len() <= 2);
+        assert!(data["none"]["nodes"].as_array().unwrap().is_empty());
+        assert!(
+            data["many"]["nodes"].as_array().unwrap().len()
+                <= MockOptions::default().max_list_length
+        );
+        data["products"]["edges"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .for_each(|edge| {
+                assert!(
+                    edge["node"]["tags"].as_array().unwrap().len()
+                        <= MockOptions::default().max_list_length
+                );
+            });
+    });
+}
+
+#[test]
+fn test_custom_generators() {
+    (0..5).for_each(|seed| {
+        let data = mock_data(
+            "{ products(first: 3) { nodes { title createdAt } } }",
+            seed,
+            &TestGenerator,
+        );
+        data["products"]["nodes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .for_each(|node| {
+                assert!(["Hat", "Scarf", "Gloves"].contains(&node["title"].as_str().unwrap()));
+                assert!(node["createdAt"].as_str().unwrap().starts_with("2024-01-"));
+            });
+    });
+}
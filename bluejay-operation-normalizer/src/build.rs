@@ -18,13 +18,16 @@ use crate::ir::{
     NormalizedDirective, NormalizedField, NormalizedInlineFragment, NormalizedSelection,
 };
 
+const DEFER_DIRECTIVE_NAME: &str = "defer";
+
 /// Build and normalize a selection set in a single recursive pass (steps 2a–2e).
 ///
 /// For each selection in the set:
 /// - **Fields** (step 2a): collect name (alias dropped), sorted args, sorted directives,
 ///   and recursively build child selections.
 /// - **Fragment spreads** (step 2b): expand to inline fragments with the fragment's type
-///   condition, merging directives from both spread and definition.
+///   condition and the spread's sorted argument names, merging directives from both spread
+///   and definition.
 /// - **Inline fragments** (step 2c): flatten bare ones (no type condition, no directives)
 ///   into the parent; keep others as-is.
 ///
@@ -74,6 +77,7 @@ pub(crate) fn build_selections<'a, 'bump, E: ExecutableDocument + 'a>(
                     result.push(NormalizedSelection::InlineFragment(
                         NormalizedInlineFragment {
                             type_condition: Some(frag_def.type_condition()),
+                            arg_names: build_arg_names::<false, E>(spread.arguments(), bump),
                             directives,
                             selections,
                         },
@@ -93,6 +97,7 @@ pub(crate) fn build_selections<'a, 'bump, E: ExecutableDocument + 'a>(
                     result.push(NormalizedSelection::InlineFragment(
                         NormalizedInlineFragment {
                             type_condition: inline.type_condition(),
+                            arg_names: BVec::new_in(bump),
                             directives,
                             selections,
                         },
@@ -108,9 +113,9 @@ pub(crate) fn build_selections<'a, 'bump, E: ExecutableDocument + 'a>(
     result
 }
 
-/// Steps 2d–2e: merge inline fragments with matching `(type_condition, directives)`,
-/// then sort all selections (fields first by name, then inline fragments by type
-/// condition and directives).
+/// Steps 2d–2e: merge inline fragments with matching `(type_condition, arg_names, directives)`,
+/// except deferred ones, then sort all selections (fields first by name, then inline
+/// fragments by type condition, argument names, directives and selections).
 fn normalize_in_place(selections: &mut BVec<'_, NormalizedSelection<'_, '_>>) {
     let mut if_count = 0u32;
     for s in selections.iter() {
@@ -133,7 +138,12 @@ fn normalize_in_place(selections: &mut BVec<'_, NormalizedSelection<'_, '_>>) {
                         (
                             NormalizedSelection::InlineFragment(a),
                             NormalizedSelection::InlineFragment(b),
-                        ) => a.type_condition == b.type_condition && a.directives == b.directives,
+                        ) => {
+                            a.type_condition == b.type_condition
+                                && a.arg_names == b.arg_names
+                                && a.directives == b.directives
+                                && !a.is_deferred()
+                        }
                         _ => false,
                     };
                     if should_merge {
@@ -169,7 +179,8 @@ fn normalize_in_place(selections: &mut BVec<'_, NormalizedSelection<'_, '_>>) {
 }
 
 /// Sort order for step 2e: fields first (alphabetically by name), then inline
-/// fragments (by type condition, then by directives).
+/// fragments (by type condition, then by argument names, then by directives, then by their
+/// selections, which only differ for deferred fragments as the others are merged).
 fn cmp_selections(a: &NormalizedSelection<'_, '_>, b: &NormalizedSelection<'_, '_>) -> Ordering {
     match (a, b) {
         (NormalizedSelection::Field(af), NormalizedSelection::Field(bf)) => af
@@ -184,7 +195,31 @@ fn cmp_selections(a: &NormalizedSelection<'_, '_>, b: &NormalizedSelection<'_, '
         (NormalizedSelection::InlineFragment(ai), NormalizedSelection::InlineFragment(bi)) => ai
             .type_condition
             .cmp(&bi.type_condition)
-            .then_with(|| ai.directives.cmp(&bi.directives)),
+            .then_with(|| ai.arg_names.as_slice().cmp(bi.arg_names.as_slice()))
+            .then_with(|| ai.directives.cmp(&bi.directives))
+            .then_with(|| cmp_selection_sets(&ai.selections, &bi.selections)),
+    }
+}
+
+/// Compares normalized selection sets element by element, then by length.
+fn cmp_selection_sets(
+    a: &[NormalizedSelection<'_, '_>],
+    b: &[NormalizedSelection<'_, '_>],
+) -> Ordering {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| cmp_selections(a, b))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| a.len().cmp(&b.len()))
+}
+
+impl NormalizedInlineFragment<'_, '_> {
+    /// Whether the fragment is deferred with `@defer`, in which case it is delivered as its
+    /// own incremental payload and must not be merged with other fragments (step 2d).
+    fn is_deferred(&self) -> bool {
+        self.directives
+            .iter()
+            .any(|directive| directive.name == DEFER_DIRECTIVE_NAME)
     }
 }
 
@@ -51,16 +51,19 @@ pub(crate) struct NormalizedField<'a, 'bump> {
 
 /// An inline fragment produced by expanding a named fragment spread (step 2b)
 /// or kept from an existing inline fragment (step 2c). Inline fragments with
-/// matching `(type_condition, directives)` are merged (step 2d).
+/// matching `(type_condition, arg_names, directives)` are merged unless deferred (step 2d).
 pub(crate) struct NormalizedInlineFragment<'a, 'bump> {
     pub type_condition: Option<&'a str>,
+    /// Argument names of the expanded fragment spread sorted alphabetically, which is
+    /// empty for inline fragments. Values are erased.
+    pub arg_names: BVec<'bump, &'a str>,
     pub directives: BVec<'bump, NormalizedDirective<'a, 'bump>>,
     pub selections: BVec<'bump, NormalizedSelection<'a, 'bump>>,
 }
 
 /// A normalized selection: either a field or an inline fragment.
 /// Sorted with fields first (by name), then inline fragments (by type condition,
-/// then directives) — see step 2e.
+/// then argument names, then directives) — see step 2e.
 pub(crate) enum NormalizedSelection<'a, 'bump> {
     Field(NormalizedField<'a, 'bump>),
     InlineFragment(NormalizedInlineFragment<'a, 'bump>),
@@ -42,6 +42,16 @@
 //!       # normalized — spread replaced with inline fragment, named fragment dropped
 //!       query{...on User{name}}
 //!       ```
+//!       With fragment arguments, the argument names of the spread are kept after the type
+//!       condition, and the variable definitions of the fragment are dropped like those of
+//!       the operation.
+//!       ```graphql
+//!       # input
+//!       { ...F(size: 10) }
+//!       fragment F($size: Int) on User { avatar(size: $size) }
+//!       # normalized
+//!       query{...on User(size:){avatar(size:)}}
+//!       ```
 //!
 //!    c. **Inline fragments** — if bare (no type condition, no directives), flatten their
 //!       children directly into the parent selection set. Otherwise, keep as-is.
@@ -53,17 +63,26 @@
 //!       ```
 //!
 //!    d. **Merge inline fragments** — at each level, merge inline fragments that share the
-//!       same `(type_condition, directives)` pair into a single inline fragment, combining
-//!       their child selections.
+//!       same `(type_condition, argument names, directives)` into a single inline fragment,
+//!       combining their child selections.
 //!       ```graphql
 //!       # input — two inline fragments on same type
 //!       { ... on User { name } ... on User { email } }
 //!       # normalized — merged into one
 //!       query{...on User{email name}}
 //!       ```
+//!       Fragments deferred with `@defer` are never merged, as each one is delivered as a
+//!       separate incremental payload.
+//!       ```graphql
+//!       # input — two deferred inline fragments on same type
+//!       { ... on User @defer(label: "a") { name } ... on User @defer(label: "b") { email } }
+//!       # normalized — kept distinct
+//!       query{...on User@defer(label:){email} ...on User@defer(label:){name}}
+//!       ```
 //!
 //!    e. **Sort selections** — fields first (alphabetically by field name), then inline
-//!       fragments (by type condition, then by directives).
+//!       fragments (by type condition, then by argument names, then by directives, then by
+//!       their selections).
 //!       ```graphql
 //!       # input
 //!       { z a ... on User { b } m }
@@ -145,12 +164,24 @@ pub fn signature<E: ExecutableDocument>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use bluejay_parser::ast::{executable::ExecutableDocument as ParserDoc, Parse};
+    use bluejay_parser::ast::{executable::ExecutableDocument as ParserDoc, Parse, ParseOptions};
 
     fn parse(input: &str) -> ParserDoc {
         ParserDoc::parse(input).result.expect("parse error")
     }
 
+    fn parse_with_fragment_arguments(input: &str) -> ParserDoc {
+        ParserDoc::parse_with_options(
+            input,
+            ParseOptions {
+                fragment_arguments: true,
+                ..Default::default()
+            },
+        )
+        .result
+        .expect("parse error")
+    }
+
     // === Basic field sorting ===
 
     #[test]
@@ -335,6 +366,51 @@ mod tests {
 
     // === Sort order: fields first, then inline fragments ===
 
+    #[test]
+    fn deferred_inline_fragments_not_merged() {
+        let doc = parse(
+            r#"query { ... on T @defer(label: "a") { y } ... on T @defer(label: "b") { x } }"#,
+        );
+        assert_eq!(
+            normalize(&doc, None).unwrap(),
+            "query{...on T@defer(label:){x} ...on T@defer(label:){y}}"
+        );
+    }
+
+    #[test]
+    fn deferred_fragment_spreads_not_merged() {
+        let doc = parse(
+            "query { ...A @defer ...B @defer ... on T { z } }
+            fragment A on T { a }
+            fragment B on T { b }",
+        );
+        assert_eq!(
+            normalize(&doc, None).unwrap(),
+            "query{...on T{z} ...on T@defer{a} ...on T@defer{b}}"
+        );
+    }
+
+    #[test]
+    fn deferred_fragment_order_independent() {
+        let a = parse("query { ... @defer { x } ... @defer { y } }");
+        let b = parse("query { ... @defer { y } ... @defer { x } }");
+        assert_eq!(
+            normalize(&a, None).unwrap(),
+            "query{...@defer{x} ...@defer{y}}"
+        );
+        assert_eq!(signature(&a, None).unwrap(), signature(&b, None).unwrap());
+    }
+
+    #[test]
+    fn deferred_fragments_differ_from_merged_fragments() {
+        let deferred = parse("query { ... on T @defer { a } ... on T @defer { b } }");
+        let merged = parse("query { ... on T @defer { a b } }");
+        assert_ne!(
+            signature(&deferred, None).unwrap(),
+            signature(&merged, None).unwrap()
+        );
+    }
+
     #[test]
     fn selection_sort_order() {
         let doc = parse(
@@ -618,4 +694,42 @@ mod tests {
             "query{...on Admin{a} ...on Middle{m} ...on Zebra{z}}"
         );
     }
+
+    // === Fragment arguments ===
+
+    #[test]
+    fn fragment_arguments_names_kept_and_variable_definitions_dropped() {
+        let doc = parse_with_fragment_arguments(
+            "query { ...F(z: 1, a: $a) }
+            fragment F($a: Int, $z: Int = 2) on T { field(a: $a, z: $z) }",
+        );
+        assert_eq!(
+            normalize(&doc, None).unwrap(),
+            "query{...on T(a:,z:){field(a:,z:)}}"
+        );
+    }
+
+    #[test]
+    fn fragment_spreads_with_same_argument_names_merged() {
+        let doc = parse_with_fragment_arguments(
+            "query { ...F(size: 1) ...G(size: 2) ...F ... on T { c } }
+            fragment F($size: Int) on T { a }
+            fragment G($size: Int) on T { b }",
+        );
+        assert_eq!(
+            normalize(&doc, None).unwrap(),
+            "query{...on T{a c} ...on T(size:){a b}}"
+        );
+    }
+
+    #[test]
+    fn fragment_argument_values_same_hash() {
+        let a = parse_with_fragment_arguments(
+            "query { ...F(size: 1) } fragment F($size: Int) on T { a(size: $size) }",
+        );
+        let b = parse_with_fragment_arguments(
+            "query($s: Int) { ...F(size: $s) } fragment F($size: Int = 5) on T { a(size: $size) }",
+        );
+        assert_eq!(signature(&a, None).unwrap(), signature(&b, None).unwrap());
+    }
 }
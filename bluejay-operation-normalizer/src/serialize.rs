@@ -55,6 +55,7 @@ fn write_inline_fragment(out: &mut String, inf: &NormalizedInlineFragment<'_, '_
         out.push_str("on ");
         out.push_str(tc);
     }
+    write_arguments(out, &inf.arg_names);
     write_directives(out, &inf.directives);
     write_selection_set(out, &inf.selections);
 }
@@ -916,4 +916,30 @@ mod tests {
             builtin_types,
         );
     }
+
+    #[cfg(feature = "incremental-delivery")]
+    #[test]
+    fn builtin_incremental_delivery_directives_test() {
+        use bluejay_core::definition::DirectiveDefinition as CoreDirectiveDefinition;
+
+        let s = r#"
+        type Query {
+            foo: [String!]!
+        }
+        "#;
+
+        let document: DefinitionDocument = DefinitionDocument::parse(s)
+            .result
+            .expect("Document had parse errors");
+
+        let schema_definition = SchemaDefinition::try_from(&document)
+            .expect("Could not convert document to schema definition");
+
+        let directives: HashSet<&str> = schema_definition
+            .directive_definitions()
+            .filter_map(|dd| dd.is_builtin().then_some(dd.name()))
+            .collect();
+
+        assert!(HashSet::from(["defer", "stream"]).is_subset(&directives));
+    }
 }
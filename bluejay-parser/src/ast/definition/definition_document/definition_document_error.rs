@@ -8,7 +8,7 @@ use crate::HasSpan;
 use bluejay_core::definition::{
     DirectiveDefinition as CoreDirectiveDefinition, TypeDefinition as CoreTypeDefinition,
 };
-use bluejay_core::{Directive as _, OperationType};
+use bluejay_core::{Directive as _, ErrorCode, OperationType};
 
 #[derive(Debug)]
 pub enum DefinitionDocumentError<'a, C: Context> {
@@ -61,8 +61,54 @@ pub enum DefinitionDocumentError<'a, C: Context> {
     },
 }
 
+impl<C: Context> ErrorCode for DefinitionDocumentError<'_, C> {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::DuplicateDirectiveDefinitions { .. } => {
+                "GRAPHQL_SCHEMA_INVALID/UniqueDirectiveNames"
+            }
+            Self::DuplicateTypeDefinitions { .. } => "GRAPHQL_SCHEMA_INVALID/UniqueTypeNames",
+            Self::ImplicitRootOperationTypeNotAnObject { .. }
+            | Self::ExplicitRootOperationTypeNotAnObject { .. } => {
+                "GRAPHQL_SCHEMA_INVALID/RootOperationTypeNotAnObject"
+            }
+            Self::ImplicitSchemaDefinitionMissingQuery
+            | Self::ExplicitSchemaDefinitionMissingQuery { .. } => {
+                "GRAPHQL_SCHEMA_INVALID/QueryRootOperationTypeMissing"
+            }
+            Self::DuplicateExplicitSchemaDefinitions { .. } => {
+                "GRAPHQL_SCHEMA_INVALID/LoneSchemaDefinition"
+            }
+            Self::DuplicateExplicitRootOperationDefinitions { .. } => {
+                "GRAPHQL_SCHEMA_INVALID/UniqueOperationTypes"
+            }
+            Self::ExplicitRootOperationTypeDoesNotExist { .. } => {
+                "GRAPHQL_SCHEMA_INVALID/RootOperationTypeDoesNotExist"
+            }
+            Self::NoSchemaDefinition => "GRAPHQL_SCHEMA_INVALID/NoSchemaDefinition",
+            Self::ReferencedTypeDoesNotExist { .. } => "GRAPHQL_SCHEMA_INVALID/KnownTypeNames",
+            Self::ReferencedTypeIsNotAnOutputType { .. } => {
+                "GRAPHQL_SCHEMA_INVALID/ReferencedTypeIsNotAnOutputType"
+            }
+            Self::ReferencedTypeIsNotAnInputType { .. } => {
+                "GRAPHQL_SCHEMA_INVALID/ReferencedTypeIsNotAnInputType"
+            }
+            Self::ReferencedUnionMemberTypeIsNotAnObject { .. } => {
+                "GRAPHQL_SCHEMA_INVALID/ReferencedUnionMemberTypeIsNotAnObject"
+            }
+            Self::ReferencedTypeIsNotAnInterface { .. } => {
+                "GRAPHQL_SCHEMA_INVALID/ReferencedTypeIsNotAnInterface"
+            }
+            Self::ReferencedDirectiveDoesNotExist { .. } => {
+                "GRAPHQL_SCHEMA_INVALID/KnownDirectives"
+            }
+        }
+    }
+}
+
 impl<C: Context> From<DefinitionDocumentError<'_, C>> for Error {
     fn from(value: DefinitionDocumentError<C>) -> Self {
+        let code = value.code();
         match value {
             DefinitionDocumentError::DuplicateDirectiveDefinitions { name, definitions } => {
                 let message = if definitions
@@ -236,5 +282,6 @@ impl<C: Context> From<DefinitionDocumentError<'_, C>> for Error {
                 Vec::new(),
             ),
         }
+        .with_code(code)
     }
 }
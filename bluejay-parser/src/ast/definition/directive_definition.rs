@@ -9,14 +9,21 @@ use bluejay_core::AsIter;
 use std::str::FromStr;
 use strum::{EnumIter, IntoStaticStr};
 
+/// The directives defined by every schema. `@defer` and `@stream` are only defined with the
+/// `incremental-delivery` feature, so matches on this enum must not be exhaustive.
 #[derive(IntoStaticStr, EnumIter, Clone, Copy, Debug, PartialEq)]
 #[strum(serialize_all = "camelCase")]
+#[non_exhaustive]
 pub enum BuiltinDirectiveDefinition {
     Deprecated,
     Include,
     OneOf,
     Skip,
     SpecifiedBy,
+    #[cfg(feature = "incremental-delivery")]
+    Defer,
+    #[cfg(feature = "incremental-delivery")]
+    Stream,
 }
 
 impl BuiltinDirectiveDefinition {
@@ -27,6 +34,12 @@ impl BuiltinDirectiveDefinition {
     const DEPRECATED_DEFINITION: &'static str = "directive @deprecated(reason: String = \"No longer supported\") on FIELD_DEFINITION | ARGUMENT_DEFINITION | INPUT_FIELD_DEFINITION | ENUM_VALUE";
     const SPECIFIED_BY_DEFINITION: &'static str = "directive @specifiedBy(url: String!) on SCALAR";
     const ONE_OF_DEFINITION: &'static str = "directive @oneOf on INPUT_OBJECT";
+    #[cfg(feature = "incremental-delivery")]
+    const DEFER_DEFINITION: &'static str =
+        "directive @defer(label: String, if: Boolean! = true) on FRAGMENT_SPREAD | INLINE_FRAGMENT";
+    #[cfg(feature = "incremental-delivery")]
+    const STREAM_DEFINITION: &'static str =
+        "directive @stream(label: String, if: Boolean! = true, initialCount: Int = 0) on FIELD";
 
     fn definition(&self) -> &'static str {
         match self {
@@ -35,6 +48,10 @@ impl BuiltinDirectiveDefinition {
             Self::OneOf => Self::ONE_OF_DEFINITION,
             Self::Skip => Self::SKIP_DEFINITION,
             Self::SpecifiedBy => Self::SPECIFIED_BY_DEFINITION,
+            #[cfg(feature = "incremental-delivery")]
+            Self::Defer => Self::DEFER_DEFINITION,
+            #[cfg(feature = "incremental-delivery")]
+            Self::Stream => Self::STREAM_DEFINITION,
         }
     }
 }
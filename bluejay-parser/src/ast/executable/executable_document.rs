@@ -146,6 +146,7 @@ impl<'a> bluejay_core::executable::ExecutableDocument for ExecutableDocument<'a>
 mod tests {
     use super::{ExecutableDocument, Parse};
     use crate::ast::ParseOptions;
+    use bluejay_core::AsIter;
 
     /// Verifies that the ExecutableDefinitionsRule from the GraphQL spec
     /// (sec 5.1.1) is enforced at the parser level: type and schema definitions
@@ -231,6 +232,7 @@ mod tests {
                 graphql_ruby_compatibility: false,
                 max_depth: 1,
                 max_tokens: None,
+                fragment_arguments: false,
             },
         )
         .result
@@ -246,10 +248,47 @@ mod tests {
                 graphql_ruby_compatibility: false,
                 max_depth: 2,
                 max_tokens: None,
+                fragment_arguments: false,
             },
         )
         .result
         .unwrap();
         assert_eq!(1, executable_document.operation_definitions().len());
     }
+
+    #[test]
+    fn test_fragment_arguments() {
+        let document = r#"
+            query {
+                user {
+                    ...Avatar(size: 100)
+                    ...Avatar
+                }
+            }
+
+            fragment Avatar($size: Int = 50) on User {
+                avatar(size: $size)
+            }
+        "#;
+
+        assert!(ExecutableDocument::parse(document).result.is_err());
+
+        let executable_document = ExecutableDocument::parse_with_options(
+            document,
+            ParseOptions {
+                fragment_arguments: true,
+                ..Default::default()
+            },
+        )
+        .result
+        .unwrap();
+
+        let [fragment_definition] = executable_document.fragment_definitions() else {
+            panic!("Expected a single fragment definition");
+        };
+        let variable_definitions = fragment_definition
+            .variable_definitions()
+            .expect("Expected variable definitions");
+        assert_eq!(1, variable_definitions.iter().count());
+    }
 }
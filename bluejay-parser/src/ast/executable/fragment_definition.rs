@@ -1,4 +1,4 @@
-use crate::ast::executable::{SelectionSet, TypeCondition};
+use crate::ast::executable::{SelectionSet, TypeCondition, VariableDefinitions};
 use crate::ast::try_from_tokens::TryFromTokens;
 use crate::ast::{DepthLimiter, FromTokens, IsMatch, ParseError, Tokens, VariableDirectives};
 use crate::lexical_token::{Name, StringValue};
@@ -8,6 +8,7 @@ use crate::{HasSpan, Span};
 pub struct FragmentDefinition<'a> {
     description: Option<StringValue<'a>>,
     name: Name<'a>,
+    variable_definitions: Option<VariableDefinitions<'a>>,
     type_condition: TypeCondition<'a>,
     directives: Option<VariableDirectives<'a>>,
     selection_set: SelectionSet<'a>,
@@ -35,6 +36,11 @@ impl<'a> FromTokens<'a> for FragmentDefinition<'a> {
             // TODO: make this error message better
             return Err(ParseError::UnexpectedToken { span: name.into() });
         }
+        let variable_definitions = if tokens.fragment_arguments() {
+            VariableDefinitions::try_from_tokens(tokens, depth_limiter.bump()?)?
+        } else {
+            None
+        };
         let type_condition = TypeCondition::from_tokens(tokens, depth_limiter.bump()?)?;
         let directives = VariableDirectives::try_from_tokens(tokens, depth_limiter.bump()?)?;
         let selection_set = SelectionSet::from_tokens(tokens, depth_limiter.bump()?)?;
@@ -46,6 +52,7 @@ impl<'a> FromTokens<'a> for FragmentDefinition<'a> {
         Ok(Self {
             description,
             name,
+            variable_definitions,
             type_condition,
             directives,
             selection_set,
@@ -59,6 +66,10 @@ impl<'a> FragmentDefinition<'a> {
         &self.name
     }
 
+    pub fn variable_definitions(&self) -> Option<&VariableDefinitions<'a>> {
+        self.variable_definitions.as_ref()
+    }
+
     pub fn type_condition(&self) -> &TypeCondition<'a> {
         &self.type_condition
     }
@@ -77,6 +88,7 @@ impl bluejay_core::Indexable for FragmentDefinition<'_> {
 }
 
 impl<'a> bluejay_core::executable::FragmentDefinition for FragmentDefinition<'a> {
+    type VariableDefinitions = VariableDefinitions<'a>;
     type Directives = VariableDirectives<'a>;
     type SelectionSet = SelectionSet<'a>;
 
@@ -88,6 +100,10 @@ impl<'a> bluejay_core::executable::FragmentDefinition for FragmentDefinition<'a>
         self.name.as_ref()
     }
 
+    fn variable_definitions(&self) -> Option<&Self::VariableDefinitions> {
+        self.variable_definitions.as_ref()
+    }
+
     fn type_condition(&self) -> &str {
         self.type_condition.named_type().as_ref()
     }
@@ -1,12 +1,15 @@
 use crate::ast::executable::TypeCondition;
 use crate::ast::try_from_tokens::TryFromTokens;
-use crate::ast::{DepthLimiter, FromTokens, IsMatch, ParseError, Tokens, VariableDirectives};
+use crate::ast::{
+    DepthLimiter, FromTokens, IsMatch, ParseError, Tokens, VariableArguments, VariableDirectives,
+};
 use crate::lexical_token::{Name, PunctuatorType};
 use crate::{HasSpan, Span};
 
 #[derive(Debug)]
 pub struct FragmentSpread<'a> {
     name: Name<'a>,
+    arguments: Option<VariableArguments<'a>>,
     directives: Option<VariableDirectives<'a>>,
     span: Span,
 }
@@ -20,10 +23,16 @@ impl<'a> FromTokens<'a> for FragmentSpread<'a> {
         let ellipse_span = tokens.expect_punctuator(PunctuatorType::Ellipse)?;
         let name = tokens.expect_name()?;
         assert_ne!(TypeCondition::ON, name.as_ref());
+        let arguments = if tokens.fragment_arguments() {
+            VariableArguments::try_from_tokens(tokens, depth_limiter.bump()?)?
+        } else {
+            None
+        };
         let directives = VariableDirectives::try_from_tokens(tokens, depth_limiter.bump()?)?;
         let span = ellipse_span.merge(name.span());
         Ok(Self {
             name,
+            arguments,
             directives,
             span,
         })
@@ -45,15 +54,24 @@ impl<'a> FragmentSpread<'a> {
     pub fn name(&self) -> &Name<'a> {
         &self.name
     }
+
+    pub fn arguments(&self) -> Option<&VariableArguments<'a>> {
+        self.arguments.as_ref()
+    }
 }
 
 impl<'a> bluejay_core::executable::FragmentSpread for FragmentSpread<'a> {
+    type Arguments = VariableArguments<'a>;
     type Directives = VariableDirectives<'a>;
 
     fn name(&self) -> &str {
         self.name.as_ref()
     }
 
+    fn arguments(&self) -> Option<&Self::Arguments> {
+        self.arguments.as_ref()
+    }
+
     fn directives(&self) -> Option<&Self::Directives> {
         self.directives.as_ref()
     }
@@ -22,6 +22,10 @@ pub struct ParseOptions {
     pub graphql_ruby_compatibility: bool,
     pub max_depth: usize,
     pub max_tokens: Option<usize>,
+    /// Parses variable definitions on fragment definitions and arguments on fragment spreads,
+    /// e.g. `fragment Avatar($size: Int = 50) on User` and `...Avatar(size: 100)`, from the
+    /// fragment arguments proposal.
+    pub fragment_arguments: bool,
 }
 
 impl Default for ParseOptions {
@@ -30,6 +34,7 @@ impl Default for ParseOptions {
             graphql_ruby_compatibility: false,
             max_depth: DEFAULT_MAX_DEPTH,
             max_tokens: None,
+            fragment_arguments: false,
         }
     }
 }
@@ -45,7 +50,7 @@ pub trait Parse<'a>: Sized {
         let lexer = LogosLexer::new(s)
             .with_graphql_ruby_compatibility(options.graphql_ruby_compatibility)
             .with_max_tokens(options.max_tokens);
-        let tokens = LexerTokens::new(lexer);
+        let tokens = LexerTokens::new(lexer).with_fragment_arguments(options.fragment_arguments);
 
         Self::parse_from_tokens(tokens, options.max_depth)
     }
@@ -1,5 +1,6 @@
 use crate::error::{Annotation, Error};
 use crate::Span;
+use bluejay_core::ErrorCode;
 
 #[derive(Debug)]
 pub enum ParseError {
@@ -32,8 +33,25 @@ pub enum ParseError {
     },
 }
 
+impl ErrorCode for ParseError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidEnumValue { .. } => "GRAPHQL_PARSE_FAILED/InvalidEnumValue",
+            Self::ExpectedOneOf { .. } => "GRAPHQL_PARSE_FAILED/ExpectedOneOf",
+            Self::ExpectedIdentifier { .. } => "GRAPHQL_PARSE_FAILED/ExpectedIdentifier",
+            Self::ExpectedName { .. } => "GRAPHQL_PARSE_FAILED/ExpectedName",
+            Self::UnexpectedEOF { .. } => "GRAPHQL_PARSE_FAILED/UnexpectedEOF",
+            Self::UnexpectedToken { .. } => "GRAPHQL_PARSE_FAILED/UnexpectedToken",
+            Self::EmptyDocument => "GRAPHQL_PARSE_FAILED/EmptyDocument",
+            Self::MaxDepthExceeded => "GRAPHQL_PARSE_FAILED/MaxDepthExceeded",
+            Self::MaxTokensExceeded { .. } => "GRAPHQL_PARSE_FAILED/MaxTokensExceeded",
+        }
+    }
+}
+
 impl From<ParseError> for Error {
     fn from(val: ParseError) -> Self {
+        let code = val.code();
         match val {
             ParseError::InvalidEnumValue { span, value } => Self::new(
                 "Parse error",
@@ -86,5 +104,6 @@ impl From<ParseError> for Error {
                 Vec::new(),
             ),
         }
+        .with_code(code)
     }
 }
@@ -26,12 +26,16 @@ pub trait Tokens<'a>: Iterator<Item = LexicalToken<'a>> {
     fn peek_punctuator_matches(&mut self, n: usize, punctuator_type: PunctuatorType) -> bool;
     fn into_errors(self) -> Vec<(LexError, Span)>;
     fn token_count(&self) -> usize;
+    /// Whether variable definitions on fragment definitions and arguments on fragment spreads
+    /// are parsed, see [`crate::ast::ParseOptions::fragment_arguments`].
+    fn fragment_arguments(&self) -> bool;
 }
 
 pub struct LexerTokens<'a, T: Lexer<'a>> {
     lexer: T,
     errors: Vec<(LexError, Span)>,
     buffer: VecDeque<LexicalToken<'a>>,
+    fragment_arguments: bool,
 }
 
 impl<'a, T: Lexer<'a>> LexerTokens<'a, T> {
@@ -41,9 +45,15 @@ impl<'a, T: Lexer<'a>> LexerTokens<'a, T> {
             lexer,
             errors: Vec::new(),
             buffer: VecDeque::new(),
+            fragment_arguments: false,
         }
     }
 
+    pub fn with_fragment_arguments(mut self, enabled: bool) -> Self {
+        self.fragment_arguments = enabled;
+        self
+    }
+
     #[inline]
     pub fn token_count(&self) -> usize {
         self.lexer.token_count()
@@ -337,4 +347,9 @@ impl<'a, T: Lexer<'a>> Tokens<'a> for LexerTokens<'a, T> {
     fn token_count(&self) -> usize {
         self.token_count()
     }
+
+    #[inline]
+    fn fragment_arguments(&self) -> bool {
+        self.fragment_arguments
+    }
 }
@@ -16,6 +16,7 @@ pub struct Error {
     message: Cow<'static, str>,
     primary_annotation: Option<Annotation>,
     secondary_annotations: Vec<Annotation>,
+    code: Option<&'static str>,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -31,6 +32,31 @@ pub struct Location {
 pub struct GraphQLError {
     pub message: Cow<'static, str>,
     pub locations: Vec<Location>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub extensions: Option<GraphQLErrorExtensions>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct GraphQLErrorExtensions {
+    pub code: Cow<'static, str>,
+}
+
+impl GraphQLError {
+    fn new(
+        message: Cow<'static, str>,
+        locations: Vec<Location>,
+        code: Option<&'static str>,
+    ) -> Self {
+        Self {
+            message,
+            locations,
+            extensions: code.map(|code| GraphQLErrorExtensions { code: code.into() }),
+        }
+    }
 }
 
 impl Error {
@@ -43,9 +69,16 @@ impl Error {
             message: message.into(),
             primary_annotation,
             secondary_annotations,
+            code: None,
         }
     }
 
+    /// Attaches a stable, machine-readable code to the error, see [`bluejay_core::ErrorCode`].
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.code = Some(code);
+        self
+    }
+
     pub fn into_graphql_errors<E: Into<Error>>(
         document: &str,
         errors: impl IntoIterator<Item = E>,
@@ -55,33 +88,38 @@ impl Error {
             .into_iter()
             .flat_map(|err| {
                 let err: Error = err.into();
+                let code = err.code;
                 if let Some(primary_annotation) = err.primary_annotation {
                     let (line, col) = converter
                         .borrow_mut()
                         .convert(primary_annotation.span())
                         .unwrap_or((0, 0));
-                    Either::Left(std::iter::once(GraphQLError {
-                        message: primary_annotation.message,
-                        locations: vec![Location { line, col }],
-                    }))
+                    Either::Left(std::iter::once(GraphQLError::new(
+                        primary_annotation.message,
+                        vec![Location { line, col }],
+                        code,
+                    )))
                 } else if !err.secondary_annotations.is_empty() {
+                    let converter = &converter;
                     Either::Right(err.secondary_annotations.into_iter().map(
-                        |secondary_annotation| {
+                        move |secondary_annotation| {
                             let (line, col) = converter
                                 .borrow_mut()
                                 .convert(secondary_annotation.span())
                                 .unwrap_or((0, 0));
-                            GraphQLError {
-                                message: secondary_annotation.message,
-                                locations: vec![Location { line, col }],
-                            }
+                            GraphQLError::new(
+                                secondary_annotation.message,
+                                vec![Location { line, col }],
+                                code,
+                            )
                         },
                     ))
                 } else {
-                    Either::Left(std::iter::once(GraphQLError {
-                        message: err.message,
-                        locations: vec![],
-                    }))
+                    Either::Left(std::iter::once(GraphQLError::new(
+                        err.message,
+                        vec![],
+                        code,
+                    )))
                 }
             })
             .collect()
@@ -148,4 +186,8 @@ impl Error {
     pub fn message(&self) -> &str {
         self.message.as_ref()
     }
+
+    pub fn code(&self) -> Option<&'static str> {
+        self.code
+    }
 }
@@ -1,5 +1,6 @@
 use crate::error::{Annotation, Error};
 use crate::Span;
+use bluejay_core::ErrorCode;
 
 #[derive(Debug, PartialEq, Clone, Default)]
 pub enum LexError {
@@ -19,6 +20,18 @@ impl From<Vec<StringValueLexError>> for LexError {
     }
 }
 
+impl ErrorCode for LexError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::UnrecognizedToken => "GRAPHQL_PARSE_FAILED/UnrecognizedToken",
+            Self::IntegerValueTooLarge => "GRAPHQL_PARSE_FAILED/IntegerValueTooLarge",
+            Self::FloatValueTooLarge => "GRAPHQL_PARSE_FAILED/FloatValueTooLarge",
+            Self::StringValueInvalid(_) => "GRAPHQL_PARSE_FAILED/StringValueInvalid",
+            Self::MaxTokensExceeded { .. } => "GRAPHQL_PARSE_FAILED/MaxTokensExceeded",
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum StringValueLexError {
     InvalidUnicodeEscapeSequence(Span),
@@ -27,6 +40,7 @@ pub enum StringValueLexError {
 
 impl From<(LexError, Span)> for Error {
     fn from((error, span): (LexError, Span)) -> Self {
+        let code = error.code();
         match error {
             LexError::UnrecognizedToken => Self::new(
                 "Unrecognized token",
@@ -70,5 +84,6 @@ impl From<(LexError, Span)> for Error {
                 Vec::new(),
             ),
         }
+        .with_code(code)
     }
 }
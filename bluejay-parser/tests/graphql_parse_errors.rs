@@ -19,6 +19,9 @@ fn test_parser_errors() {
     let expected: Vec<GraphQLError> = vec![GraphQLError {
         message: Cow::from("Expected a name"),
         locations: vec![Location { line: 2, col: 15 }],
+        extensions: Some(bluejay_parser::error::GraphQLErrorExtensions {
+            code: Cow::from("GRAPHQL_PARSE_FAILED/ExpectedName"),
+        }),
     }];
     assert_eq!(expected, graphql_errors);
 }
@@ -1,4 +1,4 @@
-use crate::executable::SelectionSetPrinter;
+use crate::executable::{SelectionSetPrinter, VariableDefinitionsPrinter};
 use bluejay_core::executable::FragmentDefinition;
 use std::fmt::{Display, Formatter, Result};
 
@@ -19,10 +19,17 @@ impl<T: FragmentDefinition> Display for FragmentDefinitionPrinter<'_, T> {
         let Self {
             fragment_definition,
         } = *self;
+        write!(f, "fragment {}", fragment_definition.name())?;
+        if let Some(variable_definitions) = fragment_definition.variable_definitions() {
+            write!(
+                f,
+                "{}",
+                VariableDefinitionsPrinter::new(variable_definitions)
+            )?;
+        }
         write!(
             f,
-            "fragment {} on {} {}",
-            fragment_definition.name(),
+            " on {} {}",
             fragment_definition.type_condition(),
             SelectionSetPrinter::new(fragment_definition.selection_set(), 0),
         )
@@ -1,7 +1,7 @@
 use bluejay_core::executable::FragmentSpread;
 use std::fmt::{Display, Formatter, Result};
 
-use crate::{directive::DirectivesPrinter, write_indent};
+use crate::{argument::ArgumentsPrinter, directive::DirectivesPrinter, write_indent};
 
 pub(crate) struct FragmentSpreadPrinter<'a, T: FragmentSpread> {
     fragment_spread: &'a T,
@@ -25,6 +25,9 @@ impl<T: FragmentSpread> Display for FragmentSpreadPrinter<'_, T> {
         } = *self;
         write_indent(f, indentation)?;
         write!(f, "...{}", fragment_spread.name())?;
+        if let Some(arguments) = fragment_spread.arguments() {
+            write!(f, "{}", ArgumentsPrinter::new(arguments))?;
+        }
         if let Some(directives) = fragment_spread.directives() {
             write!(f, "{}", DirectivesPrinter::new(directives))?;
         };
@@ -1,7 +1,7 @@
 use bluejay_parser::ast::{
     definition::{DefinitionDocument, SchemaDefinition},
     executable::ExecutableDocument,
-    Parse,
+    Parse, ParseOptions,
 };
 use bluejay_printer::{definition::SchemaDefinitionPrinter, executable::ExecutableDocumentPrinter};
 use similar_asserts::assert_eq;
@@ -39,3 +39,21 @@ fn test_executable_printer() {
         assert_eq!(input, printed);
     });
 }
+
+#[test]
+fn test_executable_printer_with_fragment_arguments() {
+    insta::glob!("test_data/fragment_arguments/*.graphql", |path| {
+        let input = std::fs::read_to_string(path).unwrap();
+        let executable_document = ExecutableDocument::parse_with_options(
+            input.as_str(),
+            ParseOptions {
+                fragment_arguments: true,
+                ..Default::default()
+            },
+        )
+        .result
+        .unwrap_or_else(|_| panic!("Document `{}` had parse errors", path.display()));
+        let printed = ExecutableDocumentPrinter::to_string(&executable_document);
+        assert_eq!(input, printed);
+    });
+}
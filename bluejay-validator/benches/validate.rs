@@ -3,8 +3,16 @@ use bluejay_parser::ast::{
     executable::ExecutableDocument,
     Parse,
 };
-use bluejay_validator::executable::{document::BuiltinRulesValidator, Cache};
+use bluejay_validator::executable::{
+    document::{BatchValidator, BuiltinRulesValidator},
+    operation::{
+        analyzers::{ComplexityCost, Deprecation, QueryDepth},
+        Orchestrator,
+    },
+    Cache, Pipeline,
+};
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use serde_json::{Map, Value};
 use std::sync::LazyLock;
 
 const SCHEMA: &str = include_str!("../tests/test_data/executable/schema.graphql");
@@ -182,5 +190,98 @@ fn bench_cache_construction(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_validate, bench_cache_construction);
+fn bench_batch_validate(c: &mut Criterion) {
+    const BATCH_SIZE: usize = 1_000;
+
+    let mut group = c.benchmark_group("batch_validate");
+    let docs: Vec<ExecutableDocument> = CASES
+        .iter()
+        .cycle()
+        .take(BATCH_SIZE)
+        .map(|case| {
+            ExecutableDocument::parse(case.source)
+                .result
+                .expect("Document had parse errors")
+        })
+        .collect();
+
+    group.bench_function("sequential", |b| {
+        b.iter(|| {
+            docs.iter().for_each(|doc| {
+                let cache = Cache::new(doc, &*SCHEMA_DEFINITION);
+                assert_eq!(
+                    0,
+                    BuiltinRulesValidator::validate(doc, &*SCHEMA_DEFINITION, &cache).count()
+                );
+            });
+        });
+    });
+
+    let batch_validator = BatchValidator::new(&*SCHEMA_DEFINITION);
+    group.bench_function("batch", |b| {
+        b.iter(|| {
+            let results = batch_validator.validate(&docs, |_, report| report.is_valid());
+            assert!(results.into_iter().all(std::convert::identity));
+        });
+    });
+    group.finish();
+}
+
+type Analyzers<'a, E, S> = (
+    QueryDepth,
+    ComplexityCost<'a, E, S, Map<String, Value>>,
+    Deprecation<'a, E, S, Map<String, Value>>,
+);
+
+// The separate variant walks the document twice, once to validate and once to analyze, while the
+// pipeline analyzes the operation in the walk that validates the document and reuses its schema
+// cache across documents.
+fn bench_validate_and_analyze(c: &mut Criterion) {
+    let mut group = c.benchmark_group("validate_and_analyze");
+    let variables = serde_json::json!({ "command": "SIT", "atOtherHomes": true });
+    let variables = variables.as_object().unwrap();
+    for case in CASES {
+        let doc = ExecutableDocument::parse(case.source)
+            .result
+            .expect("Document had parse errors");
+        group.bench_with_input(BenchmarkId::new("separate", case.name), &doc, |b, doc| {
+            b.iter(|| {
+                let cache = Cache::new(doc, &*SCHEMA_DEFINITION);
+                assert_eq!(
+                    0,
+                    BuiltinRulesValidator::validate(doc, &*SCHEMA_DEFINITION, &cache).count()
+                );
+                Orchestrator::<_, _, _, Analyzers<_, _>>::analyze(
+                    doc,
+                    &*SCHEMA_DEFINITION,
+                    None,
+                    variables,
+                    &cache,
+                    ((), (), ()),
+                )
+                .unwrap();
+            });
+        });
+
+        let pipeline = Pipeline::new(&*SCHEMA_DEFINITION);
+        group.bench_with_input(BenchmarkId::new("pipeline", case.name), &doc, |b, doc| {
+            b.iter(|| {
+                assert!(pipeline.run(doc, |run| {
+                    run.analyze::<_, Analyzers<_, _>>(None, variables, ((), (), ()))
+                        .output()
+                        .is_some()
+                }));
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_validate,
+    bench_cache_construction,
+    bench_batch_validate,
+    bench_validate_and_analyze
+);
 criterion_main!(benches);
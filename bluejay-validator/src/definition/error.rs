@@ -1,4 +1,5 @@
 use bluejay_core::definition::{InputObjectTypeDefinition, SchemaDefinition};
+use bluejay_core::ErrorCode;
 
 #[cfg(feature = "parser-integration")]
 use bluejay_parser::{
@@ -22,9 +23,26 @@ pub enum Error<'a, S: SchemaDefinition> {
     },
 }
 
+impl<S: SchemaDefinition> ErrorCode for Error<'_, S> {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::NonUniqueInputValueDefinitionNames { .. } => {
+                "GRAPHQL_SCHEMA_INVALID/UniqueInputValueDefinitionNames"
+            }
+            Self::NonUniqueEnumValueDefinitionNames { .. } => {
+                "GRAPHQL_SCHEMA_INVALID/UniqueEnumValueNames"
+            }
+            Self::InputObjectTypeDefinitionCircularReferences { .. } => {
+                "GRAPHQL_SCHEMA_INVALID/InputObjectCircularReferences"
+            }
+        }
+    }
+}
+
 #[cfg(feature = "parser-integration")]
 impl<'a> From<Error<'a, ParserSchemaDefinition<'a>>> for ParserError {
     fn from(value: Error<'a, ParserSchemaDefinition<'a>>) -> Self {
+        let code = value.code();
         match value {
             Error::NonUniqueInputValueDefinitionNames {
                 name,
@@ -83,5 +101,6 @@ impl<'a> From<Error<'a, ParserSchemaDefinition<'a>>> for ParserError {
             )
             }
         }
+        .with_code(code)
     }
 }
@@ -1,5 +1,15 @@
 mod cache;
 pub mod document;
 pub mod operation;
+mod pipeline;
+mod schema_cache;
+#[cfg(feature = "validation-cache")]
+mod validation_cache;
 
 pub use cache::Cache;
+pub use pipeline::{Pipeline, PipelineResult, PipelineRun};
+pub use schema_cache::SchemaCache;
+#[cfg(feature = "validation-cache")]
+pub use validation_cache::{
+    SchemaFingerprint, SharedValidationCache, ValidationCache, ValidationCacheKey,
+};
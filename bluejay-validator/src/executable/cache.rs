@@ -1,7 +1,7 @@
-use crate::executable::document::VariableDefinitionInputType;
+use crate::executable::{document::VariableDefinitionInputType, SchemaCache};
 use bluejay_core::definition::SchemaDefinition;
 use bluejay_core::executable::{
-    ExecutableDocument, FragmentDefinition, OperationDefinition, VariableDefinition,
+    ExecutableDocument, FragmentDefinition, OperationDefinition, VariableDefinition, VariableType,
 };
 use bluejay_core::{AsIter, Indexed};
 use std::collections::HashMap;
@@ -10,34 +10,64 @@ pub struct Cache<'a, E: ExecutableDocument, S: SchemaDefinition> {
     variable_definition_input_types:
         HashMap<Indexed<'a, E::VariableType>, VariableDefinitionInputType<'a, S::InputType>>,
     indexed_fragment_definitions: HashMap<&'a str, &'a E::FragmentDefinition>,
+    schema_cache: Option<&'a SchemaCache<'a, S>>,
 }
 
 impl<'a, E: ExecutableDocument, S: SchemaDefinition> Cache<'a, E, S> {
     pub fn new(executable_document: &'a E, schema_definition: &'a S) -> Self {
-        let variable_definition_input_types =
-            HashMap::from_iter(executable_document.operation_definitions().flat_map(
-                |operation_definition: &'a E::OperationDefinition| {
-                    let variable_definitions_iterator = operation_definition
-                        .as_ref()
-                        .variable_definitions()
-                        .map(|variable_definitions: &'a E::VariableDefinitions| -> <E::VariableDefinitions as AsIter>::Iterator<'a> {
-                            variable_definitions.iter()
-                        });
+        Self::build(executable_document, schema_definition, None)
+    }
+
+    /// Builds a cache for the document that reuses the schema-derived lookups of `schema_cache`
+    /// instead of resolving them from `schema_definition` again.
+    pub fn with_schema_cache(
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        schema_cache: &'a SchemaCache<'a, S>,
+    ) -> Self {
+        Self::build(executable_document, schema_definition, Some(schema_cache))
+    }
 
-                    variable_definitions_iterator
-                        .into_iter()
-                        .flatten()
-                        .filter_map(|variable_definition| {
-                            let variable_type = variable_definition.r#type();
-                            VariableDefinitionInputType::try_from((
-                                schema_definition,
-                                variable_type,
-                            ))
-                            .ok()
-                            .map(|vdit| (Indexed(variable_type), vdit))
-                        })
-                },
-            ));
+    fn build(
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        schema_cache: Option<&'a SchemaCache<'a, S>>,
+    ) -> Self {
+        let operation_variable_definitions = executable_document
+            .operation_definitions()
+            .filter_map(|operation_definition: &'a E::OperationDefinition| {
+                operation_definition.as_ref().variable_definitions()
+            });
+        let fragment_variable_definitions = executable_document.fragment_definitions().filter_map(
+            |fragment_definition: &'a E::FragmentDefinition| {
+                fragment_definition.variable_definitions()
+            },
+        );
+        let variable_definition_input_types = HashMap::from_iter(
+            operation_variable_definitions
+                .chain(fragment_variable_definitions)
+                .flat_map(|variable_definitions: &'a E::VariableDefinitions| -> <E::VariableDefinitions as AsIter>::Iterator<'a> {
+                    variable_definitions.iter()
+                })
+                .filter_map(|variable_definition| {
+                    let variable_type = variable_definition.r#type();
+                    let variable_definition_input_type = match schema_cache {
+                        Some(schema_cache) => schema_cache
+                            .base_input_type(variable_type.as_ref().name())
+                            .ok_or(())
+                            .and_then(|base| {
+                                VariableDefinitionInputType::try_from((base, variable_type))
+                            }),
+                        None => VariableDefinitionInputType::try_from((
+                            schema_definition,
+                            variable_type,
+                        )),
+                    };
+                    variable_definition_input_type
+                        .ok()
+                        .map(|vdit| (Indexed(variable_type), vdit))
+                }),
+        );
         let indexed_fragment_definitions = HashMap::from_iter(
             executable_document
                 .fragment_definitions()
@@ -46,6 +76,7 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition> Cache<'a, E, S> {
         Self {
             variable_definition_input_types,
             indexed_fragment_definitions,
+            schema_cache,
         }
     }
 
@@ -60,4 +91,8 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition> Cache<'a, E, S> {
     pub fn fragment_definition(&self, name: &str) -> Option<&'a E::FragmentDefinition> {
         self.indexed_fragment_definitions.get(name).copied()
     }
+
+    pub fn schema_cache(&self) -> Option<&'a SchemaCache<'a, S>> {
+        self.schema_cache
+    }
 }
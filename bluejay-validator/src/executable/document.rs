@@ -1,17 +1,25 @@
 mod analyzer;
+mod batch_validator;
 mod error;
 mod orchestrator;
 mod path;
 mod rule;
+mod rule_registry;
 pub mod rules;
 mod variable_definition_input_type;
 mod visitor;
 
 pub use analyzer::Analyzer;
+pub use batch_validator::BatchValidator;
 pub use error::{ArgumentError, DirectiveError, Error};
+pub(crate) use orchestrator::OperationWalk;
 pub use orchestrator::{BuiltinRulesValidator, Orchestrator};
 pub use path::{Path, PathRoot};
 pub use rule::{Rule, RuleErrorAdapter};
+pub use rule_registry::{
+    RuleRegistry, Severity, UnknownRuleError, ValidationReport, BUILTIN_RULE_NAMES,
+    OPT_IN_RULE_NAMES,
+};
 pub use rules::BuiltinRules;
 pub use variable_definition_input_type::VariableDefinitionInputType;
 pub use visitor::Visitor;
@@ -0,0 +1,116 @@
+use crate::executable::{
+    document::{RuleRegistry, ValidationReport},
+    Cache, SchemaCache,
+};
+use bluejay_core::definition::SchemaDefinition;
+use bluejay_core::executable::ExecutableDocument;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Validates many executable documents against one schema on a pool of worker threads.
+///
+/// The [`SchemaCache`] is built once and shared by every document, and each document is
+/// validated with the configured [`RuleRegistry`].
+pub struct BatchValidator<'a, S: SchemaDefinition> {
+    schema_definition: &'a S,
+    schema_cache: SchemaCache<'a, S>,
+    rule_registry: RuleRegistry,
+    worker_count: NonZeroUsize,
+}
+
+impl<'a, S: SchemaDefinition + Sync> BatchValidator<'a, S>
+where
+    SchemaCache<'a, S>: Sync,
+{
+    pub fn new(schema_definition: &'a S) -> Self {
+        Self {
+            schema_definition,
+            schema_cache: SchemaCache::new(schema_definition),
+            rule_registry: RuleRegistry::default(),
+            worker_count: std::thread::available_parallelism().unwrap_or(NonZeroUsize::MIN),
+        }
+    }
+
+    pub fn with_rule_registry(mut self, rule_registry: RuleRegistry) -> Self {
+        self.rule_registry = rule_registry;
+        self
+    }
+
+    /// Defaults to [`std::thread::available_parallelism`].
+    pub fn with_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = worker_count;
+        self
+    }
+
+    pub fn schema_cache(&self) -> &SchemaCache<'a, S> {
+        &self.schema_cache
+    }
+
+    /// Validates every document, returning one result per document in the order of `documents`.
+    ///
+    /// The errors in a [`ValidationReport`] borrow from the per-document [`Cache`], which lives
+    /// only on the worker thread, so `f` maps each report into an owned result there, e.g. by
+    /// converting the errors into `bluejay_parser::error::GraphQLError`s.
+    pub fn validate<E, T, F>(&self, documents: &[E], f: F) -> Vec<T>
+    where
+        E: ExecutableDocument + Sync,
+        T: Send,
+        F: for<'b> Fn(&'b E, ValidationReport<'b, E, S>) -> T + Sync,
+    {
+        let worker_count = self.worker_count.get().min(documents.len());
+
+        if worker_count <= 1 {
+            return documents
+                .iter()
+                .map(|document| self.validate_document(document, &f))
+                .collect();
+        }
+
+        let next_index = AtomicUsize::new(0);
+
+        let mut indexed_results: Vec<(usize, T)> = std::thread::scope(|scope| {
+            let workers: Vec<_> = (0..worker_count)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut results = Vec::new();
+                        loop {
+                            let index = next_index.fetch_add(1, Ordering::Relaxed);
+                            let Some(document) = documents.get(index) else {
+                                break results;
+                            };
+                            results.push((index, self.validate_document(document, &f)));
+                        }
+                    })
+                })
+                .collect();
+
+            workers
+                .into_iter()
+                .flat_map(|worker| {
+                    worker
+                        .join()
+                        .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+                })
+                .collect()
+        });
+
+        indexed_results.sort_unstable_by_key(|(index, _)| *index);
+        indexed_results
+            .into_iter()
+            .map(|(_, result)| result)
+            .collect()
+    }
+
+    fn validate_document<E: ExecutableDocument, T>(
+        &self,
+        document: &E,
+        f: &impl for<'b> Fn(&'b E, ValidationReport<'b, E, S>) -> T,
+    ) -> T {
+        let cache = Cache::with_schema_cache(document, self.schema_definition, &self.schema_cache);
+        f(
+            document,
+            self.rule_registry
+                .validate(document, self.schema_definition, &cache),
+        )
+    }
+}
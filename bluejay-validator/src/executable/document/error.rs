@@ -3,7 +3,7 @@ use bluejay_core::definition::{
     FieldDefinition, InputType, OutputType, SchemaDefinition, TypeDefinitionReference,
 };
 use bluejay_core::executable::{ExecutableDocument, OperationDefinition, VariableType};
-use bluejay_core::{OperationType, Value};
+use bluejay_core::{ErrorCode, OperationType, Value};
 #[cfg(feature = "parser-integration")]
 use bluejay_parser::{
     ast::executable::ExecutableDocument as ParserExecutableDocument,
@@ -29,9 +29,32 @@ pub enum Error<'a, E: ExecutableDocument, S: SchemaDefinition> {
     SubscriptionRootNotSingleField {
         operation: &'a E::OperationDefinition,
     },
+    SubscriptionRootSelectionConditional {
+        operation: &'a E::OperationDefinition,
+        directive: &'a E::Directive<false>,
+    },
+    SubscriptionRootFieldIntrospection {
+        operation: &'a E::OperationDefinition,
+        field: &'a E::Field,
+    },
+    MutationRootFieldLimitExceeded {
+        operation: &'a E::OperationDefinition,
+        limit: usize,
+        field_count: usize,
+    },
+    MutationRootFieldNotExclusive {
+        operation: &'a E::OperationDefinition,
+        field: &'a E::Field,
+    },
+    SlicingArgumentCountInvalid {
+        field: &'a E::Field,
+        slicing_arguments: Vec<&'a str>,
+        passed_slicing_arguments: Vec<&'a str>,
+    },
     FieldDoesNotExistOnType {
         field: &'a E::Field,
         r#type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        suggestions: Vec<&'a str>,
     },
     OperationTypeNotDefined {
         operation: &'a E::ExplicitOperationDefinition,
@@ -50,9 +73,11 @@ pub enum Error<'a, E: ExecutableDocument, S: SchemaDefinition> {
     },
     FragmentDefinitionTargetTypeDoesNotExist {
         fragment_definition: &'a E::FragmentDefinition,
+        suggestions: Vec<&'a str>,
     },
     InlineFragmentTargetTypeDoesNotExist {
         inline_fragment: &'a E::InlineFragment,
+        suggestions: Vec<&'a str>,
     },
     FragmentDefinitionTargetTypeNotComposite {
         fragment_definition: &'a E::FragmentDefinition,
@@ -65,6 +90,7 @@ pub enum Error<'a, E: ExecutableDocument, S: SchemaDefinition> {
     },
     FragmentSpreadTargetUndefined {
         fragment_spread: &'a E::FragmentSpread,
+        suggestions: Vec<&'a str>,
     },
     FragmentSpreadCycle {
         fragment_definition: &'a E::FragmentDefinition,
@@ -120,16 +146,127 @@ pub enum Error<'a, E: ExecutableDocument, S: SchemaDefinition> {
         variable_type: &'a E::VariableType,
         location_type: &'a S::InputType,
     },
+    InvalidFragmentArgumentVariableUsage {
+        variable: &'a <E::Value<false> as Value<false>>::Variable,
+        variable_type: &'a E::VariableType,
+        argument_type: &'a E::VariableType,
+    },
     InvalidOneOfVariableUsage {
         variable: &'a <E::Value<false> as Value<false>>::Variable,
         variable_type: &'a E::VariableType,
         parent_type_name: &'a str,
     },
+    NonUniqueDeferStreamLabels {
+        label: &'a str,
+        directives: Vec<&'a E::Directive<false>>,
+    },
+    DeferStreamLabelNotStatic {
+        directive: &'a E::Directive<false>,
+    },
+    DeferStreamOnSubscriptionRoot {
+        operation: &'a E::OperationDefinition,
+        directive: &'a E::Directive<false>,
+    },
+    StreamOnNonListField {
+        field: &'a E::Field,
+        field_definition: &'a S::FieldDefinition,
+        directive: &'a E::Directive<false>,
+    },
+}
+
+impl<E: ExecutableDocument, S: SchemaDefinition> ErrorCode for Error<'_, E, S> {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::NonUniqueOperationNames { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/UniqueOperationNames"
+            }
+            Self::NotLoneAnonymousOperation { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/LoneAnonymousOperation"
+            }
+            Self::SubscriptionRootNotSingleField { .. }
+            | Self::SubscriptionRootSelectionConditional { .. }
+            | Self::SubscriptionRootFieldIntrospection { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/SingleFieldSubscriptions"
+            }
+            Self::MutationRootFieldLimitExceeded { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/MutationRootFieldLimit"
+            }
+            Self::MutationRootFieldNotExclusive { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/ExclusiveMutationRootField"
+            }
+            Self::SlicingArgumentCountInvalid { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/RequireOneSlicingArgument"
+            }
+            Self::FieldDoesNotExistOnType { .. } => "GRAPHQL_VALIDATION_FAILED/FieldsOnCorrectType",
+            Self::OperationTypeNotDefined { .. } => "GRAPHQL_VALIDATION_FAILED/KnownOperationTypes",
+            Self::LeafFieldSelectionNotEmpty { .. } | Self::NonLeafFieldSelectionEmpty { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/ScalarLeafs"
+            }
+            Self::NonUniqueFragmentDefinitionNames { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/UniqueFragmentNames"
+            }
+            Self::FragmentDefinitionTargetTypeDoesNotExist { .. }
+            | Self::InlineFragmentTargetTypeDoesNotExist { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/KnownTypeNames"
+            }
+            Self::FragmentDefinitionTargetTypeNotComposite { .. }
+            | Self::InlineFragmentTargetTypeNotComposite { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/FragmentsOnCompositeTypes"
+            }
+            Self::FragmentDefinitionUnused { .. } => "GRAPHQL_VALIDATION_FAILED/NoUnusedFragments",
+            Self::FragmentSpreadTargetUndefined { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/KnownFragmentNames"
+            }
+            Self::FragmentSpreadCycle { .. } => "GRAPHQL_VALIDATION_FAILED/NoFragmentCycles",
+            Self::FieldSelectionsDoNotMergeIncompatibleTypes { .. }
+            | Self::FieldSelectionsDoNotMergeDifferingNames { .. }
+            | Self::FieldSelectionsDoNotMergeDifferingArguments { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/OverlappingFieldsCanBeMerged"
+            }
+            Self::FragmentSpreadIsNotPossible { .. }
+            | Self::InlineFragmentSpreadIsNotPossible { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/PossibleFragmentSpreads"
+            }
+            Self::InvalidConstValue(error) => error.code(),
+            Self::InvalidVariableValue(error) => error.code(),
+            Self::InvalidConstDirective(error) => error.code(),
+            Self::InvalidVariableDirective(error) => error.code(),
+            Self::InvalidConstArgument(error) => error.code(),
+            Self::InvalidVariableArgument(error) => error.code(),
+            Self::NonUniqueVariableDefinitionNames { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/UniqueVariableNames"
+            }
+            Self::VariableDefinitionTypeNotInput { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/VariablesAreInputTypes"
+            }
+            Self::VariableNotDefined { .. } => "GRAPHQL_VALIDATION_FAILED/NoUndefinedVariables",
+            Self::VariableDefinitionUnused { .. } => "GRAPHQL_VALIDATION_FAILED/NoUnusedVariables",
+            Self::InvalidVariableUsage { .. }
+            | Self::InvalidFragmentArgumentVariableUsage { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/VariablesInAllowedPosition"
+            }
+            Self::InvalidOneOfVariableUsage { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/ValuesOfCorrectType"
+            }
+            Self::NonUniqueDeferStreamLabels { .. } | Self::DeferStreamLabelNotStatic { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/DeferStreamDirectiveLabel"
+            }
+            Self::DeferStreamOnSubscriptionRoot { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/DeferStreamDirectiveOnRootField"
+            }
+            Self::StreamOnNonListField { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/StreamDirectiveOnListField"
+            }
+        }
+    }
 }
 
 #[cfg(feature = "parser-integration")]
-impl<'a, S: SchemaDefinition> From<Error<'a, ParserExecutableDocument<'a>, S>> for ParserError {
-    fn from(value: Error<'a, ParserExecutableDocument<'a>, S>) -> Self {
+impl<'a, 'b: 'a, S: SchemaDefinition> From<Error<'a, ParserExecutableDocument<'b>, S>>
+    for ParserError
+{
+    fn from(value: Error<'a, ParserExecutableDocument<'b>, S>) -> Self {
+        let code = value.code();
         match value {
             Error::NonUniqueOperationNames { name, operations } => Self::new(
                 format!("Multiple operation definitions named `{name}`"),
@@ -169,11 +306,84 @@ impl<'a, S: SchemaDefinition> From<Error<'a, ParserExecutableDocument<'a>, S>> f
                 )),
                 Vec::new(),
             ),
-            Error::FieldDoesNotExistOnType { field, r#type } => Self::new(
+            Error::SubscriptionRootSelectionConditional { directive, .. } => Self::new(
                 format!(
-                    "Field `{}` does not exist on type `{}`",
+                    "Subscription root selections must not use `@{}`",
+                    directive.name().as_ref(),
+                ),
+                Some(Annotation::new(
+                    "Conditional directive on subscription root selection",
+                    *directive.span(),
+                )),
+                Vec::new(),
+            ),
+            Error::SubscriptionRootFieldIntrospection { field, .. } => Self::new(
+                format!(
+                    "Subscription root field `{}` is an introspection field",
                     field.name().as_ref(),
-                    r#type.name()
+                ),
+                Some(Annotation::new(
+                    "Introspection field on subscription root",
+                    *field.name().span(),
+                )),
+                Vec::new(),
+            ),
+            Error::MutationRootFieldLimitExceeded {
+                operation,
+                limit,
+                field_count,
+            } => Self::new(
+                format!("Mutation selects {field_count} root fields, but at most {limit} are allowed"),
+                Some(Annotation::new(
+                    format!("Selection set contains {field_count} root fields"),
+                    *operation.as_ref().selection_set().span(),
+                )),
+                Vec::new(),
+            ),
+            Error::MutationRootFieldNotExclusive { field, .. } => Self::new(
+                format!(
+                    "Mutation field `{}` must be the only root field of the operation",
+                    field.name().as_ref(),
+                ),
+                Some(Annotation::new(
+                    "Field must be the only root field",
+                    *field.name().span(),
+                )),
+                Vec::new(),
+            ),
+            Error::SlicingArgumentCountInvalid {
+                field,
+                slicing_arguments,
+                passed_slicing_arguments,
+            } => Self::new(
+                format!(
+                    "Field `{}` must be passed exactly one of the slicing arguments {}, but was passed {}",
+                    field.name().as_ref(),
+                    slicing_arguments
+                        .iter()
+                        .map(|name| format!("`{name}`"))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    passed_slicing_arguments.len(),
+                ),
+                Some(Annotation::new(
+                    "Field requires exactly one slicing argument",
+                    *field.name().span(),
+                )),
+                Vec::new(),
+            ),
+            Error::FieldDoesNotExistOnType {
+                field,
+                r#type,
+                suggestions,
+            } => Self::new(
+                message_with_suggestions(
+                    format!(
+                        "Field `{}` does not exist on type `{}`",
+                        field.name().as_ref(),
+                        r#type.name()
+                    ),
+                    &suggestions,
                 ),
                 Some(Annotation::new(
                     format!("Field does not exist on type `{}`", r#type.name()),
@@ -238,10 +448,14 @@ impl<'a, S: SchemaDefinition> From<Error<'a, ParserExecutableDocument<'a>, S>> f
             ),
             Error::FragmentDefinitionTargetTypeDoesNotExist {
                 fragment_definition,
+                suggestions,
             } => Self::new(
-                format!(
-                    "No type definition with name `{}`",
-                    fragment_definition.type_condition().named_type().as_ref()
+                message_with_suggestions(
+                    format!(
+                        "No type definition with name `{}`",
+                        fragment_definition.type_condition().named_type().as_ref()
+                    ),
+                    &suggestions,
                 ),
                 Some(Annotation::new(
                     "No type with this name",
@@ -252,13 +466,19 @@ impl<'a, S: SchemaDefinition> From<Error<'a, ParserExecutableDocument<'a>, S>> f
                 )),
                 Vec::new(),
             ),
-            Error::InlineFragmentTargetTypeDoesNotExist { inline_fragment } => Self::new(
-                format!(
-                    "No type definition with name `{}`",
-                    inline_fragment
-                        .type_condition()
-                        .map(|tc| tc.named_type().as_ref())
-                        .unwrap_or_default()
+            Error::InlineFragmentTargetTypeDoesNotExist {
+                inline_fragment,
+                suggestions,
+            } => Self::new(
+                message_with_suggestions(
+                    format!(
+                        "No type definition with name `{}`",
+                        inline_fragment
+                            .type_condition()
+                            .map(|tc| tc.named_type().as_ref())
+                            .unwrap_or_default()
+                    ),
+                    &suggestions,
                 ),
                 inline_fragment.type_condition().map(|tc| {
                     Annotation::new("No type with this name", *tc.named_type().span())
@@ -310,10 +530,16 @@ impl<'a, S: SchemaDefinition> From<Error<'a, ParserExecutableDocument<'a>, S>> f
                 )),
                 Vec::new(),
             ),
-            Error::FragmentSpreadTargetUndefined { fragment_spread } => Self::new(
-                format!(
-                    "No fragment defined with name `{}`",
-                    fragment_spread.name().as_ref()
+            Error::FragmentSpreadTargetUndefined {
+                fragment_spread,
+                suggestions,
+            } => Self::new(
+                message_with_suggestions(
+                    format!(
+                        "No fragment defined with name `{}`",
+                        fragment_spread.name().as_ref()
+                    ),
+                    &suggestions,
                 ),
                 Some(Annotation::new(
                     "No fragment defined with this name",
@@ -522,6 +748,27 @@ impl<'a, S: SchemaDefinition> From<Error<'a, ParserExecutableDocument<'a>, S>> f
                 )),
                 Vec::new(),
             ),
+            Error::InvalidFragmentArgumentVariableUsage {
+                variable,
+                variable_type,
+                argument_type,
+            } => Self::new(
+                format!(
+                    "Variable ${} of type {} cannot be used here, where {} is expected",
+                    variable.name(),
+                    variable_type.as_ref().display_name(),
+                    argument_type.as_ref().display_name(),
+                ),
+                Some(Annotation::new(
+                    format!(
+                        "Cannot use variable of type {} where {} is expected",
+                        variable_type.as_ref().display_name(),
+                        argument_type.as_ref().display_name(),
+                    ),
+                    *variable.span(),
+                )),
+                Vec::new(),
+            ),
             Error::InvalidOneOfVariableUsage {
                 variable,
                 variable_type,
@@ -544,6 +791,66 @@ impl<'a, S: SchemaDefinition> From<Error<'a, ParserExecutableDocument<'a>, S>> f
                 )),
                 Vec::new(),
             ),
+            Error::NonUniqueDeferStreamLabels { label, directives } => Self::new(
+                format!("Multiple `@defer` or `@stream` directives with label `{label}`"),
+                None,
+                directives
+                    .iter()
+                    .map(|directive| {
+                        Annotation::new(
+                            format!("Directive with label `{label}`"),
+                            *directive.span(),
+                        )
+                    })
+                    .collect(),
+            ),
+            Error::DeferStreamLabelNotStatic { directive } => Self::new(
+                format!(
+                    "Label of `@{}` must be a static string",
+                    directive.name().as_ref(),
+                ),
+                Some(Annotation::new(
+                    "Label must not be a variable",
+                    *directive.span(),
+                )),
+                Vec::new(),
+            ),
+            Error::DeferStreamOnSubscriptionRoot { directive, .. } => Self::new(
+                format!(
+                    "Subscription root selections must not use `@{}`",
+                    directive.name().as_ref(),
+                ),
+                Some(Annotation::new(
+                    "Incremental delivery directive on subscription root selection",
+                    *directive.span(),
+                )),
+                Vec::new(),
+            ),
+            Error::StreamOnNonListField {
+                field,
+                field_definition,
+                directive,
+            } => Self::new(
+                format!(
+                    "`@stream` used on field `{}` of non-list type `{}`",
+                    field.name().as_ref(),
+                    field_definition.r#type().display_name(),
+                ),
+                Some(Annotation::new(
+                    "`@stream` on non-list field",
+                    *directive.span(),
+                )),
+                Vec::new(),
+            ),
         }
+        .with_code(code)
+    }
+}
+
+#[cfg(feature = "parser-integration")]
+fn message_with_suggestions<T: std::fmt::Display>(message: String, suggestions: &[T]) -> String {
+    match crate::utils::did_you_mean(suggestions) {
+        Some(did_you_mean) => format!("{message}. {did_you_mean}"),
+        None => message,
     }
 }
@@ -1,8 +1,13 @@
+#[cfg(feature = "parser-integration")]
+use super::message_with_suggestions;
 use bluejay_core::definition::{
     DirectiveDefinition, FieldDefinition, InputValueDefinition, SchemaDefinition,
 };
 use bluejay_core::executable::ExecutableDocument;
 #[cfg(feature = "parser-integration")]
+use bluejay_core::executable::VariableDefinition;
+use bluejay_core::ErrorCode;
+#[cfg(feature = "parser-integration")]
 use bluejay_parser::{
     ast::executable::ExecutableDocument as ParserExecutableDocument,
     error::{Annotation, Error as ParserError},
@@ -18,10 +23,17 @@ pub enum ArgumentError<'a, const CONST: bool, E: ExecutableDocument, S: SchemaDe
     ArgumentDoesNotExistOnField {
         argument: &'a E::Argument<CONST>,
         field_definition: &'a S::FieldDefinition,
+        suggestions: Vec<&'a str>,
     },
     ArgumentDoesNotExistOnDirective {
         argument: &'a E::Argument<CONST>,
         directive_definition: &'a S::DirectiveDefinition,
+        suggestions: Vec<&'a str>,
+    },
+    ArgumentDoesNotExistOnFragment {
+        argument: &'a E::Argument<CONST>,
+        fragment_definition: &'a E::FragmentDefinition,
+        suggestions: Vec<&'a str>,
     },
     DirectiveMissingRequiredArguments {
         directive: &'a E::Directive<CONST>,
@@ -33,13 +45,39 @@ pub enum ArgumentError<'a, const CONST: bool, E: ExecutableDocument, S: SchemaDe
         field_definition: &'a S::FieldDefinition,
         missing_argument_definitions: Vec<&'a S::InputValueDefinition>,
     },
+    FragmentSpreadMissingRequiredArguments {
+        fragment_spread: &'a E::FragmentSpread,
+        fragment_definition: &'a E::FragmentDefinition,
+        missing_variable_definitions: Vec<&'a E::VariableDefinition>,
+    },
+}
+
+impl<const CONST: bool, E: ExecutableDocument, S: SchemaDefinition> ErrorCode
+    for ArgumentError<'_, CONST, E, S>
+{
+    fn code(&self) -> &'static str {
+        match self {
+            Self::NonUniqueArgumentNames { .. } => "GRAPHQL_VALIDATION_FAILED/UniqueArgumentNames",
+            Self::ArgumentDoesNotExistOnField { .. }
+            | Self::ArgumentDoesNotExistOnDirective { .. }
+            | Self::ArgumentDoesNotExistOnFragment { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/KnownArgumentNames"
+            }
+            Self::DirectiveMissingRequiredArguments { .. }
+            | Self::FieldMissingRequiredArguments { .. }
+            | Self::FragmentSpreadMissingRequiredArguments { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/ProvidedRequiredArguments"
+            }
+        }
+    }
 }
 
 #[cfg(feature = "parser-integration")]
-impl<'a, const CONST: bool, S: SchemaDefinition>
-    From<ArgumentError<'a, CONST, ParserExecutableDocument<'a>, S>> for ParserError
+impl<'a, 'b: 'a, const CONST: bool, S: SchemaDefinition>
+    From<ArgumentError<'a, CONST, ParserExecutableDocument<'b>, S>> for ParserError
 {
-    fn from(value: ArgumentError<'a, CONST, ParserExecutableDocument<'a>, S>) -> Self {
+    fn from(value: ArgumentError<'a, CONST, ParserExecutableDocument<'b>, S>) -> Self {
+        let code = value.code();
         match value {
             ArgumentError::NonUniqueArgumentNames { arguments, name } => Self::new(
                 format!("Multiple arguments with name `{name}`"),
@@ -57,11 +95,15 @@ impl<'a, const CONST: bool, S: SchemaDefinition>
             ArgumentError::ArgumentDoesNotExistOnField {
                 argument,
                 field_definition,
+                suggestions,
             } => Self::new(
-                format!(
-                    "Field `{}` does not define an argument named `{}`",
-                    field_definition.name(),
-                    argument.name().as_ref(),
+                message_with_suggestions(
+                    format!(
+                        "Field `{}` does not define an argument named `{}`",
+                        field_definition.name(),
+                        argument.name().as_ref(),
+                    ),
+                    &suggestions,
                 ),
                 Some(Annotation::new(
                     "No argument definition with this name",
@@ -72,11 +114,15 @@ impl<'a, const CONST: bool, S: SchemaDefinition>
             ArgumentError::ArgumentDoesNotExistOnDirective {
                 argument,
                 directive_definition,
+                suggestions,
             } => Self::new(
-                format!(
-                    "Directive `{}` does not define an argument named `{}`",
-                    directive_definition.name(),
-                    argument.name().as_ref(),
+                message_with_suggestions(
+                    format!(
+                        "Directive `{}` does not define an argument named `{}`",
+                        directive_definition.name(),
+                        argument.name().as_ref(),
+                    ),
+                    &suggestions,
                 ),
                 Some(Annotation::new(
                     "No argument definition with this name",
@@ -84,6 +130,25 @@ impl<'a, const CONST: bool, S: SchemaDefinition>
                 )),
                 Vec::new(),
             ),
+            ArgumentError::ArgumentDoesNotExistOnFragment {
+                argument,
+                fragment_definition,
+                suggestions,
+            } => Self::new(
+                message_with_suggestions(
+                    format!(
+                        "Fragment `{}` does not define a variable named `{}`",
+                        fragment_definition.name().as_ref(),
+                        argument.name().as_ref(),
+                    ),
+                    &suggestions,
+                ),
+                Some(Annotation::new(
+                    "No variable definition with this name",
+                    *argument.name().span(),
+                )),
+                Vec::new(),
+            ),
             ArgumentError::DirectiveMissingRequiredArguments {
                 directive,
                 missing_argument_definitions,
@@ -130,6 +195,32 @@ impl<'a, const CONST: bool, S: SchemaDefinition>
                     Vec::new(),
                 )
             }
+            ArgumentError::FragmentSpreadMissingRequiredArguments {
+                fragment_spread,
+                fragment_definition: _,
+                missing_variable_definitions,
+            } => {
+                let missing_argument_names = missing_variable_definitions
+                    .into_iter()
+                    .map(VariableDefinition::variable)
+                    .join(", ");
+                let span = match fragment_spread.arguments() {
+                    Some(arguments) => fragment_spread.span().merge(arguments.span()),
+                    None => *fragment_spread.span(),
+                };
+                Self::new(
+                    format!(
+                        "Fragment spread `{}` missing argument(s): {missing_argument_names}",
+                        fragment_spread.name().as_ref(),
+                    ),
+                    Some(Annotation::new(
+                        format!("Missing argument(s): {missing_argument_names}"),
+                        span,
+                    )),
+                    Vec::new(),
+                )
+            }
         }
+        .with_code(code)
     }
 }
@@ -1,6 +1,8 @@
+#[cfg(feature = "parser-integration")]
+use super::message_with_suggestions;
 use bluejay_core::definition::{DirectiveDefinition, DirectiveLocation, SchemaDefinition};
 use bluejay_core::executable::ExecutableDocument;
-use bluejay_core::AsIter;
+use bluejay_core::{AsIter, ErrorCode};
 #[cfg(feature = "parser-integration")]
 use bluejay_parser::{
     ast::executable::ExecutableDocument as ParserExecutableDocument,
@@ -12,6 +14,7 @@ use itertools::Itertools;
 pub enum DirectiveError<'a, const CONST: bool, E: ExecutableDocument, S: SchemaDefinition> {
     DirectiveDoesNotExist {
         directive: &'a E::Directive<CONST>,
+        suggestions: Vec<&'a str>,
     },
     DirectiveInInvalidLocation {
         directive: &'a E::Directive<CONST>,
@@ -24,16 +27,41 @@ pub enum DirectiveError<'a, const CONST: bool, E: ExecutableDocument, S: SchemaD
     },
 }
 
+impl<const CONST: bool, E: ExecutableDocument, S: SchemaDefinition> ErrorCode
+    for DirectiveError<'_, CONST, E, S>
+{
+    fn code(&self) -> &'static str {
+        match self {
+            Self::DirectiveDoesNotExist { .. } | Self::DirectiveInInvalidLocation { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/KnownDirectives"
+            }
+            Self::DirectivesNotUniquePerLocation { .. } => {
+                "GRAPHQL_VALIDATION_FAILED/UniqueDirectivesPerLocation"
+            }
+        }
+    }
+}
+
 #[cfg(feature = "parser-integration")]
-impl<'a, const CONST: bool, S: SchemaDefinition>
-    From<DirectiveError<'a, CONST, ParserExecutableDocument<'a>, S>> for ParserError
+impl<'a, 'b: 'a, const CONST: bool, S: SchemaDefinition>
+    From<DirectiveError<'a, CONST, ParserExecutableDocument<'b>, S>> for ParserError
 {
-    fn from(value: DirectiveError<'a, CONST, ParserExecutableDocument<'a>, S>) -> Self {
+    fn from(value: DirectiveError<'a, CONST, ParserExecutableDocument<'b>, S>) -> Self {
+        let code = value.code();
         match value {
-            DirectiveError::DirectiveDoesNotExist { directive } => Self::new(
-                format!(
-                    "No directive definition with name `@{}`",
-                    directive.name().as_ref()
+            DirectiveError::DirectiveDoesNotExist {
+                directive,
+                suggestions,
+            } => Self::new(
+                message_with_suggestions(
+                    format!(
+                        "No directive definition with name `@{}`",
+                        directive.name().as_ref()
+                    ),
+                    &suggestions
+                        .iter()
+                        .map(|suggestion| format!("@{suggestion}"))
+                        .collect::<Vec<_>>(),
                 ),
                 Some(Annotation::new(
                     "No directive definition with this name",
@@ -69,5 +97,6 @@ impl<'a, const CONST: bool, S: SchemaDefinition>
                 )).collect(),
             ),
         }
+        .with_code(code)
     }
 }
@@ -11,30 +11,144 @@ use bluejay_core::executable::{
     OperationDefinition, Selection, SelectionReference, VariableDefinition,
 };
 use bluejay_core::{Argument, AsIter, Directive, OperationType};
+use std::marker::PhantomData;
 
 pub struct Orchestrator<'a, E: ExecutableDocument, S: SchemaDefinition, V: Visitor<'a, E, S>> {
-    schema_definition: &'a S,
-    executable_document: &'a E,
-    visitor: V,
+    visitor: PhantomData<(&'a E, &'a S, V)>,
 }
 
 pub type BuiltinRulesValidator<'a, E, S> = Orchestrator<'a, E, S, BuiltinRules<'a, E, S>>;
 
-impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: Visitor<'a, E, S>>
-    Orchestrator<'a, E, S, V>
-{
-    fn new(
-        executable_document: &'a E,
-        schema_definition: &'a S,
-        cache: &'a Cache<'a, E, S>,
-    ) -> Self {
-        Self {
-            schema_definition,
-            executable_document,
-            visitor: Visitor::new(executable_document, schema_definition, cache),
-        }
+/// A walk of a single operation that is driven by the walk of the document, so that the
+/// selections of the operation are walked once for both.
+///
+/// The [`State`](OperationWalk::State) returned when entering a selection is passed when
+/// entering its nested selections, and when leaving it.
+pub(crate) trait OperationWalk<'a, E: ExecutableDocument, S: SchemaDefinition> {
+    type State: Copy;
+
+    /// The state of selections that are not part of the operation, e.g. of fragment definitions.
+    fn excluded() -> Self::State;
+
+    /// Walks the operation on its own, when the document is not walked.
+    #[cfg(feature = "validation-cache")]
+    fn walk(self) -> Self;
+
+    fn enter_operation_definition(
+        &mut self,
+        operation_definition: &'a E::OperationDefinition,
+    ) -> Self::State;
+
+    fn leave_operation_definition(
+        &mut self,
+        operation_definition: &'a E::OperationDefinition,
+        state: Self::State,
+    );
+
+    /// Called before each selection of a selection set.
+    fn enter_selection(&mut self, state: Self::State) -> Self::State;
+
+    fn enter_field(
+        &mut self,
+        field: &'a E::Field,
+        field_definition: &'a S::FieldDefinition,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        state: Self::State,
+    ) -> Self::State;
+
+    fn leave_field(
+        &mut self,
+        field: &'a E::Field,
+        field_definition: &'a S::FieldDefinition,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        state: Self::State,
+    );
+
+    fn enter_inline_fragment(
+        &mut self,
+        inline_fragment: &'a E::InlineFragment,
+        fragment_type: Option<TypeDefinitionReference<'a, S::TypeDefinition>>,
+        state: Self::State,
+    ) -> Self::State;
+
+    /// The walk of the document does not follow fragment spreads, so the operation walk
+    /// walks the selections of the fragment itself.
+    fn visit_fragment_spread(&mut self, fragment_spread: &'a E::FragmentSpread, state: Self::State);
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition> OperationWalk<'a, E, S> for () {
+    type State = ();
+
+    fn excluded() -> Self::State {}
+
+    #[cfg(feature = "validation-cache")]
+    fn walk(self) -> Self {}
+
+    fn enter_operation_definition(
+        &mut self,
+        _operation_definition: &'a E::OperationDefinition,
+    ) -> Self::State {
+    }
+
+    fn leave_operation_definition(
+        &mut self,
+        _operation_definition: &'a E::OperationDefinition,
+        _state: Self::State,
+    ) {
+    }
+
+    fn enter_selection(&mut self, _state: Self::State) -> Self::State {}
+
+    fn enter_field(
+        &mut self,
+        _field: &'a E::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        _state: Self::State,
+    ) -> Self::State {
     }
 
+    fn leave_field(
+        &mut self,
+        _field: &'a E::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        _state: Self::State,
+    ) {
+    }
+
+    fn enter_inline_fragment(
+        &mut self,
+        _inline_fragment: &'a E::InlineFragment,
+        _fragment_type: Option<TypeDefinitionReference<'a, S::TypeDefinition>>,
+        _state: Self::State,
+    ) -> Self::State {
+    }
+
+    fn visit_fragment_spread(
+        &mut self,
+        _fragment_spread: &'a E::FragmentSpread,
+        _state: Self::State,
+    ) {
+    }
+}
+
+/// Walks an executable document with a [`Visitor`], driving an [`OperationWalk`] along with it.
+struct DocumentWalk<'a, E: ExecutableDocument, S: SchemaDefinition, V: Visitor<'a, E, S>, O> {
+    schema_definition: &'a S,
+    executable_document: &'a E,
+    visitor: V,
+    operation_walk: O,
+}
+
+impl<
+        'a,
+        E: ExecutableDocument,
+        S: SchemaDefinition,
+        V: Visitor<'a, E, S>,
+        O: OperationWalk<'a, E, S>,
+    > DocumentWalk<'a, E, S, V, O>
+{
     fn visit(&mut self) {
         self.executable_document
             .operation_definitions()
@@ -52,6 +166,9 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: Visitor<'a, E, S>>
         let path = Path::new(PathRoot::Operation(operation_definition));
         self.visitor
             .visit_operation_definition(operation_definition);
+        let operation_walk_state = self
+            .operation_walk
+            .enter_operation_definition(operation_definition);
         let core_operation_definition = operation_definition.as_ref();
         if let Some(directives) = core_operation_definition.directives() {
             self.visit_variable_directives(
@@ -91,17 +208,29 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: Visitor<'a, E, S>>
                         )
                     }),
                 &path,
+                operation_walk_state,
             );
         }
+
+        self.operation_walk
+            .leave_operation_definition(operation_definition, operation_walk_state);
     }
 
     fn visit_fragment_definition(&mut self, fragment_definition: &'a E::FragmentDefinition) {
         let path = Path::new(PathRoot::Fragment(fragment_definition));
+        if let Some(variable_definitions) = fragment_definition.variable_definitions() {
+            self.visit_variable_definitions(variable_definitions);
+        }
         let type_condition = self
             .schema_definition
             .get_type_definition(fragment_definition.type_condition());
         if let Some(type_condition) = type_condition {
-            self.visit_selection_set(fragment_definition.selection_set(), type_condition, &path);
+            self.visit_selection_set(
+                fragment_definition.selection_set(),
+                type_condition,
+                &path,
+                O::excluded(),
+            );
         }
         if let Some(directives) = fragment_definition.directives() {
             self.visit_variable_directives(
@@ -119,36 +248,52 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: Visitor<'a, E, S>>
         selection_set: &'a E::SelectionSet,
         scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
         path: &Path<'a, E>,
+        operation_walk_state: O::State,
     ) {
         self.visitor.visit_selection_set(selection_set, scoped_type);
 
-        selection_set
-            .iter()
-            .for_each(|selection| match selection.as_ref() {
+        selection_set.iter().for_each(|selection| {
+            let operation_walk_state = self.operation_walk.enter_selection(operation_walk_state);
+            match selection.as_ref() {
                 SelectionReference::Field(f) => {
                     let field_definition = scoped_type
                         .fields_definition()
                         .and_then(|fields_definition| fields_definition.get(f.name()));
 
                     if let Some(field_definition) = field_definition {
-                        self.visit_field(f, field_definition, path);
+                        self.visit_field(
+                            f,
+                            field_definition,
+                            scoped_type,
+                            path,
+                            operation_walk_state,
+                        );
                     }
                 }
                 SelectionReference::InlineFragment(i) => {
-                    self.visit_inline_fragment(i, scoped_type, path)
+                    self.visit_inline_fragment(i, scoped_type, path, operation_walk_state)
                 }
                 SelectionReference::FragmentSpread(fs) => {
-                    self.visit_fragment_spread(fs, scoped_type, path)
+                    self.visit_fragment_spread(fs, scoped_type, path, operation_walk_state)
                 }
-            })
+            }
+        })
     }
 
     fn visit_field(
         &mut self,
         field: &'a E::Field,
         field_definition: &'a S::FieldDefinition,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
         path: &Path<'a, E>,
+        operation_walk_state: O::State,
     ) {
+        let operation_walk_state = self.operation_walk.enter_field(
+            field,
+            field_definition,
+            scoped_type,
+            operation_walk_state,
+        );
         self.visitor.visit_field(field, field_definition, path);
         if let Some(directives) = field.directives() {
             self.visit_variable_directives(directives, DirectiveLocation::Field, path);
@@ -166,11 +311,13 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: Visitor<'a, E, S>>
                 .schema_definition
                 .get_type_definition(field_definition.r#type().base_name())
             {
-                self.visit_selection_set(selection_set, nested_type, path);
+                self.visit_selection_set(selection_set, nested_type, path, operation_walk_state);
             }
         }
 
         self.visitor.leave_field(field, field_definition);
+        self.operation_walk
+            .leave_field(field, field_definition, scoped_type, operation_walk_state);
     }
 
     fn visit_variable_directives(
@@ -236,6 +383,7 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: Visitor<'a, E, S>>
         inline_fragment: &'a E::InlineFragment,
         scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
         path: &Path<'a, E>,
+        operation_walk_state: O::State,
     ) {
         if let Some(directives) = inline_fragment.directives() {
             self.visit_variable_directives(directives, DirectiveLocation::InlineFragment, path);
@@ -247,8 +395,18 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: Visitor<'a, E, S>>
             Some(scoped_type)
         };
 
+        let operation_walk_state = self.operation_walk.enter_inline_fragment(
+            inline_fragment,
+            fragment_type,
+            operation_walk_state,
+        );
         if let Some(fragment_type) = fragment_type {
-            self.visit_selection_set(inline_fragment.selection_set(), fragment_type, path);
+            self.visit_selection_set(
+                inline_fragment.selection_set(),
+                fragment_type,
+                path,
+                operation_walk_state,
+            );
         }
 
         self.visitor
@@ -260,6 +418,7 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: Visitor<'a, E, S>>
         fragment_spread: &'a E::FragmentSpread,
         scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
         path: &Path<'a, E>,
+        operation_walk_state: O::State,
     ) {
         if let Some(directives) = fragment_spread.directives() {
             self.visit_variable_directives(directives, DirectiveLocation::FragmentSpread, path);
@@ -267,6 +426,8 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: Visitor<'a, E, S>>
 
         self.visitor
             .visit_fragment_spread(fragment_spread, scoped_type, path);
+        self.operation_walk
+            .visit_fragment_spread(fragment_spread, operation_walk_state);
         // fragment will get checked when definition is visited
     }
 
@@ -324,6 +485,24 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: Visitor<'a, E, S>>
         self.visitor
             .visit_variable_argument(argument, input_value_definition, path);
     }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: Visitor<'a, E, S>>
+    Orchestrator<'a, E, S, V>
+{
+    fn visit(
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        cache: &'a Cache<'a, E, S>,
+    ) -> V {
+        visit(
+            executable_document,
+            schema_definition,
+            Visitor::new(executable_document, schema_definition, cache),
+            (),
+        )
+        .0
+    }
 
     pub fn validate(
         executable_document: &'a E,
@@ -333,9 +512,7 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: Visitor<'a, E, S>>
     where
         V: Rule<'a, E, S>,
     {
-        let mut instance = Self::new(executable_document, schema_definition, cache);
-        instance.visit();
-        instance.visitor.into_errors()
+        Self::visit(executable_document, schema_definition, cache).into_errors()
     }
 
     pub fn analyze(
@@ -346,9 +523,7 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: Visitor<'a, E, S>>
     where
         V: Analyzer<'a, E, S>,
     {
-        let mut instance = Self::new(executable_document, schema_definition, cache);
-        instance.visit();
-        instance.visitor.into_output()
+        Self::visit(executable_document, schema_definition, cache).into_output()
     }
 }
 
@@ -363,11 +538,31 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, R: Rule<'a, E, S>, A: Analy
         <R as Rule<'a, E, S>>::Errors,
         <A as Analyzer<'a, E, S>>::Output,
     ) {
-        let mut instance = Self::new(executable_document, schema_definition, cache);
-        instance.visit();
-        (
-            instance.visitor.0.into_errors(),
-            instance.visitor.1.into_output(),
-        )
+        let (rule, analyzer) = Self::visit(executable_document, schema_definition, cache);
+        (rule.into_errors(), analyzer.into_output())
     }
 }
+
+/// Walks the document with an already constructed visitor, for visitors whose construction
+/// depends on more than what [`Visitor::new`] receives, driving `operation_walk` along with it.
+pub(super) fn visit<
+    'a,
+    E: ExecutableDocument,
+    S: SchemaDefinition,
+    V: Visitor<'a, E, S>,
+    O: OperationWalk<'a, E, S>,
+>(
+    executable_document: &'a E,
+    schema_definition: &'a S,
+    visitor: V,
+    operation_walk: O,
+) -> (V, O) {
+    let mut instance = DocumentWalk {
+        schema_definition,
+        executable_document,
+        visitor,
+        operation_walk,
+    };
+    instance.visit();
+    (instance.visitor, instance.operation_walk)
+}
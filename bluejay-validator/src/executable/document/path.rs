@@ -1,6 +1,6 @@
 use bluejay_core::{
-    executable::{ExecutableDocument, FragmentDefinition, OperationDefinition},
-    Indexable,
+    executable::{ExecutableDocument, FragmentDefinition, OperationDefinition, VariableDefinition},
+    AsIter, Indexable,
 };
 use std::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
 use std::hash::{Hash, Hasher};
@@ -39,6 +39,20 @@ impl<'a, E: ExecutableDocument + 'a> PathRoot<'a, E> {
             Self::Fragment(f) => Some(f.name()),
         }
     }
+
+    /// The definition of the variable named `name` if the root is a fragment that defines it,
+    /// in which case uses of the variable within the fragment refer to the arguments of its
+    /// spreads rather than to a variable of an operation.
+    pub fn fragment_variable_definition(&self, name: &str) -> Option<&'a E::VariableDefinition> {
+        match self {
+            Self::Operation(_) => None,
+            Self::Fragment(f) => f.variable_definitions().and_then(|variable_definitions| {
+                variable_definitions
+                    .iter()
+                    .find(|variable_definition| variable_definition.variable() == name)
+            }),
+        }
+    }
 }
 
 impl<E: ExecutableDocument> Clone for PathRoot<'_, E> {
@@ -34,6 +34,8 @@ seq_macro::seq!(N in 2..=10 {
 });
 
 impl_rule!(26);
+impl_rule!(28);
+impl_rule!(31);
 
 pub struct RuleErrorAdapter<R, ER> {
     rule: R,
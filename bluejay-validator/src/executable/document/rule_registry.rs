@@ -0,0 +1,435 @@
+use crate::executable::{
+    document::{
+        orchestrator,
+        rules::{self, MutationRootFieldConfig},
+        BuiltinRules, BuiltinRulesValidator, Error, OperationWalk, Path, Rule, Visitor,
+    },
+    Cache,
+};
+use bluejay_core::definition::{DirectiveLocation, SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::executable::ExecutableDocument;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// Runtime selection of the rules to run, each with a [`Severity`].
+///
+/// The default configuration enables every builtin rule at [`Severity::Error`] and is validated
+/// with [`BuiltinRulesValidator`]. Any other configuration is validated in a single pass over the
+/// document with only the enabled rules.
+///
+/// The rules named in [`OPT_IN_RULE_NAMES`] are not builtin rules, and are only run when enabled.
+/// `MutationRootFields` enforces the [`MutationRootFieldConfig`] set with
+/// [`RuleRegistry::set_mutation_root_field_config`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleRegistry {
+    severities: [Option<Severity>; RULE_COUNT],
+    mutation_root_field_config: MutationRootFieldConfig,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct UnknownRuleError<'b> {
+    pub name: &'b str,
+}
+
+impl UnknownRuleError<'_> {
+    pub fn message(&self) -> String {
+        format!("No rule with name `{}`", self.name)
+    }
+}
+
+pub struct ValidationReport<'a, E: ExecutableDocument, S: SchemaDefinition> {
+    pub errors: Vec<Error<'a, E, S>>,
+    pub warnings: Vec<Error<'a, E, S>>,
+}
+
+impl<E: ExecutableDocument, S: SchemaDefinition> ValidationReport<'_, E, S> {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+macro_rules! define_builtin_rule_registry {
+    ($($rule:ident<$($param:tt),*>),* $(,)?) => {
+        const BUILTIN_RULE_COUNT: usize = [$(stringify!($rule)),*].len();
+
+        /// The names of the builtin rules, in the order they are run by [`BuiltinRulesValidator`].
+        pub const BUILTIN_RULE_NAMES: [&str; BUILTIN_RULE_COUNT] = [$(stringify!($rule)),*];
+
+        fn instantiate_builtin_rule<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a>(
+            index: usize,
+            executable_document: &'a E,
+            schema_definition: &'a S,
+            cache: &'a Cache<'a, E, S>,
+        ) -> Box<dyn DynRule<'a, E, S> + 'a> {
+            let mut builtin_rule_index = 0;
+            $(
+                if index == builtin_rule_index {
+                    return Box::new(<rules::$rule<$($param),*> as Visitor<'a, E, S>>::new(
+                        executable_document,
+                        schema_definition,
+                        cache,
+                    ));
+                }
+                builtin_rule_index += 1;
+            )*
+            unreachable!("No builtin rule at index {index} of {builtin_rule_index}")
+        }
+    };
+}
+
+rules::builtin_rules!(define_builtin_rule_registry);
+
+/// The names of the rules that are not builtin rules, which a [`RuleRegistry`] only runs when
+/// they are enabled.
+pub const OPT_IN_RULE_NAMES: [&str; 2] = ["MutationRootFields", "RequireOneSlicingArgument"];
+
+const RULE_COUNT: usize = BUILTIN_RULE_COUNT + OPT_IN_RULE_NAMES.len();
+
+impl Default for RuleRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl RuleRegistry {
+    /// All builtin rules enabled with [`Severity::Error`].
+    pub fn builtin() -> Self {
+        let mut severities = [None; RULE_COUNT];
+        severities[..BUILTIN_RULE_COUNT].fill(Some(Severity::Error));
+        Self {
+            severities,
+            mutation_root_field_config: MutationRootFieldConfig::default(),
+        }
+    }
+
+    /// No rules enabled.
+    pub fn empty() -> Self {
+        Self {
+            severities: [None; RULE_COUNT],
+            mutation_root_field_config: MutationRootFieldConfig::default(),
+        }
+    }
+
+    /// The policy enforced by the `MutationRootFields` rule when it is enabled.
+    pub fn mutation_root_field_config(&self) -> &MutationRootFieldConfig {
+        &self.mutation_root_field_config
+    }
+
+    /// Sets the policy enforced by the `MutationRootFields` rule when it is enabled.
+    pub fn set_mutation_root_field_config(
+        &mut self,
+        mutation_root_field_config: MutationRootFieldConfig,
+    ) -> &mut Self {
+        self.mutation_root_field_config = mutation_root_field_config;
+        self
+    }
+
+    pub fn enable<'b>(
+        &mut self,
+        name: &'b str,
+        severity: Severity,
+    ) -> Result<&mut Self, UnknownRuleError<'b>> {
+        *self.severity_mut(name)? = Some(severity);
+        Ok(self)
+    }
+
+    pub fn disable<'b>(&mut self, name: &'b str) -> Result<&mut Self, UnknownRuleError<'b>> {
+        *self.severity_mut(name)? = None;
+        Ok(self)
+    }
+
+    /// The severity of the rule with the given name, or `None` if it is disabled or unknown.
+    pub fn severity(&self, name: &str) -> Option<Severity> {
+        Self::index_of(name).and_then(|index| self.severities[index])
+    }
+
+    pub fn enabled_rules(&self) -> impl Iterator<Item = (&'static str, Severity)> + '_ {
+        BUILTIN_RULE_NAMES
+            .into_iter()
+            .chain(OPT_IN_RULE_NAMES)
+            .zip(self.severities)
+            .filter_map(|(name, severity)| severity.map(|severity| (name, severity)))
+    }
+
+    pub fn validate<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a>(
+        &self,
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        cache: &'a Cache<'a, E, S>,
+    ) -> ValidationReport<'a, E, S> {
+        if self.severities == Self::builtin().severities {
+            return ValidationReport {
+                errors: BuiltinRulesValidator::validate(
+                    executable_document,
+                    schema_definition,
+                    cache,
+                )
+                .collect(),
+                warnings: Vec::new(),
+            };
+        }
+
+        self.validate_with_operation_walk(executable_document, schema_definition, cache, ())
+            .0
+    }
+
+    /// Validates the document as [`RuleRegistry::validate`] does, driving `operation_walk` in the
+    /// same walk of the document.
+    pub(crate) fn validate_with_operation_walk<
+        'a,
+        E: ExecutableDocument + 'a,
+        S: SchemaDefinition + 'a,
+        O: OperationWalk<'a, E, S>,
+    >(
+        &self,
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        cache: &'a Cache<'a, E, S>,
+        operation_walk: O,
+    ) -> (ValidationReport<'a, E, S>, O) {
+        if self.severities == Self::builtin().severities {
+            let (builtin_rules, operation_walk) = orchestrator::visit(
+                executable_document,
+                schema_definition,
+                BuiltinRules::new(executable_document, schema_definition, cache),
+                operation_walk,
+            );
+            return (
+                ValidationReport {
+                    errors: builtin_rules.into_errors().collect(),
+                    warnings: Vec::new(),
+                },
+                operation_walk,
+            );
+        }
+
+        let configured_rules = ConfiguredRules {
+            rules: self
+                .severities
+                .iter()
+                .enumerate()
+                .filter_map(|(index, severity)| {
+                    severity.map(|severity| {
+                        (
+                            severity,
+                            self.instantiate_rule(
+                                index,
+                                executable_document,
+                                schema_definition,
+                                cache,
+                            ),
+                        )
+                    })
+                })
+                .collect(),
+        };
+
+        let (configured_rules, operation_walk) = orchestrator::visit(
+            executable_document,
+            schema_definition,
+            configured_rules,
+            operation_walk,
+        );
+
+        let mut report = ValidationReport {
+            errors: Vec::new(),
+            warnings: Vec::new(),
+        };
+        configured_rules
+            .rules
+            .into_iter()
+            .for_each(|(severity, rule)| match severity {
+                Severity::Error => report.errors.extend(rule.into_boxed_errors()),
+                Severity::Warning => report.warnings.extend(rule.into_boxed_errors()),
+            });
+        (report, operation_walk)
+    }
+
+    fn instantiate_rule<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a>(
+        &self,
+        index: usize,
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        cache: &'a Cache<'a, E, S>,
+    ) -> Box<dyn DynRule<'a, E, S> + 'a> {
+        match index.checked_sub(BUILTIN_RULE_COUNT) {
+            None => instantiate_builtin_rule(index, executable_document, schema_definition, cache),
+            Some(0) => Box::new(rules::MutationRootFields::with_policy(
+                cache,
+                self.mutation_root_field_config.clone(),
+            )),
+            Some(1) => Box::new(rules::RequireOneSlicingArgument::new(
+                executable_document,
+                schema_definition,
+                cache,
+            )),
+            Some(opt_in_rule_index) => {
+                unreachable!("No opt-in rule at index {opt_in_rule_index}")
+            }
+        }
+    }
+
+    fn index_of(name: &str) -> Option<usize> {
+        BUILTIN_RULE_NAMES
+            .iter()
+            .chain(&OPT_IN_RULE_NAMES)
+            .position(|rule_name| *rule_name == name)
+    }
+
+    fn severity_mut<'b>(
+        &mut self,
+        name: &'b str,
+    ) -> Result<&mut Option<Severity>, UnknownRuleError<'b>> {
+        Self::index_of(name)
+            .map(|index| &mut self.severities[index])
+            .ok_or(UnknownRuleError { name })
+    }
+}
+
+trait DynRule<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a>: Visitor<'a, E, S> {
+    fn into_boxed_errors(self: Box<Self>) -> Vec<Error<'a, E, S>>;
+}
+
+impl<
+        'a,
+        E: ExecutableDocument + 'a,
+        S: SchemaDefinition + 'a,
+        R: Rule<'a, E, S, Error = Error<'a, E, S>>,
+    > DynRule<'a, E, S> for R
+{
+    fn into_boxed_errors(self: Box<Self>) -> Vec<Error<'a, E, S>> {
+        self.into_errors().collect()
+    }
+}
+
+struct ConfiguredRules<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> {
+    rules: Vec<(Severity, Box<dyn DynRule<'a, E, S> + 'a>)>,
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> ConfiguredRules<'a, E, S> {
+    fn for_each_rule(&mut self, f: impl Fn(&mut (dyn DynRule<'a, E, S> + 'a))) {
+        self.rules.iter_mut().for_each(|(_, rule)| f(rule.as_mut()));
+    }
+}
+
+#[warn(clippy::missing_trait_methods)]
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
+    for ConfiguredRules<'a, E, S>
+{
+    fn new(
+        _executable_document: &'a E,
+        _schema_definition: &'a S,
+        _cache: &'a Cache<'a, E, S>,
+    ) -> Self {
+        unreachable!("ConfiguredRules are only constructed by RuleRegistry::validate")
+    }
+
+    fn visit_operation_definition(&mut self, operation_definition: &'a E::OperationDefinition) {
+        self.for_each_rule(|rule| rule.visit_operation_definition(operation_definition));
+    }
+
+    fn visit_selection_set(
+        &mut self,
+        selection_set: &'a E::SelectionSet,
+        r#type: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) {
+        self.for_each_rule(|rule| rule.visit_selection_set(selection_set, r#type));
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a E::Field,
+        field_definition: &'a S::FieldDefinition,
+        path: &Path<'a, E>,
+    ) {
+        self.for_each_rule(|rule| rule.visit_field(field, field_definition, path));
+    }
+
+    fn leave_field(&mut self, field: &'a E::Field, field_definition: &'a S::FieldDefinition) {
+        self.for_each_rule(|rule| rule.leave_field(field, field_definition));
+    }
+
+    fn visit_const_directive(
+        &mut self,
+        directive: &'a E::Directive<true>,
+        location: DirectiveLocation,
+    ) {
+        self.for_each_rule(|rule| rule.visit_const_directive(directive, location));
+    }
+
+    fn visit_variable_directive(
+        &mut self,
+        directive: &'a E::Directive<false>,
+        location: DirectiveLocation,
+    ) {
+        self.for_each_rule(|rule| rule.visit_variable_directive(directive, location));
+    }
+
+    fn visit_const_directives(
+        &mut self,
+        directives: &'a E::Directives<true>,
+        location: DirectiveLocation,
+    ) {
+        self.for_each_rule(|rule| rule.visit_const_directives(directives, location));
+    }
+
+    fn visit_variable_directives(
+        &mut self,
+        directives: &'a E::Directives<false>,
+        location: DirectiveLocation,
+    ) {
+        self.for_each_rule(|rule| rule.visit_variable_directives(directives, location));
+    }
+
+    fn visit_fragment_definition(&mut self, fragment_definition: &'a E::FragmentDefinition) {
+        self.for_each_rule(|rule| rule.visit_fragment_definition(fragment_definition));
+    }
+
+    fn visit_inline_fragment(
+        &mut self,
+        inline_fragment: &'a E::InlineFragment,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) {
+        self.for_each_rule(|rule| rule.visit_inline_fragment(inline_fragment, scoped_type));
+    }
+
+    fn visit_fragment_spread(
+        &mut self,
+        fragment_spread: &'a E::FragmentSpread,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        path: &Path<'a, E>,
+    ) {
+        self.for_each_rule(|rule| rule.visit_fragment_spread(fragment_spread, scoped_type, path));
+    }
+
+    fn visit_const_argument(
+        &mut self,
+        argument: &'a E::Argument<true>,
+        input_value_definition: &'a S::InputValueDefinition,
+    ) {
+        self.for_each_rule(|rule| rule.visit_const_argument(argument, input_value_definition));
+    }
+
+    fn visit_variable_argument(
+        &mut self,
+        argument: &'a E::Argument<false>,
+        input_value_definition: &'a S::InputValueDefinition,
+        path: &Path<'a, E>,
+    ) {
+        self.for_each_rule(|rule| {
+            rule.visit_variable_argument(argument, input_value_definition, path)
+        });
+    }
+
+    fn visit_variable_definition(&mut self, variable_definition: &'a E::VariableDefinition) {
+        self.for_each_rule(|rule| rule.visit_variable_definition(variable_definition));
+    }
+
+    fn visit_variable_definitions(&mut self, variable_definitions: &'a E::VariableDefinitions) {
+        self.for_each_rule(|rule| rule.visit_variable_definitions(variable_definitions));
+    }
+}
@@ -3,6 +3,8 @@ mod all_variable_uses_defined;
 mod all_variables_used;
 mod argument_names;
 mod argument_uniqueness;
+mod defer_stream_labels;
+mod defer_stream_not_on_subscription_root;
 mod directives_are_defined;
 mod directives_are_in_valid_locations;
 mod directives_are_unique_per_location;
@@ -17,10 +19,15 @@ mod fragments_must_be_used;
 mod fragments_on_composite_types;
 mod leaf_field_selections;
 mod lone_anonymous_operation;
+mod mutation_root_fields;
 mod named_operation_name_uniqueness;
 mod operation_type_is_defined;
+mod require_one_slicing_argument;
 mod required_arguments;
+mod stream_on_list_fields;
 mod subscription_operation_single_root_field;
+mod subscription_root_fields_not_introspection;
+mod subscription_root_selections_unconditional;
 mod value_is_valid;
 mod variable_uniqueness;
 mod variables_are_input_types;
@@ -30,6 +37,8 @@ pub use all_variable_uses_defined::AllVariableUsesDefined;
 pub use all_variables_used::AllVariablesUsed;
 pub use argument_names::ArgumentNames;
 pub use argument_uniqueness::ArgumentUniqueness;
+pub use defer_stream_labels::DeferStreamLabels;
+pub use defer_stream_not_on_subscription_root::DeferStreamNotOnSubscriptionRoot;
 pub use directives_are_defined::DirectivesAreDefined;
 pub use directives_are_in_valid_locations::DirectivesAreInValidLocations;
 pub use directives_are_unique_per_location::DirectivesAreUniquePerLocation;
@@ -44,39 +53,77 @@ pub use fragments_must_be_used::FragmentsMustBeUsed;
 pub use fragments_on_composite_types::FragmentsOnCompositeTypes;
 pub use leaf_field_selections::LeafFieldSelections;
 pub use lone_anonymous_operation::LoneAnonymousOperation;
+pub use mutation_root_fields::{
+    MutationRootFieldConfig, MutationRootFieldPolicy, MutationRootFields,
+};
 pub use named_operation_name_uniqueness::NamedOperationNameUniqueness;
 pub use operation_type_is_defined::OperationTypeIsDefined;
+pub use require_one_slicing_argument::RequireOneSlicingArgument;
 pub use required_arguments::RequiredArguments;
+pub use stream_on_list_fields::StreamOnListFields;
 pub use subscription_operation_single_root_field::SubscriptionOperationSingleRootField;
+pub use subscription_root_fields_not_introspection::SubscriptionRootFieldsNotIntrospection;
+pub use subscription_root_selections_unconditional::SubscriptionRootSelectionsUnconditional;
 pub use value_is_valid::ValueIsValid;
 pub use variable_uniqueness::VariableUniqueness;
 pub use variables_are_input_types::VariablesAreInputTypes;
 
-pub type BuiltinRules<'a, E, S> = (
-    NamedOperationNameUniqueness<'a, E>,
-    LoneAnonymousOperation<'a, E>,
-    SubscriptionOperationSingleRootField<'a, E>,
-    FieldSelections<'a, E, S>,
-    FieldSelectionMerging<'a, E, S>,
-    OperationTypeIsDefined<'a, E, S>,
-    LeafFieldSelections<'a, E, S>,
-    ArgumentNames<'a, E, S>,
-    ArgumentUniqueness<'a, E, S>,
-    RequiredArguments<'a, E, S>,
-    FragmentNameUniqueness<'a, E>,
-    FragmentSpreadTypeExists<'a, E, S>,
-    FragmentsOnCompositeTypes<'a, E, S>,
-    FragmentsMustBeUsed<'a, E>,
-    FragmentSpreadTargetDefined<'a, E, S>,
-    FragmentSpreadsMustNotFormCycles<'a, E, S>,
-    FragmentSpreadIsPossible<'a, E, S>,
-    ValueIsValid<'a, E, S>,
-    DirectivesAreDefined<'a, E, S>,
-    DirectivesAreInValidLocations<'a, E, S>,
-    DirectivesAreUniquePerLocation<'a, E, S>,
-    VariableUniqueness<'a, E, S>,
-    VariablesAreInputTypes<'a, E, S>,
-    AllVariableUsesDefined<'a, E, S>,
-    AllVariablesUsed<'a, E, S>,
-    AllVariableUsagesAllowed<'a, E, S>,
-);
+/// Invokes `$callback` with the builtin rules, in the order they are run. This is the single
+/// list that [`BuiltinRules`] and [`BUILTIN_RULE_NAMES`](super::BUILTIN_RULE_NAMES) are built from.
+///
+/// The rules for `@defer` and `@stream` are only builtin with the `incremental-delivery` feature.
+macro_rules! builtin_rules {
+    ($callback:ident) => {
+        $crate::executable::document::rules::builtin_rules!(@with_incremental_delivery $callback [
+            NamedOperationNameUniqueness<'a, E>,
+            LoneAnonymousOperation<'a, E>,
+            SubscriptionOperationSingleRootField<'a, E>,
+            SubscriptionRootSelectionsUnconditional<'a, E, S>,
+            SubscriptionRootFieldsNotIntrospection<'a, E, S>,
+            FieldSelections<'a, E, S>,
+            FieldSelectionMerging<'a, E, S>,
+            OperationTypeIsDefined<'a, E, S>,
+            LeafFieldSelections<'a, E, S>,
+            ArgumentNames<'a, E, S>,
+            ArgumentUniqueness<'a, E, S>,
+            RequiredArguments<'a, E, S>,
+            FragmentNameUniqueness<'a, E>,
+            FragmentSpreadTypeExists<'a, E, S>,
+            FragmentsOnCompositeTypes<'a, E, S>,
+            FragmentsMustBeUsed<'a, E>,
+            FragmentSpreadTargetDefined<'a, E, S>,
+            FragmentSpreadsMustNotFormCycles<'a, E, S>,
+            FragmentSpreadIsPossible<'a, E, S>,
+            ValueIsValid<'a, E, S>,
+            DirectivesAreDefined<'a, E, S>,
+            DirectivesAreInValidLocations<'a, E, S>,
+            DirectivesAreUniquePerLocation<'a, E, S>,
+            VariableUniqueness<'a, E, S>,
+            VariablesAreInputTypes<'a, E, S>,
+            AllVariableUsesDefined<'a, E, S>,
+            AllVariablesUsed<'a, E, S>,
+            AllVariableUsagesAllowed<'a, E, S>,
+        ]);
+    };
+    (@with_incremental_delivery $callback:ident [$($rules:tt)*]) => {
+        #[cfg(not(feature = "incremental-delivery"))]
+        $callback!($($rules)*);
+        #[cfg(feature = "incremental-delivery")]
+        $callback!(
+            $($rules)*
+            DeferStreamLabels<'a, E, S>,
+            DeferStreamNotOnSubscriptionRoot<'a, E, S>,
+            StreamOnListFields<'a, E, S>,
+        );
+    };
+}
+
+pub(crate) use builtin_rules;
+
+macro_rules! define_builtin_rules {
+    ($($rule:ident<$($param:tt),*>),* $(,)?) => {
+        pub type BuiltinRules<'a, E, S> = ($($rule<$($param),*>,)*);
+    };
+}
+
+builtin_rules!(define_builtin_rules);
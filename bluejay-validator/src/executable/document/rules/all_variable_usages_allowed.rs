@@ -18,6 +18,7 @@ use std::collections::{BTreeMap, BTreeSet, HashMap};
 pub struct AllVariableUsagesAllowed<'a, E: ExecutableDocument, S: SchemaDefinition> {
     fragment_references: HashMap<Indexed<'a, E::FragmentDefinition>, BTreeSet<PathRoot<'a, E>>>,
     variable_usages: BTreeMap<PathRoot<'a, E>, Vec<VariableUsage<'a, E, S>>>,
+    fragment_argument_usages: BTreeMap<PathRoot<'a, E>, Vec<FragmentArgumentUsage<'a, E>>>,
     cache: &'a Cache<'a, E, S>,
     schema_definition: &'a S,
 }
@@ -29,6 +30,7 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
         Self {
             fragment_references: HashMap::new(),
             variable_usages: BTreeMap::new(),
+            fragment_argument_usages: BTreeMap::new(),
             cache,
             schema_definition,
         }
@@ -58,6 +60,28 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
                 .entry(Indexed(fragment_definition))
                 .or_default()
                 .insert(*path.root());
+
+            // only variables used as the whole value of an argument are checked, as the nested
+            // positions of a list or input object value have no variable type to compare with
+            if let Some(arguments) = fragment_spread.arguments() {
+                arguments.iter().for_each(|argument| {
+                    let fragment_variable_definition = PathRoot::<E>::Fragment(fragment_definition)
+                        .fragment_variable_definition(argument.name());
+                    if let (
+                        ValueReference::Variable(variable),
+                        Some(fragment_variable_definition),
+                    ) = (argument.value().as_ref(), fragment_variable_definition)
+                    {
+                        self.fragment_argument_usages
+                            .entry(*path.root())
+                            .or_default()
+                            .push(FragmentArgumentUsage {
+                                variable,
+                                fragment_variable_definition,
+                            });
+                    }
+                });
+            }
         }
     }
 }
@@ -105,6 +129,20 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition> AllVariableUsagesAllowed<'a
         }
     }
 
+    fn operation_definitions_in_scope(
+        &self,
+        root: &PathRoot<'a, E>,
+    ) -> impl Iterator<Item = &'a E::OperationDefinition> {
+        match root {
+            PathRoot::Operation(operation_definition) => {
+                Either::Left(std::iter::once(*operation_definition))
+            }
+            PathRoot::Fragment(fragment_definition) => {
+                Either::Right(self.operation_definitions_where_fragment_used(fragment_definition))
+            }
+        }
+    }
+
     fn operation_definitions_where_fragment_used(
         &self,
         fragment_definition: &'a E::FragmentDefinition,
@@ -183,6 +221,49 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition> AllVariableUsagesAllowed<'a
         }
     }
 
+    fn validate_fragment_argument_usage(
+        &self,
+        variable_definition: &'a E::VariableDefinition,
+        fragment_argument_usage: &FragmentArgumentUsage<'a, E>,
+    ) -> Result<(), Error<'a, E, S>> {
+        let variable_type = variable_definition.r#type().as_ref();
+
+        // filter non-input types to avoid duplicate error
+        if !self.is_input_type(variable_type.name()) {
+            return Ok(());
+        }
+
+        let FragmentArgumentUsage {
+            variable,
+            fragment_variable_definition,
+        } = fragment_argument_usage;
+        let argument_type = fragment_variable_definition.r#type().as_ref();
+
+        let is_compatible = if argument_type.is_required() && !variable_type.is_required() {
+            let has_non_null_variable_default_value =
+                matches!(variable_definition.default_value(), Some(v) if !v.as_ref().is_null());
+            let has_argument_default_value = matches!(fragment_variable_definition.default_value(), Some(v) if !v.as_ref().is_null());
+
+            (has_non_null_variable_default_value || has_argument_default_value)
+                && are_variable_types_compatible::<E>(
+                    variable_type,
+                    argument_type.unwrap_nullable(),
+                )
+        } else {
+            are_variable_types_compatible::<E>(variable_type, argument_type)
+        };
+
+        if is_compatible {
+            Ok(())
+        } else {
+            Err(Error::InvalidFragmentArgumentVariableUsage {
+                variable,
+                variable_type: variable_definition.r#type(),
+                argument_type: fragment_variable_definition.r#type(),
+            })
+        }
+    }
+
     #[allow(clippy::only_used_in_recursion)] // making it a class method requires some additional lifetime constraints
     fn are_types_compatible(
         &self,
@@ -221,43 +302,126 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Rule<'a, E, S>
     type Errors = std::vec::IntoIter<Error<'a, E, S>>;
 
     fn into_errors(self) -> Self::Errors {
-        self.variable_usages
+        let rule = &self;
+        let variable_usage_errors = self
+            .variable_usages
             .iter()
             .filter(|(_, variable_usages)| !variable_usages.is_empty())
             .flat_map(|(root, variable_usages)| {
-                let operation_definitions: Either<std::iter::Once<&'a E::OperationDefinition>, _> =
-                    match root {
-                        PathRoot::Operation(operation_definition) => {
-                            Either::Left(std::iter::once(operation_definition))
-                        }
-                        PathRoot::Fragment(fragment_definition) => Either::Right(
-                            self.operation_definitions_where_fragment_used(fragment_definition),
-                        ),
-                    };
-                operation_definitions.flat_map(|operation_definition| {
-                    variable_usages.iter().filter_map(|variable_usage| {
-                        let VariableUsage { variable, .. } = variable_usage;
-                        let variable_definition = operation_definition
-                            .as_ref()
-                            .variable_definitions()
-                            .and_then(|variable_definitions| {
-                                variable_definitions.iter().find(|variable_definition| {
-                                    variable_definition.variable() == variable.name()
+                // uses of variables of a fragment are checked once against the fragment itself,
+                // and all other uses once for every operation the fragment is used in
+                let (fragment_variable_usages, operation_variable_usages): (Vec<_>, Vec<_>) =
+                    variable_usages
+                        .iter()
+                        .partition(|VariableUsage { variable, .. }| {
+                            root.fragment_variable_definition(variable.name()).is_some()
+                        });
+                let fragment_variable_usage_errors = fragment_variable_usages
+                    .into_iter()
+                    .filter_map(|variable_usage| {
+                        root.fragment_variable_definition(variable_usage.variable.name())
+                            .and_then(|variable_definition| {
+                                rule.validate_variable_usage(variable_definition, variable_usage)
+                                    .err()
+                            })
+                    });
+                let operation_variable_usage_errors = self
+                    .operation_definitions_in_scope(root)
+                    .flat_map(move |operation_definition| {
+                        operation_variable_usages.clone().into_iter().filter_map(
+                            move |variable_usage| {
+                                operation_variable_definition::<E>(
+                                    operation_definition,
+                                    variable_usage.variable.name(),
+                                )
+                                .and_then(|variable_definition| {
+                                    rule.validate_variable_usage(
+                                        variable_definition,
+                                        variable_usage,
+                                    )
+                                    .err()
                                 })
-                            });
+                            },
+                        )
+                    });
+                fragment_variable_usage_errors.chain(operation_variable_usage_errors)
+            });
 
-                        variable_definition.and_then(|variable_definition| {
-                            self.validate_variable_usage(variable_definition, variable_usage)
+        let fragment_argument_usage_errors =
+            self.fragment_argument_usages
+                .iter()
+                .flat_map(|(root, fragment_argument_usages)| {
+                    fragment_argument_usages
+                        .iter()
+                        .flat_map(move |fragment_argument_usage| {
+                            let variable_name = fragment_argument_usage.variable.name();
+                            let variable_definitions =
+                                match root.fragment_variable_definition(variable_name) {
+                                    Some(variable_definition) => {
+                                        Either::Left(std::iter::once(variable_definition))
+                                    }
+                                    None => Either::Right(
+                                        rule.operation_definitions_in_scope(root).filter_map(
+                                            move |operation_definition| {
+                                                operation_variable_definition::<E>(
+                                                    operation_definition,
+                                                    variable_name,
+                                                )
+                                            },
+                                        ),
+                                    ),
+                                };
+                            variable_definitions.filter_map(move |variable_definition| {
+                                rule.validate_fragment_argument_usage(
+                                    variable_definition,
+                                    fragment_argument_usage,
+                                )
                                 .err()
+                            })
                         })
-                    })
-                })
-            })
+                });
+
+        variable_usage_errors
+            .chain(fragment_argument_usage_errors)
             .collect::<Vec<Error<'a, E, S>>>()
             .into_iter()
     }
 }
 
+fn operation_variable_definition<'a, E: ExecutableDocument + 'a>(
+    operation_definition: &'a E::OperationDefinition,
+    name: &str,
+) -> Option<&'a E::VariableDefinition> {
+    operation_definition
+        .as_ref()
+        .variable_definitions()
+        .and_then(|variable_definitions| {
+            variable_definitions
+                .iter()
+                .find(|variable_definition| variable_definition.variable() == name)
+        })
+}
+
+fn are_variable_types_compatible<E: ExecutableDocument>(
+    variable_type: VariableTypeReference<'_, E::VariableType>,
+    argument_type: VariableTypeReference<'_, E::VariableType>,
+) -> bool {
+    match (variable_type, argument_type) {
+        (
+            VariableTypeReference::List(item_variable_type, variable_required),
+            VariableTypeReference::List(item_argument_type, argument_required),
+        ) if variable_required || !argument_required => are_variable_types_compatible::<E>(
+            item_variable_type.as_ref(),
+            item_argument_type.as_ref(),
+        ),
+        (
+            VariableTypeReference::Named(variable_type_name, variable_required),
+            VariableTypeReference::Named(argument_type_name, argument_required),
+        ) if variable_required || !argument_required => variable_type_name == argument_type_name,
+        _ => false,
+    }
+}
+
 #[derive(Debug)]
 enum VariableUsageLocation<'a, S: SchemaDefinition> {
     Argument(&'a S::InputValueDefinition),
@@ -313,3 +477,8 @@ struct VariableUsage<'a, E: ExecutableDocument, S: SchemaDefinition> {
     variable: &'a <E::Value<false> as Value<false>>::Variable,
     location: VariableUsageLocation<'a, S>,
 }
+
+struct FragmentArgumentUsage<'a, E: ExecutableDocument> {
+    variable: &'a <E::Value<false> as Value<false>>::Variable,
+    fragment_variable_definition: &'a E::VariableDefinition,
+}
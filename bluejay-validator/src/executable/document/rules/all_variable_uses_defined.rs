@@ -49,6 +49,11 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
                 .or_default()
                 .insert(*path.root());
         }
+        if let Some(arguments) = fragment_spread.arguments() {
+            arguments
+                .iter()
+                .for_each(|argument| self.visit_value(argument.value(), *path.root()));
+        }
     }
 }
 
@@ -60,7 +65,10 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition> AllVariableUsesDefined<'a,
     ) {
         match value.as_ref() {
             ValueReference::Variable(v) => {
-                self.variable_usages.entry(root).or_default().push(v);
+                // uses of variables of the fragment are defined by the fragment itself
+                if root.fragment_variable_definition(v.name()).is_none() {
+                    self.variable_usages.entry(root).or_default().push(v);
+                }
             }
             ValueReference::List(l) => l.iter().for_each(|value| self.visit_value(value, root)),
             ValueReference::Object(o) => o
@@ -4,7 +4,7 @@ use crate::executable::{
 };
 use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
 use bluejay_core::executable::{
-    ExecutableDocument, FragmentSpread, OperationDefinition, VariableDefinition,
+    ExecutableDocument, FragmentDefinition, FragmentSpread, OperationDefinition, VariableDefinition,
 };
 use bluejay_core::{Argument, AsIter, Indexed, ObjectValue, Value, ValueReference, Variable};
 use std::collections::{HashMap, HashSet};
@@ -13,6 +13,7 @@ use std::ops::Not;
 pub struct AllVariablesUsed<'a, E: ExecutableDocument, S: SchemaDefinition> {
     fragment_references: HashMap<PathRoot<'a, E>, HashSet<Indexed<'a, E::FragmentDefinition>>>,
     variable_usages: HashMap<PathRoot<'a, E>, HashSet<&'a str>>,
+    fragment_variable_usages: HashMap<Indexed<'a, E::FragmentDefinition>, HashSet<&'a str>>,
     cache: &'a Cache<'a, E, S>,
     executable_document: &'a E,
 }
@@ -24,6 +25,7 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
         Self {
             fragment_references: HashMap::new(),
             variable_usages: HashMap::new(),
+            fragment_variable_usages: HashMap::new(),
             cache,
             executable_document,
         }
@@ -50,6 +52,11 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
                 .or_default()
                 .insert(Indexed(fragment_definition));
         }
+        if let Some(arguments) = fragment_spread.arguments() {
+            arguments
+                .iter()
+                .for_each(|argument| self.visit_value(argument.value(), *path.root()));
+        }
     }
 }
 
@@ -60,12 +67,20 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition> AllVariablesUsed<'a, E, S>
         root: PathRoot<'a, E>,
     ) {
         match value.as_ref() {
-            ValueReference::Variable(v) => {
-                self.variable_usages
-                    .entry(root)
-                    .or_default()
-                    .insert(v.name());
-            }
+            ValueReference::Variable(v) => match root {
+                PathRoot::Fragment(f) if root.fragment_variable_definition(v.name()).is_some() => {
+                    self.fragment_variable_usages
+                        .entry(Indexed(f))
+                        .or_default()
+                        .insert(v.name());
+                }
+                _ => {
+                    self.variable_usages
+                        .entry(root)
+                        .or_default()
+                        .insert(v.name());
+                }
+            },
             ValueReference::List(l) => l.iter().for_each(|value| self.visit_value(value, root)),
             ValueReference::Object(o) => o
                 .iter()
@@ -149,6 +164,25 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Rule<'a, E, S>
                     .into_iter()
                     .flatten()
             })
+            .chain(self.executable_document.fragment_definitions().flat_map(
+                |fragment_definition| {
+                    let variable_usages = self
+                        .fragment_variable_usages
+                        .get(&Indexed(fragment_definition));
+                    fragment_definition
+                        .variable_definitions()
+                        .into_iter()
+                        .flat_map(|variable_definitions| variable_definitions.iter())
+                        .filter(move |variable_definition| {
+                            variable_usages.is_none_or(|variable_usages| {
+                                !variable_usages.contains(variable_definition.variable())
+                            })
+                        })
+                        .map(|variable_definition| Error::VariableDefinitionUnused {
+                            variable_definition,
+                        })
+                },
+            ))
             .collect::<Vec<Error<'a, E, S>>>()
             .into_iter()
     }
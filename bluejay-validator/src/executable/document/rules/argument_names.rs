@@ -2,14 +2,19 @@ use crate::executable::{
     document::{ArgumentError, Error, Path, Rule, Visitor},
     Cache,
 };
+use crate::utils::suggestion_list;
 use bluejay_core::definition::{
     DirectiveDefinition, FieldDefinition, InputValueDefinition, SchemaDefinition,
+    TypeDefinitionReference,
+};
+use bluejay_core::executable::{
+    ExecutableDocument, Field, FragmentDefinition, FragmentSpread, VariableDefinition,
 };
-use bluejay_core::executable::{ExecutableDocument, Field};
 use bluejay_core::{Argument, AsIter, Directive};
 
 pub struct ArgumentNames<'a, E: ExecutableDocument, S: SchemaDefinition> {
     schema_definition: &'a S,
+    cache: &'a Cache<'a, E, S>,
     errors: Vec<Error<'a, E, S>>,
 }
 
@@ -29,17 +34,21 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> ArgumentNames<'a,
             self.visit_arguments(
                 Some(arguments),
                 directive_definition.arguments_definition(),
-                |argument| {
+                |argument, suggestions| {
                     build_error(ArgumentError::ArgumentDoesNotExistOnDirective {
                         argument,
                         directive_definition,
+                        suggestions,
                     })
                 },
             )
         }
     }
 
-    fn visit_arguments<const CONST: bool, F: Fn(&'a E::Argument<CONST>) -> Error<'a, E, S>>(
+    fn visit_arguments<
+        const CONST: bool,
+        F: Fn(&'a E::Argument<CONST>, Vec<&'a str>) -> Error<'a, E, S>,
+    >(
         &mut self,
         arguments: Option<&'a E::Arguments<CONST>>,
         arguments_definition: Option<&'a S::ArgumentsDefinition>,
@@ -52,7 +61,16 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> ArgumentNames<'a,
                         .iter()
                         .find(|ivd| ivd.name() == argument.name())
                 });
-                argument_definition.is_none().then(|| build_error(argument))
+                argument_definition.is_none().then(|| {
+                    let suggestions = suggestion_list(
+                        argument.name(),
+                        arguments_definition
+                            .into_iter()
+                            .flat_map(|arguments_definition| arguments_definition.iter())
+                            .map(InputValueDefinition::name),
+                    );
+                    build_error(argument, suggestions)
+                })
             }))
         }
     }
@@ -61,9 +79,10 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> ArgumentNames<'a,
 impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
     for ArgumentNames<'a, E, S>
 {
-    fn new(_: &'a E, schema_definition: &'a S, _: &'a Cache<'a, E, S>) -> Self {
+    fn new(_: &'a E, schema_definition: &'a S, cache: &'a Cache<'a, E, S>) -> Self {
         Self {
             schema_definition,
+            cache,
             errors: Vec::new(),
         }
     }
@@ -77,15 +96,53 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
         self.visit_arguments(
             field.arguments(),
             field_definition.arguments_definition(),
-            |argument| {
+            |argument, suggestions| {
                 Error::InvalidVariableArgument(ArgumentError::ArgumentDoesNotExistOnField {
                     argument,
                     field_definition,
+                    suggestions,
                 })
             },
         )
     }
 
+    fn visit_fragment_spread(
+        &mut self,
+        fragment_spread: &'a E::FragmentSpread,
+        _: TypeDefinitionReference<'a, S::TypeDefinition>,
+        _: &Path<'a, E>,
+    ) {
+        let (Some(arguments), Some(fragment_definition)) = (
+            fragment_spread.arguments(),
+            self.cache.fragment_definition(fragment_spread.name()),
+        ) else {
+            return;
+        };
+        let variable_definitions = fragment_definition.variable_definitions();
+
+        self.errors.extend(arguments.iter().filter_map(|argument| {
+            let variable_definition = variable_definitions.and_then(|variable_definitions| {
+                variable_definitions
+                    .iter()
+                    .find(|vd| vd.variable() == argument.name())
+            });
+            variable_definition.is_none().then(|| {
+                let suggestions = suggestion_list(
+                    argument.name(),
+                    variable_definitions
+                        .into_iter()
+                        .flat_map(|variable_definitions| variable_definitions.iter())
+                        .map(VariableDefinition::variable),
+                );
+                Error::InvalidVariableArgument(ArgumentError::ArgumentDoesNotExistOnFragment {
+                    argument,
+                    fragment_definition,
+                    suggestions,
+                })
+            })
+        }))
+    }
+
     fn visit_variable_directive(
         &mut self,
         directive: &'a <E as ExecutableDocument>::Directive<false>,
@@ -3,8 +3,8 @@ use crate::executable::{
     Cache,
 };
 use crate::utils::duplicates;
-use bluejay_core::definition::SchemaDefinition;
-use bluejay_core::executable::{ExecutableDocument, Field};
+use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::executable::{ExecutableDocument, Field, FragmentSpread};
 use bluejay_core::{Argument, AsIter, Directive};
 
 pub struct ArgumentUniqueness<'a, E: ExecutableDocument, S: SchemaDefinition> {
@@ -47,6 +47,15 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
         self.visit_arguments(field.arguments(), Error::InvalidVariableArgument)
     }
 
+    fn visit_fragment_spread(
+        &mut self,
+        fragment_spread: &'a E::FragmentSpread,
+        _: TypeDefinitionReference<'a, S::TypeDefinition>,
+        _: &Path<'a, E>,
+    ) {
+        self.visit_arguments(fragment_spread.arguments(), Error::InvalidVariableArgument)
+    }
+
     fn visit_variable_directive(
         &mut self,
         directive: &'a <E as ExecutableDocument>::Directive<false>,
@@ -0,0 +1,78 @@
+use crate::executable::{
+    document::{Error, Rule, Visitor},
+    Cache,
+};
+use crate::utils::duplicates;
+use bluejay_core::definition::{DirectiveLocation, SchemaDefinition};
+use bluejay_core::executable::ExecutableDocument;
+use bluejay_core::{Argument, AsIter, Directive, Value, ValueReference};
+
+const DEFER_STREAM_DIRECTIVE_NAMES: [&str; 2] = ["defer", "stream"];
+const LABEL_ARGUMENT_NAME: &str = "label";
+
+/// The `label` of every `@defer` and `@stream` in the document must be a string literal that
+/// is unique across the document, as it identifies the incremental payloads of the directive.
+pub struct DeferStreamLabels<'a, E: ExecutableDocument, S: SchemaDefinition> {
+    labeled_directives: Vec<(&'a str, &'a E::Directive<false>)>,
+    errors: Vec<Error<'a, E, S>>,
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
+    for DeferStreamLabels<'a, E, S>
+{
+    fn new(_: &'a E, _: &'a S, _: &'a Cache<'a, E, S>) -> Self {
+        Self {
+            labeled_directives: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    fn visit_variable_directive(
+        &mut self,
+        directive: &'a <E as ExecutableDocument>::Directive<false>,
+        _: DirectiveLocation,
+    ) {
+        if !DEFER_STREAM_DIRECTIVE_NAMES.contains(&directive.name()) {
+            return;
+        }
+
+        let Some(label) = directive.arguments().and_then(|arguments| {
+            arguments
+                .iter()
+                .find(|argument| argument.name() == LABEL_ARGUMENT_NAME)
+        }) else {
+            return;
+        };
+
+        match label.value().as_ref() {
+            ValueReference::String(label) => self.labeled_directives.push((label, directive)),
+            ValueReference::Variable(_) => self
+                .errors
+                .push(Error::DeferStreamLabelNotStatic { directive }),
+            // any other value is reported by `ValueIsValid`
+            _ => {}
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Rule<'a, E, S>
+    for DeferStreamLabels<'a, E, S>
+{
+    type Error = Error<'a, E, S>;
+    type Errors = std::vec::IntoIter<Error<'a, E, S>>;
+
+    fn into_errors(mut self) -> Self::Errors {
+        self.errors.extend(
+            duplicates(self.labeled_directives.into_iter(), |(label, _)| label).map(
+                |(label, labeled_directives)| Error::NonUniqueDeferStreamLabels {
+                    label,
+                    directives: labeled_directives
+                        .into_iter()
+                        .map(|(_, directive)| directive)
+                        .collect(),
+                },
+            ),
+        );
+        self.errors.into_iter()
+    }
+}
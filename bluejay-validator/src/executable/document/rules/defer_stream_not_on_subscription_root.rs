@@ -0,0 +1,122 @@
+use crate::executable::{
+    document::{Error, Rule, Visitor},
+    Cache,
+};
+use bluejay_core::definition::SchemaDefinition;
+use bluejay_core::executable::{
+    ExecutableDocument, FragmentDefinition, FragmentSpread, InlineFragment, OperationDefinition,
+    Selection, SelectionReference,
+};
+use bluejay_core::{Argument, AsIter, Directive, OperationType, Value, ValueReference};
+use std::collections::HashSet;
+
+const DEFER_STREAM_DIRECTIVE_NAMES: [&str; 2] = ["defer", "stream"];
+const IF_ARGUMENT_NAME: &str = "if";
+
+/// Root selections of a subscription, including those of fragments spread at the root, must
+/// not use `@defer` or `@stream`, unless their `if` argument can be `false`, as a subscription
+/// event must be delivered as a single payload.
+pub struct DeferStreamNotOnSubscriptionRoot<'a, E: ExecutableDocument, S: SchemaDefinition> {
+    cache: &'a Cache<'a, E, S>,
+    errors: Vec<Error<'a, E, S>>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition> Visitor<'a, E, S>
+    for DeferStreamNotOnSubscriptionRoot<'a, E, S>
+{
+    fn new(_: &'a E, _: &'a S, cache: &'a Cache<'a, E, S>) -> Self {
+        Self {
+            cache,
+            errors: Vec::new(),
+        }
+    }
+
+    fn visit_operation_definition(&mut self, operation_definition: &'a E::OperationDefinition) {
+        let core_operation_definition = operation_definition.as_ref();
+        if core_operation_definition.operation_type() == OperationType::Subscription {
+            self.visit_root_selection_set(
+                operation_definition,
+                core_operation_definition.selection_set(),
+                &mut HashSet::new(),
+            );
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition> DeferStreamNotOnSubscriptionRoot<'a, E, S> {
+    fn visit_root_selection_set(
+        &mut self,
+        operation_definition: &'a E::OperationDefinition,
+        selection_set: &'a E::SelectionSet,
+        visited_fragment_names: &mut HashSet<&'a str>,
+    ) {
+        selection_set.iter().for_each(|selection| {
+            if let Some(directives) = selection.as_ref().directives() {
+                self.errors.extend(
+                    directives
+                        .iter()
+                        .filter(|directive| {
+                            DEFER_STREAM_DIRECTIVE_NAMES.contains(&directive.name())
+                                && !Self::can_be_disabled(directive)
+                        })
+                        .map(|directive| Error::DeferStreamOnSubscriptionRoot {
+                            operation: operation_definition,
+                            directive,
+                        }),
+                );
+            }
+
+            match selection.as_ref() {
+                SelectionReference::Field(_) => {}
+                SelectionReference::FragmentSpread(fragment_spread) => {
+                    if visited_fragment_names.insert(fragment_spread.name()) {
+                        if let Some(fragment_definition) =
+                            self.cache.fragment_definition(fragment_spread.name())
+                        {
+                            self.visit_root_selection_set(
+                                operation_definition,
+                                fragment_definition.selection_set(),
+                                visited_fragment_names,
+                            );
+                        }
+                    }
+                }
+                SelectionReference::InlineFragment(inline_fragment) => {
+                    self.visit_root_selection_set(
+                        operation_definition,
+                        inline_fragment.selection_set(),
+                        visited_fragment_names,
+                    );
+                }
+            }
+        });
+    }
+
+    /// Whether the `if` argument of the directive is `false` or a variable.
+    fn can_be_disabled(directive: &'a E::Directive<false>) -> bool {
+        directive
+            .arguments()
+            .and_then(|arguments| {
+                arguments
+                    .iter()
+                    .find(|argument| argument.name() == IF_ARGUMENT_NAME)
+            })
+            .is_some_and(|argument| {
+                matches!(
+                    argument.value().as_ref(),
+                    ValueReference::Boolean(false) | ValueReference::Variable(_)
+                )
+            })
+    }
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Rule<'a, E, S>
+    for DeferStreamNotOnSubscriptionRoot<'a, E, S>
+{
+    type Error = Error<'a, E, S>;
+    type Errors = std::vec::IntoIter<Error<'a, E, S>>;
+
+    fn into_errors(self) -> Self::Errors {
+        self.errors.into_iter()
+    }
+}
@@ -1,5 +1,5 @@
 use crate::executable::{
-    document::{Error, Rule, Visitor},
+    document::{Error, PathRoot, Rule, Visitor},
     Cache,
 };
 use bluejay_core::definition::{
@@ -10,7 +10,9 @@ use bluejay_core::executable::{
     ExecutableDocument, Field, FragmentDefinition, FragmentSpread, InlineFragment, Selection,
     SelectionReference,
 };
-use bluejay_core::{Arguments, AsIter, Indexed};
+use bluejay_core::{
+    Argument, Arguments, AsIter, Indexed, ObjectValue, Value, ValueReference, Variable,
+};
 use std::collections::{BTreeMap, HashMap};
 use std::ops::Not;
 
@@ -205,7 +207,8 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> FieldSelectionMer
             } else if !<E::Arguments<false> as Arguments<false>>::equivalent(
                 first.field.arguments(),
                 other.field.arguments(),
-            ) {
+            ) || !self.same_fragment_variable_values(first, other)
+            {
                 Some(Error::FieldSelectionsDoNotMergeDifferingArguments {
                     selection_set,
                     field_a: first.field,
@@ -236,7 +239,7 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> FieldSelectionMer
         parent_type: TypeDefinitionReference<'a, S::TypeDefinition>,
     ) -> HashMap<&'a str, Vec<FieldContext<'a, E, S>>> {
         let mut fields = HashMap::new();
-        self.visit_selections_for_fields(selection_set.iter(), &mut fields, parent_type, &[]);
+        self.visit_selections_for_fields(selection_set.iter(), &mut fields, parent_type, &[], None);
         fields
     }
 
@@ -260,6 +263,7 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> FieldSelectionMer
                             &mut fields,
                             parent_type,
                             &field_context.parent_fragments,
+                            field_context.fragment_spread,
                         );
                     }
                 }
@@ -274,6 +278,7 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> FieldSelectionMer
         fields: &mut HashMap<&'a str, Vec<FieldContext<'a, E, S>>>,
         parent_type: TypeDefinitionReference<'a, S::TypeDefinition>,
         parent_fragments: &[&'a str],
+        fragment_spread: Option<&'a E::FragmentSpread>,
     ) {
         selections.for_each(|selection| match selection.as_ref() {
             SelectionReference::Field(field) => {
@@ -289,6 +294,7 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> FieldSelectionMer
                             field_definition,
                             parent_type,
                             parent_fragments: parent_fragments.to_vec(),
+                            fragment_spread,
                         });
                 }
             }
@@ -314,6 +320,7 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> FieldSelectionMer
                                     fields,
                                     scoped_type,
                                     &new_parent_fragments,
+                                    Some(fs),
                                 );
                             }
                         }
@@ -334,6 +341,7 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> FieldSelectionMer
                             fields,
                             scoped_type,
                             parent_fragments,
+                            fragment_spread,
                         );
                     }
                 }
@@ -341,6 +349,61 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> FieldSelectionMer
         });
     }
 
+    /// Fields with equivalent arguments can still have different argument values when the
+    /// arguments use variables of the fragments the fields are selected in, which refer to the
+    /// arguments of the spreads of those fragments.
+    fn same_fragment_variable_values(
+        &self,
+        field_a: &FieldContext<'a, E, S>,
+        field_b: &FieldContext<'a, E, S>,
+    ) -> bool {
+        if !self.uses_fragment_variables(field_a) && !self.uses_fragment_variables(field_b) {
+            return true;
+        }
+
+        match (field_a.fragment_spread, field_b.fragment_spread) {
+            (Some(fragment_spread_a), Some(fragment_spread_b)) => {
+                fragment_spread_a.name() == fragment_spread_b.name()
+                    && <E::Arguments<false> as Arguments<false>>::equivalent(
+                        fragment_spread_a.arguments(),
+                        fragment_spread_b.arguments(),
+                    )
+            }
+            _ => false,
+        }
+    }
+
+    fn uses_fragment_variables(&self, field_context: &FieldContext<'a, E, S>) -> bool {
+        let Some(fragment_definition) = field_context
+            .fragment_spread
+            .and_then(|fragment_spread| self.cache.fragment_definition(fragment_spread.name()))
+        else {
+            return false;
+        };
+        let root = PathRoot::<E>::Fragment(fragment_definition);
+
+        field_context.field.arguments().is_some_and(|arguments| {
+            arguments.iter().any(|argument| {
+                Self::value_uses_variable(argument.value(), &|name| {
+                    root.fragment_variable_definition(name).is_some()
+                })
+            })
+        })
+    }
+
+    fn value_uses_variable(value: &'a E::Value<false>, predicate: &impl Fn(&str) -> bool) -> bool {
+        match value.as_ref() {
+            ValueReference::Variable(variable) => predicate(variable.name()),
+            ValueReference::List(l) => l
+                .iter()
+                .any(|value| Self::value_uses_variable(value, predicate)),
+            ValueReference::Object(o) => o
+                .iter()
+                .any(|(_, value)| Self::value_uses_variable(value, predicate)),
+            _ => false,
+        }
+    }
+
     fn same_output_type_shape(
         schema_definition: &S,
         type_a: &S::OutputType,
@@ -386,4 +449,6 @@ struct FieldContext<'a, E: ExecutableDocument, S: SchemaDefinition> {
     field_definition: &'a S::FieldDefinition,
     parent_type: TypeDefinitionReference<'a, S::TypeDefinition>,
     parent_fragments: Vec<&'a str>,
+    /// The spread of the innermost fragment the field is selected in, if any.
+    fragment_spread: Option<&'a E::FragmentSpread>,
 }
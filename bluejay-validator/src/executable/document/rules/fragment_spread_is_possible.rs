@@ -100,6 +100,13 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition> FragmentSpreadIsPossible<'a
         t: TypeDefinitionReference<'a, S::TypeDefinition>,
         name: &str,
     ) -> bool {
+        if let Some(possible_type_names) = self
+            .cache
+            .schema_cache()
+            .and_then(|schema_cache| schema_cache.possible_type_names(t.name()))
+        {
+            return possible_type_names.contains(name);
+        }
         match t {
             TypeDefinitionReference::Object(_) => t.name() == name,
             TypeDefinitionReference::Interface(itd) => self
@@ -124,7 +131,18 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition> FragmentSpreadIsPossible<'a
             | (other, TypeDefinitionReference::Object(o)) => {
                 self.type_contains_name(other, ObjectTypeDefinition::name(o))
             }
-            // Both are abstract — collect b's possible types once, then check a's against it
+            // Both are abstract — intersect the precomputed sets when there is a schema cache
+            _ if self.cache.schema_cache().is_some() => {
+                let possible_type_names = |t: TypeDefinitionReference<'a, S::TypeDefinition>| {
+                    self.cache
+                        .schema_cache()
+                        .and_then(|schema_cache| schema_cache.possible_type_names(t.name()))
+                };
+                possible_type_names(a)
+                    .zip(possible_type_names(b))
+                    .is_some_and(|(a_names, b_names)| !a_names.is_disjoint(b_names))
+            }
+            // Otherwise collect b's possible types once, then check a's against it
             _ => {
                 let b_names: Vec<&str> = self.possible_type_names(b).collect();
                 self.possible_type_names(a)
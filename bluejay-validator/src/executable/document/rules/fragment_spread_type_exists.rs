@@ -2,6 +2,7 @@ use crate::executable::{
     document::{Error, Rule, Visitor},
     Cache,
 };
+use crate::utils::suggestion_list;
 use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
 use bluejay_core::executable::{ExecutableDocument, FragmentDefinition, InlineFragment};
 
@@ -10,6 +11,17 @@ pub struct FragmentSpreadTypeExists<'a, E: ExecutableDocument, S: SchemaDefiniti
     schema_definition: &'a S,
 }
 
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> FragmentSpreadTypeExists<'a, E, S> {
+    fn suggested_type_names(&self, type_condition: &str) -> Vec<&'a str> {
+        suggestion_list(
+            type_condition,
+            self.schema_definition
+                .type_definitions()
+                .map(|type_definition| type_definition.name()),
+        )
+    }
+}
+
 impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
     for FragmentSpreadTypeExists<'a, E, S>
 {
@@ -32,6 +44,7 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
             self.errors
                 .push(Error::FragmentDefinitionTargetTypeDoesNotExist {
                     fragment_definition,
+                    suggestions: self.suggested_type_names(fragment_definition.type_condition()),
                 });
         }
     }
@@ -48,7 +61,10 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
                 .is_none()
             {
                 self.errors
-                    .push(Error::InlineFragmentTargetTypeDoesNotExist { inline_fragment })
+                    .push(Error::InlineFragmentTargetTypeDoesNotExist {
+                        inline_fragment,
+                        suggestions: self.suggested_type_names(type_condition),
+                    })
             }
         }
     }
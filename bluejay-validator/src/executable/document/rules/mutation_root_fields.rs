@@ -0,0 +1,188 @@
+use crate::executable::{
+    document::{Error, Rule, Visitor},
+    Cache,
+};
+use bluejay_core::definition::SchemaDefinition;
+use bluejay_core::executable::{
+    ExecutableDocument, Field, FragmentDefinition, FragmentSpread, InlineFragment,
+    OperationDefinition, Selection, SelectionReference,
+};
+use bluejay_core::{AsIter, OperationType};
+use std::collections::HashSet;
+
+/// Configures the root fields allowed by [`MutationRootFields`].
+pub trait MutationRootFieldPolicy {
+    /// The maximum number of root fields of a mutation, counted by response name, or `None` for
+    /// no limit.
+    fn max_root_fields(&self) -> Option<usize> {
+        None
+    }
+
+    /// Whether the mutation field with the given name must be the only root field of a mutation.
+    fn is_exclusive_root_field(&self, _field_name: &str) -> bool {
+        false
+    }
+}
+
+/// A [`MutationRootFieldPolicy`] configured at runtime, e.g. for the `MutationRootFields` rule
+/// of a [`RuleRegistry`](crate::executable::document::RuleRegistry). The default configuration
+/// allows any root fields.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MutationRootFieldConfig {
+    pub max_root_fields: Option<usize>,
+    pub exclusive_root_fields: Vec<String>,
+}
+
+impl MutationRootFieldPolicy for MutationRootFieldConfig {
+    fn max_root_fields(&self) -> Option<usize> {
+        self.max_root_fields
+    }
+
+    fn is_exclusive_root_field(&self, field_name: &str) -> bool {
+        self.exclusive_root_fields
+            .iter()
+            .any(|exclusive_root_field| exclusive_root_field == field_name)
+    }
+}
+
+/// Enforces a [`MutationRootFieldPolicy`] on the root fields of mutations, including those of
+/// fragments spread at the root. This is not one of the builtin rules, as it is not part of the
+/// specification.
+///
+/// When run by an [`Orchestrator`](crate::executable::document::Orchestrator), the policy is
+/// constructed with [`Default`]. A policy only known at runtime can instead be given with
+/// [`MutationRootFields::with_policy`], or with a [`MutationRootFieldConfig`] set on a
+/// [`RuleRegistry`](crate::executable::document::RuleRegistry).
+///
+/// Fields with `@skip` or `@include` are counted, as variable values are not known. An
+/// exclusive field selected by several mutations through a shared fragment is reported once.
+pub struct MutationRootFields<'a, E: ExecutableDocument, S: SchemaDefinition, P> {
+    cache: &'a Cache<'a, E, S>,
+    errors: Vec<Error<'a, E, S>>,
+    reported_fields: Vec<&'a E::Field>,
+    policy: P,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, P: MutationRootFieldPolicy>
+    MutationRootFields<'a, E, S, P>
+{
+    pub fn with_policy(cache: &'a Cache<'a, E, S>, policy: P) -> Self {
+        Self {
+            cache,
+            errors: Vec::new(),
+            reported_fields: Vec::new(),
+            policy,
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, P: MutationRootFieldPolicy + Default>
+    Visitor<'a, E, S> for MutationRootFields<'a, E, S, P>
+{
+    fn new(_: &'a E, _: &'a S, cache: &'a Cache<'a, E, S>) -> Self {
+        Self::with_policy(cache, P::default())
+    }
+
+    fn visit_operation_definition(&mut self, operation_definition: &'a E::OperationDefinition) {
+        let core_operation_definition = operation_definition.as_ref();
+        if core_operation_definition.operation_type() != OperationType::Mutation {
+            return;
+        }
+
+        let mut root_fields = Vec::new();
+        self.collect_root_fields(
+            core_operation_definition.selection_set(),
+            &mut root_fields,
+            &mut HashSet::new(),
+        );
+
+        let response_names: HashSet<&str> = root_fields
+            .iter()
+            .map(|field| field.response_name())
+            .collect();
+
+        if let Some(limit) = self.policy.max_root_fields() {
+            if response_names.len() > limit {
+                self.errors.push(Error::MutationRootFieldLimitExceeded {
+                    operation: operation_definition,
+                    limit,
+                    field_count: response_names.len(),
+                });
+            }
+        }
+
+        if response_names.len() > 1 {
+            root_fields.into_iter().for_each(|field| {
+                if self.policy.is_exclusive_root_field(field.name())
+                    && !self
+                        .reported_fields
+                        .iter()
+                        .any(|reported_field| std::ptr::eq(*reported_field, field))
+                {
+                    self.reported_fields.push(field);
+                    self.errors.push(Error::MutationRootFieldNotExclusive {
+                        operation: operation_definition,
+                        field,
+                    });
+                }
+            });
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, P> MutationRootFields<'a, E, S, P> {
+    fn collect_root_fields(
+        &self,
+        selection_set: &'a E::SelectionSet,
+        root_fields: &mut Vec<&'a E::Field>,
+        visited_fragment_names: &mut HashSet<&'a str>,
+    ) {
+        selection_set
+            .iter()
+            .for_each(|selection| match selection.as_ref() {
+                SelectionReference::Field(field) => {
+                    if !root_fields
+                        .iter()
+                        .any(|root_field| std::ptr::eq(*root_field, field))
+                    {
+                        root_fields.push(field);
+                    }
+                }
+                SelectionReference::FragmentSpread(fragment_spread) => {
+                    if visited_fragment_names.insert(fragment_spread.name()) {
+                        if let Some(fragment_definition) =
+                            self.cache.fragment_definition(fragment_spread.name())
+                        {
+                            self.collect_root_fields(
+                                fragment_definition.selection_set(),
+                                root_fields,
+                                visited_fragment_names,
+                            );
+                        }
+                    }
+                }
+                SelectionReference::InlineFragment(inline_fragment) => {
+                    self.collect_root_fields(
+                        inline_fragment.selection_set(),
+                        root_fields,
+                        visited_fragment_names,
+                    );
+                }
+            });
+    }
+}
+
+impl<
+        'a,
+        E: ExecutableDocument + 'a,
+        S: SchemaDefinition + 'a,
+        P: MutationRootFieldPolicy + Default,
+    > Rule<'a, E, S> for MutationRootFields<'a, E, S, P>
+{
+    type Error = Error<'a, E, S>;
+    type Errors = std::vec::IntoIter<Error<'a, E, S>>;
+
+    fn into_errors(self) -> Self::Errors {
+        self.errors.into_iter()
+    }
+}
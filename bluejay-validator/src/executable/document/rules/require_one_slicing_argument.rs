@@ -0,0 +1,125 @@
+use crate::executable::{
+    document::{Error, Path, Rule, Visitor},
+    Cache,
+};
+use bluejay_core::definition::{prelude::*, DirectiveDefinition, HasDirectives, SchemaDefinition};
+use bluejay_core::executable::{ExecutableDocument, Field};
+use bluejay_core::{Argument, AsIter, Directive, Value, ValueReference};
+
+const LIST_SIZE_DIRECTIVE: &str = "listSize";
+const SLICING_ARGUMENTS_ARGUMENT: &str = "slicingArguments";
+const REQUIRE_ONE_SLICING_ARGUMENT_ARGUMENT: &str = "requireOneSlicingArgument";
+
+/// Requires exactly one of the `slicingArguments` of a field's `@listSize` directive to be passed,
+/// unless the directive sets `requireOneSlicingArgument: false`, as described by the
+/// [GraphQL Cost Directive specification](https://ibm.github.io/graphql-specs/cost-spec.html).
+/// This is not one of the builtin rules, as it is not part of the GraphQL specification.
+///
+/// When `requireOneSlicingArgument` is omitted, the default value from the schema's definition of
+/// `@listSize` is used, falling back to `true`. An argument passed as `null` is not counted, but a
+/// variable is, as variable values are not known.
+pub struct RequireOneSlicingArgument<'a, E: ExecutableDocument, S: SchemaDefinition> {
+    schema_definition: &'a S,
+    errors: Vec<Error<'a, E, S>>,
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
+    for RequireOneSlicingArgument<'a, E, S>
+{
+    fn new(_: &'a E, schema_definition: &'a S, _: &'a Cache<'a, E, S>) -> Self {
+        Self {
+            schema_definition,
+            errors: Vec::new(),
+        }
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a E::Field,
+        field_definition: &'a S::FieldDefinition,
+        _: &Path<'a, E>,
+    ) {
+        let Some(list_size) = field_definition.directives().and_then(|directives| {
+            directives
+                .iter()
+                .find(|directive| directive.name() == LIST_SIZE_DIRECTIVE)
+        }) else {
+            return;
+        };
+
+        let directive_argument = |name: &str| {
+            list_size.arguments().and_then(|arguments| {
+                arguments
+                    .iter()
+                    .find(|argument| argument.name() == name)
+                    .map(|argument| argument.value())
+            })
+        };
+
+        let slicing_arguments: Vec<&'a str> =
+            match directive_argument(SLICING_ARGUMENTS_ARGUMENT).map(|value| value.as_ref()) {
+                Some(ValueReference::List(values)) => values
+                    .iter()
+                    .filter_map(|value| match value.as_ref() {
+                        ValueReference::String(name) => Some(name),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => return,
+            };
+
+        let require_one_slicing_argument =
+            directive_argument(REQUIRE_ONE_SLICING_ARGUMENT_ARGUMENT)
+                .and_then(|value| value.as_ref().as_boolean().copied())
+                .or_else(|| {
+                    self.schema_definition
+                        .get_directive_definition(LIST_SIZE_DIRECTIVE)
+                        .and_then(|directive_definition| {
+                            directive_definition.arguments_definition()
+                        })
+                        .and_then(|arguments_definition| {
+                            arguments_definition.get(REQUIRE_ONE_SLICING_ARGUMENT_ARGUMENT)
+                        })
+                        .and_then(|argument_definition| argument_definition.default_value())
+                        .and_then(|value| value.as_ref().as_boolean().copied())
+                })
+                .unwrap_or(true);
+
+        if !require_one_slicing_argument || slicing_arguments.is_empty() {
+            return;
+        }
+
+        let passed_slicing_arguments: Vec<&'a str> = field
+            .arguments()
+            .map(|arguments| {
+                arguments
+                    .iter()
+                    .filter(|argument| {
+                        slicing_arguments.contains(&argument.name())
+                            && !matches!(argument.value().as_ref(), ValueReference::Null)
+                    })
+                    .map(|argument| argument.name())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if passed_slicing_arguments.len() != 1 {
+            self.errors.push(Error::SlicingArgumentCountInvalid {
+                field,
+                slicing_arguments,
+                passed_slicing_arguments,
+            });
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Rule<'a, E, S>
+    for RequireOneSlicingArgument<'a, E, S>
+{
+    type Error = Error<'a, E, S>;
+    type Errors = std::vec::IntoIter<Error<'a, E, S>>;
+
+    fn into_errors(self) -> Self::Errors {
+        self.errors.into_iter()
+    }
+}
@@ -4,12 +4,16 @@ use crate::executable::{
 };
 use bluejay_core::definition::{
     DirectiveDefinition, FieldDefinition, InputType, InputValueDefinition, SchemaDefinition,
+    TypeDefinitionReference,
+};
+use bluejay_core::executable::{
+    ExecutableDocument, Field, FragmentDefinition, FragmentSpread, VariableDefinition,
 };
-use bluejay_core::executable::{ExecutableDocument, Field};
 use bluejay_core::{Argument, AsIter, Directive};
 
 pub struct RequiredArguments<'a, E: ExecutableDocument, S: SchemaDefinition> {
     schema_definition: &'a S,
+    cache: &'a Cache<'a, E, S>,
     errors: Vec<Error<'a, E, S>>,
 }
 
@@ -71,9 +75,10 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> RequiredArguments
 impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
     for RequiredArguments<'a, E, S>
 {
-    fn new(_: &'a E, schema_definition: &'a S, _: &'a Cache<'a, E, S>) -> Self {
+    fn new(_: &'a E, schema_definition: &'a S, cache: &'a Cache<'a, E, S>) -> Self {
         Self {
             schema_definition,
+            cache,
             errors: Vec::new(),
         }
     }
@@ -97,6 +102,39 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
         )
     }
 
+    fn visit_fragment_spread(
+        &mut self,
+        fragment_spread: &'a E::FragmentSpread,
+        _: TypeDefinitionReference<'a, S::TypeDefinition>,
+        _: &Path<'a, E>,
+    ) {
+        let Some(fragment_definition) = self.cache.fragment_definition(fragment_spread.name())
+        else {
+            return;
+        };
+        let Some(variable_definitions) = fragment_definition.variable_definitions() else {
+            return;
+        };
+        let arguments = fragment_spread.arguments();
+        let missing_variable_definitions: Vec<_> = variable_definitions
+            .iter()
+            .filter(|vd| {
+                vd.is_required()
+                    && !arguments
+                        .is_some_and(|args| args.iter().any(|arg| arg.name() == vd.variable()))
+            })
+            .collect();
+        if !missing_variable_definitions.is_empty() {
+            self.errors.push(Error::InvalidVariableArgument(
+                ArgumentError::FragmentSpreadMissingRequiredArguments {
+                    fragment_spread,
+                    fragment_definition,
+                    missing_variable_definitions,
+                },
+            ));
+        }
+    }
+
     fn visit_variable_directive(
         &mut self,
         directive: &'a <E as ExecutableDocument>::Directive<false>,
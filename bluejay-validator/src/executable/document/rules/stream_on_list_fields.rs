@@ -0,0 +1,62 @@
+use crate::executable::{
+    document::{Error, Path, Rule, Visitor},
+    Cache,
+};
+use bluejay_core::definition::{
+    FieldDefinition, OutputType, SchemaDefinition, ShallowOutputTypeReference,
+};
+use bluejay_core::executable::{ExecutableDocument, Field};
+use bluejay_core::{AsIter, Directive};
+
+const STREAM_DIRECTIVE_NAME: &str = "stream";
+
+/// `@stream` may only be used on fields whose type is a list.
+pub struct StreamOnListFields<'a, E: ExecutableDocument, S: SchemaDefinition> {
+    errors: Vec<Error<'a, E, S>>,
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
+    for StreamOnListFields<'a, E, S>
+{
+    fn new(_: &'a E, _: &'a S, _: &'a Cache<'a, E, S>) -> Self {
+        Self { errors: Vec::new() }
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        field_definition: &'a S::FieldDefinition,
+        _: &Path<'a, E>,
+    ) {
+        if matches!(
+            field_definition.r#type().as_shallow_ref(),
+            ShallowOutputTypeReference::List(..)
+        ) {
+            return;
+        }
+
+        if let Some(directives) = field.directives() {
+            self.errors.extend(
+                directives
+                    .iter()
+                    .filter(|directive| directive.name() == STREAM_DIRECTIVE_NAME)
+                    .map(|directive| Error::StreamOnNonListField {
+                        field,
+                        field_definition,
+                        directive,
+                    }),
+            );
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Rule<'a, E, S>
+    for StreamOnListFields<'a, E, S>
+{
+    type Error = Error<'a, E, S>;
+    type Errors = std::vec::IntoIter<Error<'a, E, S>>;
+
+    fn into_errors(self) -> Self::Errors {
+        self.errors.into_iter()
+    }
+}
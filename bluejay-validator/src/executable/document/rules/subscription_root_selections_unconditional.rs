@@ -0,0 +1,102 @@
+use crate::executable::{
+    document::{Error, Rule, Visitor},
+    Cache,
+};
+use bluejay_core::definition::SchemaDefinition;
+use bluejay_core::executable::{
+    ExecutableDocument, FragmentDefinition, FragmentSpread, InlineFragment, OperationDefinition,
+    Selection, SelectionReference,
+};
+use bluejay_core::{AsIter, Directive, OperationType};
+use std::collections::HashSet;
+
+const CONDITIONAL_DIRECTIVE_NAMES: [&str; 2] = ["skip", "include"];
+
+/// Root selections of a subscription, including those of fragments spread at the root, must
+/// not use `@skip` or `@include`, as the single root field could then be excluded.
+pub struct SubscriptionRootSelectionsUnconditional<'a, E: ExecutableDocument, S: SchemaDefinition> {
+    cache: &'a Cache<'a, E, S>,
+    errors: Vec<Error<'a, E, S>>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition> Visitor<'a, E, S>
+    for SubscriptionRootSelectionsUnconditional<'a, E, S>
+{
+    fn new(_: &'a E, _: &'a S, cache: &'a Cache<'a, E, S>) -> Self {
+        Self {
+            cache,
+            errors: Vec::new(),
+        }
+    }
+
+    fn visit_operation_definition(&mut self, operation_definition: &'a E::OperationDefinition) {
+        let core_operation_definition = operation_definition.as_ref();
+        if core_operation_definition.operation_type() == OperationType::Subscription {
+            self.visit_root_selection_set(
+                operation_definition,
+                core_operation_definition.selection_set(),
+                &mut HashSet::new(),
+            );
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition>
+    SubscriptionRootSelectionsUnconditional<'a, E, S>
+{
+    fn visit_root_selection_set(
+        &mut self,
+        operation_definition: &'a E::OperationDefinition,
+        selection_set: &'a E::SelectionSet,
+        visited_fragment_names: &mut HashSet<&'a str>,
+    ) {
+        selection_set.iter().for_each(|selection| {
+            if let Some(directives) = selection.as_ref().directives() {
+                self.errors.extend(
+                    directives
+                        .iter()
+                        .filter(|directive| CONDITIONAL_DIRECTIVE_NAMES.contains(&directive.name()))
+                        .map(|directive| Error::SubscriptionRootSelectionConditional {
+                            operation: operation_definition,
+                            directive,
+                        }),
+                );
+            }
+
+            match selection.as_ref() {
+                SelectionReference::Field(_) => {}
+                SelectionReference::FragmentSpread(fragment_spread) => {
+                    if visited_fragment_names.insert(fragment_spread.name()) {
+                        if let Some(fragment_definition) =
+                            self.cache.fragment_definition(fragment_spread.name())
+                        {
+                            self.visit_root_selection_set(
+                                operation_definition,
+                                fragment_definition.selection_set(),
+                                visited_fragment_names,
+                            );
+                        }
+                    }
+                }
+                SelectionReference::InlineFragment(inline_fragment) => {
+                    self.visit_root_selection_set(
+                        operation_definition,
+                        inline_fragment.selection_set(),
+                        visited_fragment_names,
+                    );
+                }
+            }
+        });
+    }
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Rule<'a, E, S>
+    for SubscriptionRootSelectionsUnconditional<'a, E, S>
+{
+    type Error = Error<'a, E, S>;
+    type Errors = std::vec::IntoIter<Error<'a, E, S>>;
+
+    fn into_errors(self) -> Self::Errors {
+        self.errors.into_iter()
+    }
+}
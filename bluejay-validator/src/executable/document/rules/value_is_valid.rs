@@ -3,9 +3,11 @@ use crate::executable::{
     Cache,
 };
 use crate::value::input_coercion::CoerceInput;
-use bluejay_core::definition::{InputValueDefinition, SchemaDefinition};
-use bluejay_core::executable::{ExecutableDocument, VariableDefinition};
-use bluejay_core::Argument;
+use bluejay_core::definition::{InputValueDefinition, SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::executable::{
+    ExecutableDocument, FragmentDefinition, FragmentSpread, VariableDefinition,
+};
+use bluejay_core::{Argument, AsIter};
 
 pub struct ValueIsValid<'a, E: ExecutableDocument, S: SchemaDefinition> {
     schema_definition: &'a S,
@@ -45,6 +47,40 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
         }
     }
 
+    fn visit_fragment_spread(
+        &mut self,
+        fragment_spread: &'a E::FragmentSpread,
+        _: TypeDefinitionReference<'a, S::TypeDefinition>,
+        _: &Path<'a, E>,
+    ) {
+        let (Some(arguments), Some(variable_definitions)) = (
+            fragment_spread.arguments(),
+            self.cache
+                .fragment_definition(fragment_spread.name())
+                .and_then(FragmentDefinition::variable_definitions),
+        ) else {
+            return;
+        };
+
+        arguments.iter().for_each(|argument| {
+            let Some(input_type) = variable_definitions
+                .iter()
+                .find(|vd| vd.variable() == argument.name())
+                .and_then(|vd| self.cache.variable_definition_input_type(vd.r#type()))
+            else {
+                return;
+            };
+            if let Err(coercion_errors) = self.schema_definition.coerce_value(
+                input_type,
+                argument.value(),
+                Default::default(),
+            ) {
+                self.errors
+                    .extend(coercion_errors.into_iter().map(Error::InvalidVariableValue));
+            }
+        });
+    }
+
     fn visit_const_argument(
         &mut self,
         argument: &'a <E as ExecutableDocument>::Argument<true>,
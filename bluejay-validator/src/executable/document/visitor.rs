@@ -7,7 +7,9 @@ pub trait Visitor<'a, E: ExecutableDocument, S: SchemaDefinition> {
         executable_document: &'a E,
         schema_definition: &'a S,
         cache: &'a Cache<'a, E, S>,
-    ) -> Self;
+    ) -> Self
+    where
+        Self: Sized;
 
     fn visit_operation_definition(&mut self, _operation_definition: &'a E::OperationDefinition) {}
 
@@ -228,3 +230,5 @@ seq_macro::seq!(N in 2..=10 {
 });
 
 impl_visitor!(26);
+impl_visitor!(28);
+impl_visitor!(31);
@@ -1,10 +1,19 @@
 mod analyzer;
 pub mod analyzers;
+mod argument_values;
+pub mod execution_plan;
 mod orchestrator;
+#[cfg(feature = "serde_json")]
+mod response_validation;
 mod variable_values;
 mod visitor;
 
 pub use analyzer::Analyzer;
+pub use argument_values::{ArgumentValueError, CoercedArgumentValues};
 pub use orchestrator::{OperationResolutionError, Orchestrator};
-pub use variable_values::{OperationDefinitionValueEvaluationExt, VariableValues};
+#[cfg(feature = "serde_json")]
+pub use response_validation::{validate_response, ResponseError};
+pub use variable_values::{
+    CoercedVariableValues, OperationDefinitionValueEvaluationExt, VariableValues,
+};
 pub use visitor::Visitor;
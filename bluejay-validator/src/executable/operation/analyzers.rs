@@ -1,11 +1,37 @@
+#[cfg(feature = "serde_json")]
+mod actual_cost;
+mod authorization;
 pub mod complexity_cost;
 mod deprecation;
+mod field_amplification;
 mod input_size;
+mod introspection;
 mod query_depth;
+mod schema_usage;
+mod variable_hygiene;
 mod variable_values_are_valid;
 
-pub use complexity_cost::ComplexityCost;
+#[cfg(feature = "serde_json")]
+pub use actual_cost::{ActualCost, ActualCostReport, FieldActualCost};
+pub use authorization::{
+    Authorization, AuthorizationGrant, AuthorizationReport, MAX_REQUIRED_SCOPE_ALTERNATIVES,
+};
+pub use complexity_cost::{ComplexityCost, ExplainComplexityCost};
 pub use deprecation::Deprecation;
+pub use field_amplification::{
+    FieldAmplification, FieldAmplificationLimit, FieldAmplificationLimits,
+    FieldAmplificationOffender,
+};
 pub use input_size::InputSize;
-pub use query_depth::QueryDepth;
-pub use variable_values_are_valid::{VariableValueError, VariableValuesAreValid};
+pub use introspection::{Introspection, IntrospectionKind, IntrospectionReport};
+pub use query_depth::{
+    CountedFields, ExplainQueryDepth, QueryDepth, QueryDepthOptions, QueryDepthReport,
+};
+pub use schema_usage::{SchemaCoordinate, SchemaCoordinateUsage, SchemaUsage};
+pub use variable_hygiene::{
+    VariableFinding, VariableHygiene, VariableHygieneReport, VariableStatistics,
+    VariableValueSource,
+};
+pub use variable_values_are_valid::{
+    CoerceVariableValues, VariableValueError, VariableValuesAreValid,
+};
@@ -0,0 +1,595 @@
+use crate::{
+    executable::{
+        operation::{
+            analyzers::complexity_cost::{CostComputer, DefaultCostComputer},
+            Analyzer, VariableValues, Visitor,
+        },
+        Cache,
+    },
+    JsonPath,
+};
+use bluejay_core::definition::{
+    FieldDefinition, ObjectTypeDefinition, OutputType, SchemaDefinition, TypeDefinitionReference,
+    UnionMemberType, UnionTypeDefinition,
+};
+use bluejay_core::executable::{
+    ExecutableDocument, Field, FragmentDefinition, FragmentSpread, InlineFragment,
+    OperationDefinition, Selection, SelectionReference,
+};
+use bluejay_core::{AsIter, OperationType};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use std::collections::{HashMap, HashSet};
+
+const TYPENAME_FIELD: &str = "__typename";
+
+/// Computes the cost of an operation from the `data` of its response, using the weights of the
+/// same [`CostComputer`] as [`ComplexityCost`](super::ComplexityCost).
+///
+/// Where [`ComplexityCost`](super::ComplexityCost) multiplies by the estimated size of a list,
+/// this counts every item actually present in the response: a field costs its weight for each
+/// non-null value it resolved to (each item of a list), plus the cost of its arguments once per
+/// position in the response. Fields absent from the response cost nothing. Running both
+/// analyzers as a tuple yields the estimate and the actual cost in a single pass.
+///
+/// When the same response position is selected more than once, e.g. through fragments, it is
+/// only costed once. Object values are matched against the type conditions of the selections by
+/// their object type, which is the type of the field when it returns an object type, and
+/// otherwise the value of a selected `__typename`, under any response key. Selections under a
+/// type condition are not costed for objects whose type cannot be found, and are reported in
+/// [`ActualCostReport::unknown_type_paths`] instead.
+pub struct ActualCost<
+    'a,
+    E: ExecutableDocument,
+    S: SchemaDefinition,
+    V: VariableValues,
+    C: CostComputer<'a, E, S, V> = DefaultCostComputer,
+> {
+    schema_definition: &'a S,
+    cache: &'a Cache<'a, E, S>,
+    cost_computer: C,
+    path: JsonPath,
+    scopes_stack: Vec<ResponseScope<'a>>,
+    subtree_costs_stack: Vec<usize>,
+    costed_values: HashSet<*const JsonValue>,
+    fields: Vec<FieldActualCost>,
+    field_indices: HashMap<JsonPath, usize>,
+    unknown_type_paths: Vec<JsonPath>,
+    variable_values: std::marker::PhantomData<V>,
+}
+
+/// The values of a field in the response, with what is needed to find their object types.
+struct ResponseScope<'a> {
+    /// The name of the base type of the field.
+    type_name: &'a str,
+    is_object_type: bool,
+    /// The response keys of the `__typename` fields selected on the field.
+    typename_keys: Vec<&'a str>,
+    /// The non-null values of the field, with lists flattened.
+    instances: Vec<&'a JsonValue>,
+}
+
+impl<'a> ResponseScope<'a> {
+    fn object_type_name(&self, object: &'a JsonMap<String, JsonValue>) -> Option<&'a str> {
+        self.typename_keys
+            .iter()
+            .find_map(|typename_key| object.get(*typename_key))
+            .and_then(JsonValue::as_str)
+            .or(self.is_object_type.then_some(self.type_name))
+    }
+}
+
+impl<
+        'a,
+        E: ExecutableDocument,
+        S: SchemaDefinition,
+        V: VariableValues,
+        C: CostComputer<'a, E, S, V>,
+    > Visitor<'a, E, S, V> for ActualCost<'a, E, S, V, C>
+{
+    /// The `data` of the response.
+    type ExtraInfo = &'a JsonValue;
+
+    fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        variable_values: &'a V,
+        cache: &'a Cache<'a, E, S>,
+        data: Self::ExtraInfo,
+    ) -> Self {
+        let root_operation_type = match operation_definition.as_ref().operation_type() {
+            OperationType::Query => Some(schema_definition.query()),
+            OperationType::Mutation => schema_definition.mutation(),
+            OperationType::Subscription => schema_definition.subscription(),
+        };
+        let mut instance = Self {
+            schema_definition,
+            cache,
+            cost_computer: C::new(operation_definition, schema_definition, variable_values),
+            path: JsonPath::default(),
+            scopes_stack: Vec::new(),
+            subtree_costs_stack: vec![0],
+            costed_values: HashSet::new(),
+            fields: Vec::new(),
+            field_indices: HashMap::new(),
+            unknown_type_paths: Vec::new(),
+            variable_values: std::marker::PhantomData,
+        };
+        let mut typename_keys = Vec::new();
+        instance.collect_typename_keys(
+            operation_definition.as_ref().selection_set(),
+            &mut typename_keys,
+            &mut HashSet::new(),
+        );
+        instance.scopes_stack.push(ResponseScope {
+            type_name: root_operation_type.map_or("", ObjectTypeDefinition::name),
+            is_object_type: root_operation_type.is_some(),
+            typename_keys,
+            instances: vec![data],
+        });
+        instance
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        field_definition: &'a S::FieldDefinition,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if !included {
+            return;
+        }
+
+        let response_name = field.response_name();
+        let field_cost = self
+            .cost_computer
+            .cost_for_field_definition(field_definition);
+        let arguments_cost = self
+            .cost_computer
+            .cost_for_field_arguments(field_definition, field);
+
+        let mut parents = Vec::new();
+        let mut has_unknown_type = false;
+        if let Some(scope) = self.scopes_stack.last() {
+            scope
+                .instances
+                .iter()
+                .filter_map(|instance| instance.as_object())
+                .for_each(
+                    |parent| match self.matches_type_condition(scope, parent, scoped_type) {
+                        Some(true) => parents.push(parent),
+                        Some(false) => {}
+                        None => has_unknown_type = true,
+                    },
+                );
+        }
+
+        let mut own_cost = 0;
+        let mut count = 0;
+        let mut instances = Vec::new();
+
+        parents
+            .into_iter()
+            .filter_map(|parent| parent.get(response_name))
+            .for_each(|value| {
+                let first_visit = self.costed_values.insert(value as *const JsonValue);
+                let instances_before = instances.len();
+                Self::collect_instances(value, &mut instances);
+                if first_visit {
+                    let value_count = instances.len() - instances_before;
+                    count += value_count;
+                    own_cost += arguments_cost + field_cost * value_count;
+                }
+            });
+
+        self.path.push(response_name);
+        if has_unknown_type && !self.unknown_type_paths.contains(&self.path) {
+            self.unknown_type_paths.push(self.path.clone());
+        }
+        let index = *self
+            .field_indices
+            .entry(self.path.clone())
+            .or_insert_with(|| {
+                self.fields.push(FieldActualCost {
+                    path: self.path.clone(),
+                    count: 0,
+                    cost: 0,
+                    subtree_cost: 0,
+                });
+                self.fields.len() - 1
+            });
+        let field_actual_cost = &mut self.fields[index];
+        field_actual_cost.count += count;
+        field_actual_cost.cost += own_cost;
+
+        let type_name = field_definition.r#type().base_name();
+        let mut typename_keys = Vec::new();
+        if let Some(selection_set) = field.selection_set() {
+            self.collect_typename_keys(selection_set, &mut typename_keys, &mut HashSet::new());
+        }
+        self.scopes_stack.push(ResponseScope {
+            type_name,
+            is_object_type: matches!(
+                self.schema_definition.get_type_definition(type_name),
+                Some(TypeDefinitionReference::Object(_)),
+            ),
+            typename_keys,
+            instances,
+        });
+        self.subtree_costs_stack.push(own_cost);
+    }
+
+    fn leave_field(
+        &mut self,
+        _field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if !included {
+            return;
+        }
+
+        self.scopes_stack.pop();
+        let subtree_cost = self.subtree_costs_stack.pop().unwrap();
+        if let Some(index) = self.field_indices.get(&self.path) {
+            self.fields[*index].subtree_cost += subtree_cost;
+        }
+        if let Some(parent_subtree_cost) = self.subtree_costs_stack.last_mut() {
+            *parent_subtree_cost += subtree_cost;
+        }
+        self.path.pop();
+    }
+}
+
+impl<
+        'a,
+        E: ExecutableDocument,
+        S: SchemaDefinition,
+        V: VariableValues,
+        C: CostComputer<'a, E, S, V>,
+    > Analyzer<'a, E, S, V> for ActualCost<'a, E, S, V, C>
+{
+    type Output = ActualCostReport;
+
+    fn into_output(self) -> Self::Output {
+        ActualCostReport {
+            total_cost: self.subtree_costs_stack.first().copied().unwrap_or(0),
+            fields: self.fields,
+            unknown_type_paths: self.unknown_type_paths,
+        }
+    }
+}
+
+impl<
+        'a,
+        E: ExecutableDocument,
+        S: SchemaDefinition,
+        V: VariableValues,
+        C: CostComputer<'a, E, S, V>,
+    > ActualCost<'a, E, S, V, C>
+{
+    /// Whether the selections on `scoped_type` apply to `object`, or `None` when they are under
+    /// a type condition and the object type of `object` cannot be found.
+    fn matches_type_condition(
+        &self,
+        scope: &ResponseScope<'a>,
+        object: &'a JsonMap<String, JsonValue>,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) -> Option<bool> {
+        if scoped_type.name() == scope.type_name {
+            return Some(true);
+        }
+
+        scope
+            .object_type_name(object)
+            .map(|object_type_name| match scoped_type {
+                TypeDefinitionReference::Object(_) => scoped_type.name() == object_type_name,
+                TypeDefinitionReference::Interface(itd) => self
+                    .schema_definition
+                    .get_interface_implementors(itd)
+                    .any(|otd| otd.name() == object_type_name),
+                TypeDefinitionReference::Union(utd) => utd
+                    .union_member_types()
+                    .iter()
+                    .any(|union_member| union_member.name() == object_type_name),
+                _ => false,
+            })
+    }
+
+    /// Collects the response keys of the `__typename` fields of a selection set, including
+    /// those selected through fragments.
+    fn collect_typename_keys(
+        &self,
+        selection_set: &'a E::SelectionSet,
+        typename_keys: &mut Vec<&'a str>,
+        visited_fragment_names: &mut HashSet<&'a str>,
+    ) {
+        selection_set
+            .iter()
+            .for_each(|selection| match selection.as_ref() {
+                SelectionReference::Field(field) => {
+                    if field.name() == TYPENAME_FIELD {
+                        typename_keys.push(field.response_name());
+                    }
+                }
+                SelectionReference::FragmentSpread(fragment_spread) => {
+                    if visited_fragment_names.insert(fragment_spread.name()) {
+                        if let Some(fragment_definition) =
+                            self.cache.fragment_definition(fragment_spread.name())
+                        {
+                            self.collect_typename_keys(
+                                fragment_definition.selection_set(),
+                                typename_keys,
+                                visited_fragment_names,
+                            );
+                        }
+                    }
+                }
+                SelectionReference::InlineFragment(inline_fragment) => {
+                    self.collect_typename_keys(
+                        inline_fragment.selection_set(),
+                        typename_keys,
+                        visited_fragment_names,
+                    );
+                }
+            });
+    }
+
+    /// Collects the non-null values of a field, flattening lists.
+    fn collect_instances(value: &'a JsonValue, instances: &mut Vec<&'a JsonValue>) {
+        match value {
+            JsonValue::Null => {}
+            JsonValue::Array(values) => values
+                .iter()
+                .for_each(|value| Self::collect_instances(value, instances)),
+            _ => instances.push(value),
+        }
+    }
+}
+
+/// The output of [`ActualCost`].
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
+pub struct ActualCostReport {
+    pub total_cost: usize,
+    /// Every field selected by the operation, in the order first visited.
+    pub fields: Vec<FieldActualCost>,
+    /// Response paths of the fields selected under a type condition on objects whose object
+    /// type cannot be found, because the parent field returns an abstract type and no
+    /// `__typename` is selected on it. The field is not costed for those objects.
+    pub unknown_type_paths: Vec<JsonPath>,
+}
+
+/// The actual cost of a field, summed over all of its positions in the response.
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
+pub struct FieldActualCost {
+    /// The response names leading to this field, including its own.
+    pub path: JsonPath,
+    /// The number of non-null values the field resolved to, counting each item of a list.
+    pub count: usize,
+    /// The cost of the field itself, excluding its selections.
+    pub cost: usize,
+    /// The cost of the field including its selections.
+    pub subtree_cost: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ActualCost, ActualCostReport, FieldActualCost};
+    use crate::{
+        executable::{
+            operation::{
+                analyzers::{complexity_cost::IbmCostComputer, ComplexityCost},
+                Orchestrator,
+            },
+            Cache,
+        },
+        JsonPath,
+    };
+    use bluejay_parser::ast::{
+        definition::{DefaultContext, DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    };
+    use serde_json::Value as JsonValue;
+
+    type CostAnalyzers<'a, E, S, V> = Orchestrator<
+        'a,
+        E,
+        S,
+        V,
+        (
+            ComplexityCost<'a, E, S, V, IbmCostComputer<'a, E, S, V>>,
+            ActualCost<'a, E, S, V, IbmCostComputer<'a, E, S, V>>,
+        ),
+    >;
+
+    const TEST_SCHEMA: &str = r#"
+        directive @cost(weight: String!) on ARGUMENT_DEFINITION | ENUM | FIELD_DEFINITION | INPUT_FIELD_DEFINITION | OBJECT | SCALAR
+        directive @listSize(assumedSize: Int, slicingArguments: [String!], sizedFields: [String!]) on FIELD_DEFINITION
+
+        interface Node {
+          id: ID!
+        }
+
+        type Author implements Node @cost(weight: "2") {
+          id: ID!
+          name: String
+        }
+
+        type Book implements Node {
+          id: ID!
+          title: String @cost(weight: "3")
+          authors: [Author!]!
+        }
+
+        type Query {
+          books(first: Int!, term: String @cost(weight: "5")): [Book!]! @listSize(slicingArguments: ["first"])
+          nodes: [Node!]! @listSize(assumedSize: 4)
+        }
+    "#;
+
+    fn check_costs(
+        source: &str,
+        data: JsonValue,
+        expected_estimate: usize,
+        expected_actual: usize,
+        expected_fields: Vec<(Vec<&str>, usize, usize, usize)>,
+    ) -> ActualCostReport {
+        let definition_document: DefinitionDocument<'_, DefaultContext> =
+            DefinitionDocument::parse(TEST_SCHEMA)
+                .result
+                .expect("Schema had parse errors");
+        let schema_definition =
+            SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+        let executable_document = ExecutableDocument::parse(source)
+            .result
+            .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let cache = Cache::new(&executable_document, &schema_definition);
+        let variables = serde_json::Map::new();
+        let (estimate, actual) = CostAnalyzers::analyze(
+            &executable_document,
+            &schema_definition,
+            None,
+            &variables,
+            &cache,
+            ((), &data),
+        )
+        .unwrap();
+
+        assert_eq!(expected_estimate, estimate);
+        assert_eq!(expected_actual, actual.total_cost);
+        assert_eq!(
+            expected_fields
+                .into_iter()
+                .map(|(path, count, cost, subtree_cost)| FieldActualCost {
+                    path: path.into_iter().map(Into::into).collect(),
+                    count,
+                    cost,
+                    subtree_cost,
+                })
+                .collect::<Vec<_>>(),
+            actual.fields,
+        );
+        actual
+    }
+
+    #[test]
+    fn list_lengths_replace_estimates() {
+        check_costs(
+            r#"{ books(first: 10, term: "x") { title authors { name } } }"#,
+            serde_json::json!({
+                "books": [
+                    { "title": "A", "authors": [{ "name": "a" }, { "name": "b" }] },
+                    { "title": null, "authors": [] },
+                ],
+            }),
+            // (1 + 3 + 2 * 10) * 10 + 5
+            245,
+            // 5 + 1 * 2 + 3 + 2 * 2
+            14,
+            vec![
+                (vec!["books"], 2, 7, 14),
+                (vec!["books", "title"], 1, 3, 3),
+                (vec!["books", "authors"], 2, 4, 4),
+                (vec!["books", "authors", "name"], 2, 0, 0),
+            ],
+        );
+    }
+
+    #[test]
+    fn repeated_selections_are_costed_once() {
+        check_costs(
+            r#"{
+              books(first: 1) { title ...Title }
+              books(first: 1) { title }
+            }
+            fragment Title on Book { title }"#,
+            serde_json::json!({ "books": [{ "title": "A" }] }),
+            4,
+            4,
+            vec![(vec!["books"], 1, 1, 4), (vec!["books", "title"], 1, 3, 3)],
+        );
+    }
+
+    #[test]
+    fn typename_matches_type_conditions() {
+        check_costs(
+            r#"{
+              nodes {
+                __typename
+                ... on Author { name }
+                ... on Book { name: title }
+              }
+            }"#,
+            serde_json::json!({
+                "nodes": [
+                    { "__typename": "Author", "name": "a" },
+                    { "__typename": "Book", "name": "t" },
+                    { "__typename": "Author", "name": "b" },
+                ],
+            }),
+            // (1 + max(0, 3)) * 4
+            16,
+            // 1 * 3 + 3
+            6,
+            vec![
+                (vec!["nodes"], 3, 3, 6),
+                (vec!["nodes", "__typename"], 3, 0, 0),
+                (vec!["nodes", "name"], 3, 3, 3),
+            ],
+        );
+    }
+
+    #[test]
+    fn aliased_typename_matches_type_conditions() {
+        let report = check_costs(
+            r#"{
+              nodes {
+                kind: __typename
+                ... on Book { name: title }
+                ... on Author { name }
+              }
+            }"#,
+            serde_json::json!({
+                "nodes": [
+                    { "kind": "Author", "name": "a" },
+                    { "kind": "Book", "name": "t" },
+                ],
+            }),
+            // (1 + max(0, 3)) * 4
+            16,
+            // 1 * 2 + 3
+            5,
+            vec![
+                (vec!["nodes"], 2, 2, 5),
+                (vec!["nodes", "kind"], 2, 0, 0),
+                (vec!["nodes", "name"], 2, 3, 3),
+            ],
+        );
+
+        assert!(report.unknown_type_paths.is_empty());
+    }
+
+    #[test]
+    fn unknown_object_types_are_reported() {
+        let report = check_costs(
+            r#"{ nodes { ... on Book { title } } }"#,
+            serde_json::json!({ "nodes": [{ "title": "t" }, {}] }),
+            // (1 + 3) * 4
+            16,
+            // 1 * 2
+            2,
+            vec![(vec!["nodes"], 2, 2, 2), (vec!["nodes", "title"], 0, 0, 0)],
+        );
+
+        assert_eq!(
+            vec![JsonPath::new("nodes").with("title")],
+            report.unknown_type_paths,
+        );
+    }
+}
@@ -0,0 +1,623 @@
+use crate::{
+    executable::{
+        operation::{Analyzer, OperationDefinitionValueEvaluationExt, VariableValues, Visitor},
+        Cache,
+    },
+    JsonPath,
+};
+use bluejay_core::definition::{
+    ArgumentsDefinition, BaseInputTypeReference, EnumTypeDefinition, EnumValueDefinition,
+    FieldDefinition, HasDirectives, InputFieldsDefinition, InputObjectTypeDefinition, InputType,
+    InputTypeReference, InputValueDefinition, OutputType, SchemaDefinition, TypeDefinition,
+    TypeDefinitionReference,
+};
+use bluejay_core::executable::{ExecutableDocument, Field};
+use bluejay_core::{Argument, AsIter, Directive, ObjectValue, Value, ValueReference};
+use std::collections::{BTreeSet, HashSet};
+
+const AUTHENTICATED_DIRECTIVE: &str = "authenticated";
+const REQUIRES_SCOPES_DIRECTIVE: &str = "requiresScopes";
+const SCOPES_ARGUMENT: &str = "scopes";
+
+/// The most alternative sets of scopes [`AuthorizationReport::required_scopes`] is computed
+/// with. Each `@requiresScopes` with several alternatives multiplies the number of alternatives,
+/// so past this limit the requirement is treated as unsatisfiable instead.
+pub const MAX_REQUIRED_SCOPE_ALTERNATIVES: usize = 256;
+
+/// The credentials of a request, to check the requirements of an operation against.
+#[derive(Debug, Clone, Copy)]
+pub struct AuthorizationGrant<'a> {
+    pub authenticated: bool,
+    pub scopes: &'a HashSet<String>,
+}
+
+/// The output of [`Authorization`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorizationReport<'a> {
+    /// Whether any selected element is annotated with `@authenticated` or `@requiresScopes`.
+    pub requires_authentication: bool,
+    /// The scopes needed to execute the operation, as alternative sets of scopes that each
+    /// satisfy every `@requiresScopes` of the selected elements. `None` when no selected
+    /// element requires scopes, and empty when no set of scopes can satisfy them, including when
+    /// there would be more than [`MAX_REQUIRED_SCOPE_ALTERNATIVES`] alternatives.
+    pub required_scopes: Option<Vec<Vec<&'a str>>>,
+    /// Response paths of the fields that the grant does not authorize, either because the
+    /// field, its type, or one of its arguments or argument values requires more than was
+    /// granted, or because its argument values could not be coerced to check them. Always empty
+    /// when no grant is given.
+    pub unauthorized_paths: Vec<JsonPath>,
+}
+
+/// The [Authorization] analyzer computes the requirements that `@authenticated` and
+/// `@requiresScopes(scopes: [[String!]!])` place on an operation, accumulated across the
+/// selected fields, their return types, their arguments and the enum values, input fields and
+/// input types of the argument values. Fields excluded by `@skip` or `@include` are ignored.
+///
+/// When given an [`AuthorizationGrant`] as extra info, every field that is not authorized by
+/// the grant is reported as well.
+pub struct Authorization<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> {
+    operation_definition: &'a E::OperationDefinition,
+    schema_definition: &'a S,
+    variable_values: &'a VV,
+    grant: Option<AuthorizationGrant<'a>>,
+    path: JsonPath,
+    requires_authentication: bool,
+    required_scopes: Option<Vec<BTreeSet<&'a str>>>,
+    field_authorized: bool,
+    unauthorized_paths: Vec<JsonPath>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor<'a, E, S, VV>
+    for Authorization<'a, E, S, VV>
+{
+    type ExtraInfo = Option<AuthorizationGrant<'a>>;
+
+    fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        variable_values: &'a VV,
+        _: &'a Cache<'a, E, S>,
+        grant: Self::ExtraInfo,
+    ) -> Self {
+        Self {
+            operation_definition,
+            schema_definition,
+            variable_values,
+            grant,
+            path: JsonPath::default(),
+            requires_authentication: false,
+            required_scopes: None,
+            field_authorized: true,
+            unauthorized_paths: Vec::new(),
+        }
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if !included {
+            return;
+        }
+
+        self.path.push(field.response_name());
+        self.field_authorized = true;
+
+        self.require(requirement(field_definition));
+        if let Some(type_definition) = self
+            .schema_definition
+            .get_type_definition(field_definition.r#type().base_name())
+        {
+            self.require(type_definition_requirement(type_definition));
+        }
+        self.require_arguments(field, field_definition);
+
+        if !self.field_authorized {
+            self.unauthorized_paths.push(self.path.clone());
+        }
+    }
+
+    fn leave_field(
+        &mut self,
+        _field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if included {
+            self.path.pop();
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues>
+    Authorization<'a, E, S, VV>
+{
+    fn require(&mut self, requirement: Requirement<'a>) {
+        if let Some(grant) = self.grant {
+            if !requirement.is_satisfied_by(grant) {
+                self.field_authorized = false;
+            }
+        }
+
+        self.requires_authentication |= requirement.authenticated;
+        if let Some(scopes) = requirement.scopes {
+            let required_scopes = self.required_scopes.take().unwrap_or_else(|| {
+                // a single empty set of scopes, satisfied by any grant
+                vec![BTreeSet::new()]
+            });
+            self.required_scopes = Some(conjunction(&required_scopes, &scopes));
+        }
+    }
+
+    fn require_arguments<F: Field>(
+        &mut self,
+        field: &'a F,
+        field_definition: &'a S::FieldDefinition,
+    ) {
+        let Some(arguments_definition) = field_definition.arguments_definition() else {
+            return;
+        };
+
+        if let Some(arguments) = field.arguments() {
+            arguments.iter().for_each(|argument| {
+                if let Some(ivd) = arguments_definition.get(argument.name()) {
+                    self.require(requirement(ivd));
+                }
+            });
+        }
+
+        match self.operation_definition.coerce_argument_values(
+            self.schema_definition,
+            Some(arguments_definition),
+            field.arguments(),
+            self.variable_values,
+        ) {
+            Ok(coerced_argument_values) => {
+                coerced_argument_values.iter().for_each(|(name, value)| {
+                    if let Some(ivd) = arguments_definition.get(name) {
+                        self.require_value(ivd.r#type(), value);
+                    }
+                });
+            }
+            // the requirements of values that cannot be coerced are unknown, so the field is
+            // not authorized, whatever the grant
+            Err(_) => {
+                if self.grant.is_some() {
+                    self.field_authorized = false;
+                }
+            }
+        }
+    }
+
+    /// Walks an argument value that has been through input coercion, including the default
+    /// values of arguments and input fields.
+    fn require_value<
+        I: InputType<
+            CustomScalarTypeDefinition = S::CustomScalarTypeDefinition,
+            InputObjectTypeDefinition = S::InputObjectTypeDefinition,
+            EnumTypeDefinition = S::EnumTypeDefinition,
+        >,
+        V: Value<true>,
+    >(
+        &mut self,
+        input_type: &'a I,
+        value: &V,
+    ) {
+        if matches!(value.as_ref(), ValueReference::Null) {
+            return;
+        }
+
+        match input_type.as_ref(self.schema_definition) {
+            InputTypeReference::List(inner_list_type, _) => match value.as_ref() {
+                ValueReference::List(list_value) => list_value
+                    .iter()
+                    .for_each(|list_item| self.require_value(inner_list_type, list_item)),
+                _ => self.require_value(inner_list_type, value),
+            },
+            InputTypeReference::Base(base_input_type, _) => {
+                self.require(base_input_type_requirement(base_input_type));
+                match base_input_type {
+                    BaseInputTypeReference::Enum(etd) => {
+                        if let Some(evd) = value.as_ref().as_enum().and_then(|enum_value| {
+                            etd.enum_value_definitions()
+                                .iter()
+                                .find(|evd| evd.name() == *enum_value)
+                        }) {
+                            self.require(requirement(evd));
+                        }
+                    }
+                    BaseInputTypeReference::InputObject(iotd) => {
+                        if let ValueReference::Object(object_value) = value.as_ref() {
+                            object_value.iter().for_each(|(key, value)| {
+                                if let Some(ifd) = iotd.input_field_definitions().get(key.as_ref())
+                                {
+                                    self.require(requirement(ifd));
+                                    self.require_value(ifd.r#type(), value);
+                                }
+                            });
+                        }
+                    }
+                    BaseInputTypeReference::BuiltinScalar(_)
+                    | BaseInputTypeReference::CustomScalar(_) => {}
+                }
+            }
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'a, E, S, VV>
+    for Authorization<'a, E, S, VV>
+{
+    type Output = AuthorizationReport<'a>;
+
+    fn into_output(self) -> Self::Output {
+        AuthorizationReport {
+            requires_authentication: self.requires_authentication,
+            required_scopes: self.required_scopes.map(|required_scopes| {
+                required_scopes
+                    .into_iter()
+                    .map(|scopes| scopes.into_iter().collect())
+                    .collect()
+            }),
+            unauthorized_paths: self.unauthorized_paths,
+        }
+    }
+}
+
+/// The requirements placed on a single schema element.
+struct Requirement<'a> {
+    authenticated: bool,
+    /// Alternative sets of scopes, any of which satisfies the requirement.
+    scopes: Option<Vec<BTreeSet<&'a str>>>,
+}
+
+impl Requirement<'_> {
+    fn is_satisfied_by(&self, grant: AuthorizationGrant) -> bool {
+        (!self.authenticated || grant.authenticated)
+            && self.scopes.as_ref().is_none_or(|scopes| {
+                scopes
+                    .iter()
+                    .any(|all_of| all_of.iter().all(|scope| grant.scopes.contains(*scope)))
+            })
+    }
+}
+
+fn requirement<N: HasDirectives>(node: &N) -> Requirement<'_> {
+    let mut requirement = Requirement {
+        authenticated: false,
+        scopes: None,
+    };
+
+    if let Some(directives) = node.directives() {
+        directives
+            .iter()
+            .for_each(|directive| match directive.name() {
+                AUTHENTICATED_DIRECTIVE => requirement.authenticated = true,
+                REQUIRES_SCOPES_DIRECTIVE => {
+                    requirement.authenticated = true;
+                    let scopes = directive
+                        .arguments()
+                        .and_then(|arguments| {
+                            arguments
+                                .iter()
+                                .find(|argument| argument.name() == SCOPES_ARGUMENT)
+                        })
+                        .map(|argument| scopes_from_value(argument.value()))
+                        .unwrap_or_default();
+                    requirement.scopes = Some(match requirement.scopes.take() {
+                        Some(existing) => conjunction(&existing, &scopes),
+                        None => scopes,
+                    });
+                }
+                _ => {}
+            });
+    }
+
+    requirement
+}
+
+fn type_definition_requirement<T: TypeDefinition>(
+    type_definition: TypeDefinitionReference<'_, T>,
+) -> Requirement<'_> {
+    match type_definition {
+        TypeDefinitionReference::BuiltinScalar(_) => Requirement {
+            authenticated: false,
+            scopes: None,
+        },
+        TypeDefinitionReference::CustomScalar(cstd) => requirement(cstd),
+        TypeDefinitionReference::Object(otd) => requirement(otd),
+        TypeDefinitionReference::InputObject(iotd) => requirement(iotd),
+        TypeDefinitionReference::Enum(etd) => requirement(etd),
+        TypeDefinitionReference::Union(utd) => requirement(utd),
+        TypeDefinitionReference::Interface(itd) => requirement(itd),
+    }
+}
+
+fn base_input_type_requirement<I: InputType>(
+    base_input_type: BaseInputTypeReference<'_, I>,
+) -> Requirement<'_> {
+    match base_input_type {
+        BaseInputTypeReference::BuiltinScalar(_) => Requirement {
+            authenticated: false,
+            scopes: None,
+        },
+        BaseInputTypeReference::CustomScalar(cstd) => requirement(cstd),
+        BaseInputTypeReference::Enum(etd) => requirement(etd),
+        BaseInputTypeReference::InputObject(iotd) => requirement(iotd),
+    }
+}
+
+/// Reads a `[[String!]!]` value, coercing single items into lists as input coercion would.
+fn scopes_from_value<V: Value<true>>(value: &V) -> Vec<BTreeSet<&str>> {
+    match value.as_ref() {
+        ValueReference::List(any_of) => any_of
+            .iter()
+            .map(|all_of| match all_of.as_ref() {
+                ValueReference::List(all_of) => all_of
+                    .iter()
+                    .filter_map(|scope| match scope.as_ref() {
+                        ValueReference::String(scope) => Some(scope),
+                        _ => None,
+                    })
+                    .collect(),
+                ValueReference::String(scope) => BTreeSet::from([scope]),
+                _ => BTreeSet::new(),
+            })
+            .collect(),
+        ValueReference::String(scope) => vec![BTreeSet::from([scope])],
+        _ => Vec::new(),
+    }
+}
+
+/// Combines two OR-of-ANDs scope requirements into one that requires both, dropping any set
+/// of scopes that is a superset of another. When the result would have more than
+/// [`MAX_REQUIRED_SCOPE_ALTERNATIVES`] sets, no sets are returned, which no grant satisfies.
+fn conjunction<'a>(
+    left: &[BTreeSet<&'a str>],
+    right: &[BTreeSet<&'a str>],
+) -> Vec<BTreeSet<&'a str>> {
+    let mut combined: Vec<BTreeSet<&'a str>> = Vec::new();
+
+    for scopes in left
+        .iter()
+        .flat_map(|left| right.iter().map(move |right| left | right))
+    {
+        if combined.iter().any(|existing| existing.is_subset(&scopes)) {
+            continue;
+        }
+        combined.retain(|existing| !scopes.is_subset(existing));
+        combined.push(scopes);
+        if combined.len() > MAX_REQUIRED_SCOPE_ALTERNATIVES {
+            return Vec::new();
+        }
+    }
+
+    combined.sort();
+    combined
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Authorization, AuthorizationGrant, AuthorizationReport, MAX_REQUIRED_SCOPE_ALTERNATIVES,
+    };
+    use crate::{
+        executable::{operation::Orchestrator, Cache},
+        JsonPath,
+    };
+    use bluejay_parser::ast::{
+        definition::{
+            DefaultContext, DefinitionDocument, SchemaDefinition as ParserSchemaDefinition,
+        },
+        executable::ExecutableDocument as ParserExecutableDocument,
+        Parse,
+    };
+    use serde_json::{Map as JsonMap, Value as JsonValue};
+    use std::collections::HashSet;
+
+    const TEST_SCHEMA: &str = r#"
+        directive @authenticated on FIELD_DEFINITION | OBJECT | INTERFACE | SCALAR | ENUM
+        directive @requiresScopes(scopes: [[String!]!]!) on
+          | FIELD_DEFINITION
+          | OBJECT
+          | INTERFACE
+          | SCALAR
+          | ENUM
+          | ENUM_VALUE
+          | ARGUMENT_DEFINITION
+          | INPUT_FIELD_DEFINITION
+
+        enum Visibility {
+          PUBLIC
+          PRIVATE @requiresScopes(scopes: [["read:private"]])
+        }
+        type Query {
+          products(visibility: Visibility = PUBLIC): [Product!]!
+          me: User @authenticated
+          wide: Wide!
+        }
+        type Product {
+          name: String!
+          cost: Int! @requiresScopes(scopes: [["read:cost"], ["admin"]])
+        }
+        type User @requiresScopes(scopes: [["read:user"]]) {
+          email: String! @requiresScopes(scopes: [["read:email"], ["admin"]])
+        }
+        type Wide {
+          f1: Int! @requiresScopes(scopes: [["a1"], ["b1"]])
+          f2: Int! @requiresScopes(scopes: [["a2"], ["b2"]])
+          f3: Int! @requiresScopes(scopes: [["a3"], ["b3"]])
+          f4: Int! @requiresScopes(scopes: [["a4"], ["b4"]])
+          f5: Int! @requiresScopes(scopes: [["a5"], ["b5"]])
+          f6: Int! @requiresScopes(scopes: [["a6"], ["b6"]])
+          f7: Int! @requiresScopes(scopes: [["a7"], ["b7"]])
+          f8: Int! @requiresScopes(scopes: [["a8"], ["b8"]])
+          f9: Int! @requiresScopes(scopes: [["a9"], ["b9"]])
+        }
+        schema {
+          query: Query
+        }
+    "#;
+
+    fn check_authorization(
+        source: &str,
+        variables: JsonValue,
+        grant: Option<(bool, &[&str])>,
+        f: impl FnOnce(AuthorizationReport),
+    ) {
+        let definition_document: DefinitionDocument<'_, DefaultContext> =
+            DefinitionDocument::parse(TEST_SCHEMA)
+                .result
+                .expect("Schema had parse errors");
+        let schema_definition =
+            ParserSchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+        let executable_document = ParserExecutableDocument::parse(source)
+            .result
+            .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let cache = Cache::new(&executable_document, &schema_definition);
+        let variables = variables.as_object().expect("Variables must be an object");
+        let scopes: Option<HashSet<String>> =
+            grant.map(|(_, scopes)| scopes.iter().map(ToString::to_string).collect());
+        let grant = grant
+            .zip(scopes.as_ref())
+            .map(|((authenticated, _), scopes)| AuthorizationGrant {
+                authenticated,
+                scopes,
+            });
+        let report =
+            Orchestrator::<_, _, JsonMap<String, JsonValue>, Authorization<_, _, _>>::analyze(
+                &executable_document,
+                &schema_definition,
+                None,
+                variables,
+                &cache,
+                grant,
+            )
+            .unwrap();
+
+        f(report);
+    }
+
+    #[test]
+    fn no_requirements() {
+        check_authorization(
+            "{ products { name } }",
+            serde_json::json!({}),
+            Some((false, &[])),
+            |report| {
+                assert!(!report.requires_authentication);
+                assert_eq!(None, report.required_scopes);
+                assert!(report.unauthorized_paths.is_empty());
+            },
+        );
+    }
+
+    #[test]
+    fn accumulated_scopes() {
+        check_authorization(
+            r#"{
+              products(visibility: PRIVATE) { cost }
+              me { email }
+            }"#,
+            serde_json::json!({}),
+            None,
+            |report| {
+                assert!(report.requires_authentication);
+                assert_eq!(
+                    Some(vec![
+                        vec!["admin", "read:private", "read:user"],
+                        vec!["read:cost", "read:email", "read:private", "read:user"],
+                    ]),
+                    report.required_scopes,
+                );
+                assert!(report.unauthorized_paths.is_empty());
+            },
+        );
+    }
+
+    #[test]
+    fn unauthorized_paths() {
+        check_authorization(
+            r#"query($visibility: Visibility, $skip: Boolean!) {
+              products(visibility: $visibility) { name cost }
+              me @skip(if: $skip) { email }
+            }"#,
+            serde_json::json!({ "visibility": "PRIVATE", "skip": false }),
+            Some((true, &["read:cost", "read:user"])),
+            |report| {
+                assert_eq!(
+                    vec![JsonPath::new("products"), JsonPath::new("me").with("email")],
+                    report.unauthorized_paths,
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn skipped_fields_are_not_required() {
+        check_authorization(
+            r#"query($skip: Boolean!) {
+              products { name }
+              me @skip(if: $skip) { email }
+            }"#,
+            serde_json::json!({ "skip": true }),
+            Some((false, &[])),
+            |report| {
+                assert!(!report.requires_authentication);
+                assert!(report.unauthorized_paths.is_empty());
+            },
+        );
+    }
+
+    #[test]
+    fn uncoercible_arguments_are_unauthorized() {
+        check_authorization(
+            r#"query($visibility: Visibility) {
+              literal: products(visibility: 1) { name }
+              variable: products(visibility: $visibility) { name }
+            }"#,
+            serde_json::json!({ "visibility": "SECRET" }),
+            Some((true, &["read:private"])),
+            |report| {
+                assert_eq!(
+                    vec![JsonPath::new("literal"), JsonPath::new("variable")],
+                    report.unauthorized_paths,
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn too_many_scope_alternatives() {
+        // 2^8 alternatives is at the limit, 2^9 is past it
+        check_authorization(
+            "{ wide { f1 f2 f3 f4 f5 f6 f7 f8 } }",
+            serde_json::json!({}),
+            Some((true, &["a1", "a2", "a3", "a4", "a5", "a6", "a7", "a8"])),
+            |report| {
+                assert_eq!(
+                    Some(MAX_REQUIRED_SCOPE_ALTERNATIVES),
+                    report.required_scopes.map(|scopes| scopes.len()),
+                );
+                assert!(report.unauthorized_paths.is_empty());
+            },
+        );
+        check_authorization(
+            "{ wide { f1 f2 f3 f4 f5 f6 f7 f8 f9 } }",
+            serde_json::json!({}),
+            Some((
+                true,
+                &["a1", "a2", "a3", "a4", "a5", "a6", "a7", "a8", "a9"],
+            )),
+            |report| {
+                assert_eq!(Some(Vec::new()), report.required_scopes);
+                // each field is still checked against the grant on its own
+                assert!(report.unauthorized_paths.is_empty());
+            },
+        );
+    }
+}
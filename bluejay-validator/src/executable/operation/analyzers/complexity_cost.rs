@@ -1,6 +1,9 @@
-use crate::executable::{
-    operation::{Analyzer, VariableValues, Visitor},
-    Cache,
+use crate::{
+    executable::{
+        operation::{Analyzer, VariableValues, Visitor},
+        Cache,
+    },
+    JsonPath,
 };
 use bluejay_core::definition::{
     FieldDefinition, ObjectTypeDefinition, OutputType, SchemaDefinition, TypeDefinition,
@@ -9,6 +12,8 @@ use bluejay_core::definition::{
 use bluejay_core::executable::{ExecutableDocument, Field};
 use bluejay_core::AsIter;
 use itertools::{Either, Itertools};
+#[cfg(feature = "serde")]
+use serde::Serialize;
 use std::cmp::max;
 use std::collections::HashMap;
 
@@ -16,11 +21,17 @@ mod arena;
 use arena::{Arena, NodeId};
 
 mod cost_computer;
-pub use cost_computer::{CostComputer, DefaultCostComputer, FieldMultipliers};
+pub use cost_computer::{CostComputer, DefaultCostComputer, FieldMultipliers, MultiplierSource};
+
+mod ibm_cost_computer;
+pub use ibm_cost_computer::IbmCostComputer;
 
 mod relay_cost_computer;
 pub use relay_cost_computer::RelayCostComputer;
 
+/// Selections of fragments deferred with `@defer` and fields streamed with `@stream` are costed
+/// like any other, as they are still resolved, and a field that is selected both with and
+/// without `@defer` is costed once.
 pub struct ComplexityCost<
     'a,
     E: ExecutableDocument,
@@ -32,6 +43,8 @@ pub struct ComplexityCost<
     cost_computer: C,
     scopes_arena: Arena<ComplexityScope<'a, S::TypeDefinition, C::FieldMultipliers>>,
     scopes_stack: Vec<Option<NodeId>>,
+    explain: bool,
+    fields_stack: Vec<(&'a S::FieldDefinition, &'a E::Field)>,
 }
 
 impl<
@@ -57,6 +70,8 @@ impl<
             cost_computer: C::new(operation_definition, schema_definition, variable_values),
             scopes_arena,
             scopes_stack,
+            explain: false,
+            fields_stack: Vec::new(),
         }
     }
 
@@ -70,13 +85,22 @@ impl<
         if !included {
             return;
         }
+        let parent = self.fields_stack.last().copied();
+        if self.explain {
+            self.fields_stack.push((field_definition, field));
+        }
+
         let cost = self
             .cost_computer
             .cost_for_field_definition(field_definition);
+        let arguments_cost = self
+            .cost_computer
+            .cost_for_field_arguments(field_definition, field);
 
         // Don't grow the costing tree for leaf fields without cost,
         // just hold their position in the traversal stack with a None scope
         if cost == 0
+            && arguments_cost == 0
             && !field_definition
                 .r#type()
                 .base(self.schema_definition)
@@ -104,7 +128,11 @@ impl<
 
         // Collect any multipliers that the parent scope specifies for this field
         // ie: connection > edges/nodes
-        let parent_multiplier = parent_scope.multiplier_for_field(field);
+        let multiplier = self.cost_computer.multiplier_for_field(
+            field_definition,
+            field,
+            &parent_scope.field_multipliers,
+        );
 
         // find or create a reference to this field's scope in the parent tree of typed selections
         // ie: parent_scope.typed_selections = { Type => { "field_key" => scopes_db_index, ... } }
@@ -132,6 +160,13 @@ impl<
             });
         }
 
+        let multiplier_source = if self.explain {
+            self.cost_computer
+                .multiplier_source(field_definition, field, parent)
+        } else {
+            MultiplierSource::None
+        };
+
         // Push the current scope reference onto the traversal stack,
         // and then get a mutable reference to the scope itself
         self.scopes_stack.push(Some(scope_index));
@@ -141,8 +176,10 @@ impl<
             .expect("invalid complexity scope tree reference");
 
         // repeated scopes have a consistent argument multiplier in valid documents
-        scope.multiplier = parent_multiplier;
+        scope.multiplier = multiplier;
+        scope.multiplier_source = multiplier_source;
         scope.cost = scope.cost.max(cost);
+        scope.arguments_cost = scope.arguments_cost.max(arguments_cost);
     }
 
     fn leave_field(
@@ -154,6 +191,9 @@ impl<
     ) {
         if included {
             self.scopes_stack.pop().unwrap();
+            if self.explain {
+                self.fields_stack.pop();
+            }
         }
     }
 }
@@ -182,6 +222,17 @@ impl<
     > ComplexityCost<'a, E, S, V, C>
 {
     fn result(&mut self) -> usize {
+        self.merged_max_complexity_for_root::<false>().0
+    }
+
+    fn explanation(&self) -> ComplexityCostExplanation<'a> {
+        let (total_cost, fields) = self.merged_max_complexity_for_root::<true>();
+        ComplexityCostExplanation { total_cost, fields }
+    }
+
+    fn merged_max_complexity_for_root<const EXPLAIN: bool>(
+        &self,
+    ) -> (usize, Vec<FieldCostExplanation<'a>>) {
         let root_scope = self
             .scopes_stack
             .first()
@@ -189,13 +240,16 @@ impl<
             .flatten()
             .and_then(|index| self.scopes_arena.get(index))
             .unwrap();
-        self.merged_max_complexity_for_scopes(&[root_scope])
+        self.merged_max_complexity_for_scopes::<EXPLAIN>(&[root_scope], &JsonPath::default())
     }
 
-    fn merged_max_complexity_for_scopes(
+    /// Returns the maximum cost of the merged scopes among their possible types and, when
+    /// `EXPLAIN` is set, the costed fields of the most expensive possible type.
+    fn merged_max_complexity_for_scopes<const EXPLAIN: bool>(
         &self,
         scopes: &[&ComplexityScope<'a, S::TypeDefinition, C::FieldMultipliers>],
-    ) -> usize {
+        path: &JsonPath,
+    ) -> (usize, Vec<FieldCostExplanation<'a>>) {
         // build a set of all unique possible type definitions
         // with abstract types expanded to encompass all of their possible types
         let possible_type_names = scopes
@@ -228,16 +282,19 @@ impl<
                     })
                     .collect::<Vec<_>>();
 
-                self.merged_max_complexity_for_selections(inner_selections)
+                self.merged_max_complexity_for_selections::<EXPLAIN>(inner_selections, path)
             })
-            .max()
-            .unwrap_or(0)
+            .max_by_key(|(cost, _)| *cost)
+            .unwrap_or_default()
     }
 
-    fn merged_max_complexity_for_selections(
+    fn merged_max_complexity_for_selections<const EXPLAIN: bool>(
         &self,
         inner_selections: Vec<&InnerSelection<'a>>,
-    ) -> usize {
+        path: &JsonPath,
+    ) -> (usize, Vec<FieldCostExplanation<'a>>) {
+        let mut explanations = Vec::new();
+
         // build a unique set of field keys from across inner selections.
         // the same field keys may appear in selections on different types,
         // ex: a "metafield" key may be selected on both Product and HasMetafield types.
@@ -247,10 +304,12 @@ impl<
             .unique();
 
         // calculate a maximum possible cost for each unique field key
-        unique_field_keys
+        let cost = unique_field_keys
             .map(|field_key| {
                 let mut base_cost = 0;
+                let mut arguments_cost = 0;
                 let mut multiplier = 0;
+                let mut multiplier_source = MultiplierSource::None;
 
                 // collect child scopes from across composite selections
                 // leaf selections report their costs directly
@@ -264,7 +323,11 @@ impl<
                                 // base_cost and multiplier select their maximums from across merged scopes
                                 // in case a field name has different costs in different scope types.
                                 base_cost = max(base_cost, child_scope.cost);
-                                multiplier = max(multiplier, child_scope.multiplier);
+                                arguments_cost = max(arguments_cost, child_scope.arguments_cost);
+                                if child_scope.multiplier >= multiplier {
+                                    multiplier = child_scope.multiplier;
+                                    multiplier_source = child_scope.multiplier_source;
+                                }
 
                                 if !child_scope.typed_selections.is_empty() {
                                     Some(child_scope)
@@ -275,11 +338,53 @@ impl<
                     })
                     .collect::<Vec<&ComplexityScope<'a, S::TypeDefinition, C::FieldMultipliers>>>();
 
-                let children_cost = self.merged_max_complexity_for_scopes(&composite_scopes);
+                let field_path = if EXPLAIN {
+                    path.with(*field_key)
+                } else {
+                    JsonPath::default()
+                };
+
+                let (children_cost, children) = self
+                    .merged_max_complexity_for_scopes::<EXPLAIN>(&composite_scopes, &field_path);
+
+                let subtree_cost = (base_cost + children_cost) * multiplier + arguments_cost;
+
+                if EXPLAIN {
+                    // scopes are added as fields are visited, so the first scope of a field key
+                    // gives its position in the document
+                    let first_scope_index = inner_selections
+                        .iter()
+                        .filter_map(|inner_selection| inner_selection.get(*field_key))
+                        .min()
+                        .copied();
+                    explanations.push((
+                        first_scope_index,
+                        FieldCostExplanation {
+                            response_name: field_key,
+                            path: field_path,
+                            field_cost: base_cost,
+                            arguments_cost,
+                            multiplier,
+                            multiplier_source,
+                            subtree_cost,
+                            children,
+                        },
+                    ));
+                }
 
-                (base_cost + children_cost) * multiplier
+                subtree_cost
             })
-            .sum()
+            .sum();
+
+        explanations.sort_unstable_by_key(|(first_scope_index, _)| *first_scope_index);
+
+        (
+            cost,
+            explanations
+                .into_iter()
+                .map(|(_, explanation)| explanation)
+                .collect(),
+        )
     }
 
     fn possible_type_names(
@@ -303,6 +408,111 @@ impl<
     }
 }
 
+/// Explains how [`ExplainComplexityCost`] arrived at the cost computed by [`ComplexityCost`].
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
+pub struct ComplexityCostExplanation<'a> {
+    pub total_cost: usize,
+    /// The costed root fields, in the order they first appear in the document.
+    pub fields: Vec<FieldCostExplanation<'a>>,
+}
+
+/// The cost of a field in the response, merged across every selection of its response name.
+/// When the field's selections are on an abstract type, `children` are those of the most
+/// expensive possible type. Leaf fields without any cost are omitted.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
+pub struct FieldCostExplanation<'a> {
+    pub response_name: &'a str,
+    /// The response names leading to this field, including its own.
+    pub path: JsonPath,
+    pub field_cost: usize,
+    pub arguments_cost: usize,
+    pub multiplier: usize,
+    pub multiplier_source: MultiplierSource<'a>,
+    /// `(field_cost + sum of children's subtree_cost) * multiplier + arguments_cost`
+    pub subtree_cost: usize,
+    /// The costed child fields, in the order they first appear in the document.
+    pub children: Vec<FieldCostExplanation<'a>>,
+}
+
+/// A [`ComplexityCost`] that outputs a [`ComplexityCostExplanation`] of the cost instead of only
+/// the total. This is slower than [`ComplexityCost`], so it is meant for rejected operations
+/// and debugging.
+pub struct ExplainComplexityCost<
+    'a,
+    E: ExecutableDocument,
+    S: SchemaDefinition,
+    V: VariableValues,
+    C: CostComputer<'a, E, S, V> = DefaultCostComputer,
+>(ComplexityCost<'a, E, S, V, C>);
+
+impl<
+        'a,
+        E: ExecutableDocument,
+        S: SchemaDefinition,
+        V: VariableValues,
+        C: CostComputer<'a, E, S, V>,
+    > Visitor<'a, E, S, V> for ExplainComplexityCost<'a, E, S, V, C>
+{
+    type ExtraInfo = ();
+
+    fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        variable_values: &'a V,
+        cache: &'a Cache<'a, E, S>,
+        extra_info: Self::ExtraInfo,
+    ) -> Self {
+        let mut complexity_cost = ComplexityCost::new(
+            operation_definition,
+            schema_definition,
+            variable_values,
+            cache,
+            extra_info,
+        );
+        complexity_cost.explain = true;
+        Self(complexity_cost)
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        field_definition: &'a S::FieldDefinition,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        self.0
+            .visit_field(field, field_definition, scoped_type, included)
+    }
+
+    fn leave_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        field_definition: &'a S::FieldDefinition,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        self.0
+            .leave_field(field, field_definition, scoped_type, included)
+    }
+}
+
+impl<
+        'a,
+        E: ExecutableDocument,
+        S: SchemaDefinition,
+        V: VariableValues,
+        C: CostComputer<'a, E, S, V>,
+    > Analyzer<'a, E, S, V> for ExplainComplexityCost<'a, E, S, V, C>
+{
+    type Output = ComplexityCostExplanation<'a>;
+
+    fn into_output(self) -> Self::Output {
+        self.0.explanation()
+    }
+}
+
 type InnerSelection<'a> = HashMap<&'a str, NodeId>;
 
 struct TypedSelection<'a, T: TypeDefinition> {
@@ -312,7 +522,9 @@ struct TypedSelection<'a, T: TypeDefinition> {
 
 struct ComplexityScope<'a, T: TypeDefinition, F> {
     cost: usize,
+    arguments_cost: usize,
     multiplier: usize,
+    multiplier_source: MultiplierSource<'a>,
     typed_selections: HashMap<&'a str, TypedSelection<'a, T>>,
     field_multipliers: F,
 }
@@ -321,22 +533,15 @@ impl<T: TypeDefinition, F: Default> Default for ComplexityScope<'_, T, F> {
     fn default() -> Self {
         Self {
             cost: 0,
+            arguments_cost: 0,
             multiplier: 1,
+            multiplier_source: MultiplierSource::None,
             typed_selections: HashMap::new(),
             field_multipliers: F::default(),
         }
     }
 }
 
-impl<T: TypeDefinition, F> ComplexityScope<'_, T, F> {
-    fn multiplier_for_field<E: ExecutableDocument>(&self, field: &E::Field) -> usize
-    where
-        F: FieldMultipliers<E>,
-    {
-        self.field_multipliers.multiplier_for_field(field)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -403,6 +608,7 @@ mod tests {
 
           oneObjectConnection(first: Int!, last: Int!): BasicObjectConnection @cost(weight: "1.0", kind: "connection")
           twoObjectConnection(first: Int!, last: Int!): BasicObjectConnection @cost(weight: "2.0", kind: "connection")
+          defaultSizeConnection(first: Int = 7, last: Int): BasicObjectConnection @cost(weight: "1.0", kind: "connection")
         }
 
         type PageInfo {
@@ -503,6 +709,40 @@ mod tests {
         )
     }
 
+    fn with_explanation(
+        source: &str,
+        variables: JsonValue,
+        f: impl FnOnce(ComplexityCostExplanation),
+    ) {
+        let definition_document: DefinitionDocument<'_, DefaultContext> =
+            DefinitionDocument::parse(TEST_SCHEMA)
+                .result
+                .expect("Schema had parse errors");
+        let schema_definition =
+            SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+        let executable_document = ExecutableDocument::parse(source)
+            .result
+            .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let cache = Cache::new(&executable_document, &schema_definition);
+        let variables = variables.as_object().expect("Variables must be an object");
+        let explanation = Orchestrator::<
+            _,
+            _,
+            _,
+            ExplainComplexityCost<_, _, _, RelayCostComputer<_, _, _>>,
+        >::analyze(
+            &executable_document,
+            &schema_definition,
+            None,
+            variables,
+            &cache,
+            (),
+        )
+        .unwrap();
+
+        f(explanation)
+    }
+
     fn check_complexity(source: &str, expected_complexity: usize) {
         check_complexity_with_operation_name_and_variables(
             source,
@@ -842,6 +1082,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deferred_fragments_and_streamed_fields() {
+        // deferred and streamed selections are still resolved, so they are costed as usual
+        check_complexity(
+            r#"{
+            oneObject { zeroScalar }
+            ... @defer { fiveBasicObject { twoScalar } }
+        }"#,
+            8,
+        );
+
+        check_complexity(
+            r#"
+            fragment Stuff on Query { fiveBasicObject { twoScalar } }
+            query {
+                oneObject { zeroScalar }
+                ... Stuff @defer(label: "stuff", if: false)
+            }
+        "#,
+            8,
+        );
+
+        check_complexity(
+            r#"{ fiveObjectList @stream(initialCount: 1) { zeroScalar } }"#,
+            5,
+        );
+
+        // a field selected both with and without `@defer` is only resolved once
+        check_complexity(
+            r#"{
+            fiveBasicObject { twoScalar }
+            ... @defer { fiveBasicObject { twoScalar } }
+        }"#,
+            7,
+        );
+    }
+
     #[test]
     fn skip_and_include_fragments_with_default_variables() {
         check_complexity(
@@ -1210,4 +1487,96 @@ mod tests {
             1,
         );
     }
+
+    #[test]
+    fn explanation() {
+        let source = r#"query($first: Int) {
+            oneObjectConnection(first: $first) { # 1 + 9 + 0 = 10
+              edges { node { zeroScalar twoScalar } } # (0 + 3) * floor(2 * log(7)) = 9
+              pageInfo { hasNextPage } # 0
+            }
+        }"#;
+        let variables = serde_json::json!({ "first": 7 });
+
+        check_complexity_with_variables(source, variables.clone(), 10);
+        with_explanation(source, variables, |explanation| {
+            assert_eq!(10, explanation.total_cost);
+            let [connection] = explanation.fields.as_slice() else {
+                panic!("Expected a single root field");
+            };
+            assert_eq!("oneObjectConnection", connection.response_name);
+            assert_eq!(1, connection.field_cost);
+            assert_eq!(1, connection.multiplier);
+            assert_eq!(MultiplierSource::None, connection.multiplier_source);
+            assert_eq!(10, connection.subtree_cost);
+
+            let [edges, page_info] = connection.children.as_slice() else {
+                panic!("Expected edges and pageInfo");
+            };
+            assert_eq!(
+                JsonPath::new("oneObjectConnection").with("edges"),
+                edges.path,
+            );
+            assert_eq!(0, edges.field_cost);
+            assert_eq!(3, edges.multiplier);
+            assert_eq!(
+                MultiplierSource::Variable {
+                    argument: "first",
+                    variable: "first",
+                },
+                edges.multiplier_source,
+            );
+            assert_eq!(9, edges.subtree_cost);
+            assert_eq!("pageInfo", page_info.response_name);
+            assert_eq!(0, page_info.subtree_cost);
+
+            let [node] = edges.children.as_slice() else {
+                panic!("Expected node");
+            };
+            assert_eq!(3, node.subtree_cost);
+            // leaf fields without cost are omitted
+            let [two_scalar] = node.children.as_slice() else {
+                panic!("Expected twoScalar");
+            };
+            assert_eq!(
+                JsonPath::new("oneObjectConnection")
+                    .with("edges")
+                    .with("node")
+                    .with("twoScalar"),
+                two_scalar.path,
+            );
+            assert_eq!(2, two_scalar.subtree_cost);
+        });
+    }
+
+    #[test]
+    fn explanation_default_value_and_document_order() {
+        let source = r#"{
+            defaultSizeConnection { # 1 + 0 + 9 = 10
+              pageInfo { hasNextPage } # 0
+              edges { node { twoScalar } } # (0 + 3) * floor(2 * log(7)) = 9
+            }
+        }"#;
+
+        check_complexity(source, 10);
+        with_explanation(source, serde_json::json!({}), |explanation| {
+            assert_eq!(10, explanation.total_cost);
+            let [connection] = explanation.fields.as_slice() else {
+                panic!("Expected a single root field");
+            };
+            let response_names: Vec<_> = connection
+                .children
+                .iter()
+                .map(|child| child.response_name)
+                .collect();
+            assert_eq!(vec!["pageInfo", "edges"], response_names);
+
+            let edges = &connection.children[1];
+            assert_eq!(3, edges.multiplier);
+            assert_eq!(
+                MultiplierSource::DefaultValue { argument: "first" },
+                edges.multiplier_source,
+            );
+        });
+    }
 }
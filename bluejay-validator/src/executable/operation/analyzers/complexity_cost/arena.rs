@@ -1,4 +1,5 @@
-#[derive(Clone, Copy, PartialEq, Eq)]
+/// Ids are allocated in increasing order, so comparing them compares the order nodes were added.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub(super) struct NodeId(usize);
 
 pub(super) struct Arena<T> {
@@ -1,6 +1,8 @@
 use crate::executable::operation::VariableValues;
 use bluejay_core::definition::SchemaDefinition;
 use bluejay_core::executable::ExecutableDocument;
+#[cfg(feature = "serde")]
+use serde::Serialize;
 
 pub trait CostComputer<'a, E: ExecutableDocument, S: SchemaDefinition, V: VariableValues> {
     type FieldMultipliers: FieldMultipliers<E>;
@@ -18,6 +20,63 @@ pub trait CostComputer<'a, E: ExecutableDocument, S: SchemaDefinition, V: Variab
         field_definition: &S::FieldDefinition,
         field: &E::Field,
     ) -> Self::FieldMultipliers;
+
+    /// The multiplier applied to the cost of `field` and its selections, given the
+    /// [`FieldMultipliers`] of its parent field. Defaults to the multiplier the parent assigns.
+    fn multiplier_for_field(
+        &self,
+        _field_definition: &S::FieldDefinition,
+        field: &E::Field,
+        parent_field_multipliers: &Self::FieldMultipliers,
+    ) -> usize {
+        parent_field_multipliers.multiplier_for_field(field)
+    }
+
+    /// Where the multiplier returned by [`CostComputer::multiplier_for_field`] came from, used
+    /// when explaining a cost. `parent` is the enclosing field, if any. Defaults to
+    /// [`MultiplierSource::None`].
+    fn multiplier_source(
+        &self,
+        _field_definition: &'a S::FieldDefinition,
+        _field: &'a E::Field,
+        _parent: Option<(&'a S::FieldDefinition, &'a E::Field)>,
+    ) -> MultiplierSource<'a> {
+        MultiplierSource::None
+    }
+
+    /// The cost of the arguments passed to `field`. This is added once per resolution of the
+    /// field, rather than being scaled by the field's own multiplier.
+    fn cost_for_field_arguments(
+        &self,
+        _field_definition: &S::FieldDefinition,
+        _field: &E::Field,
+    ) -> usize {
+        0
+    }
+}
+
+/// Where the multiplier of a field came from. Sizes set by an enclosing field, like the
+/// `edges` of a connection, refer to the arguments of that enclosing field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(tag = "kind", rename_all = "camelCase")
+)]
+pub enum MultiplierSource<'a> {
+    /// The field is not multiplied.
+    None,
+    /// A literal value passed for the argument.
+    Argument { name: &'a str },
+    /// The value of a variable passed for the argument, or the variable's default value.
+    Variable {
+        argument: &'a str,
+        variable: &'a str,
+    },
+    /// The default value of the argument.
+    DefaultValue { argument: &'a str },
+    /// A size assumed in the absence of arguments, e.g. `@listSize(assumedSize:)`.
+    AssumedSize,
 }
 
 pub trait FieldMultipliers<E: ExecutableDocument>: Default {
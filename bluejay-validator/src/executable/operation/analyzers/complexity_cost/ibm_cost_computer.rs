@@ -0,0 +1,755 @@
+use crate::executable::operation::{
+    analyzers::complexity_cost::{CostComputer, FieldMultipliers, MultiplierSource},
+    OperationDefinitionValueEvaluationExt, VariableValues,
+};
+use bluejay_core::definition::{
+    prelude::*, BaseInputTypeReference, FieldDefinition, HasDirectives, InputTypeReference,
+    OutputTypeReference, SchemaDefinition,
+};
+use bluejay_core::executable::{
+    ExecutableDocument, Field, OperationDefinition, VariableDefinition,
+};
+use bluejay_core::{Argument, AsIter, Directive, ObjectValue, Value, ValueReference, Variable};
+use std::marker::PhantomData;
+
+const COST_DIRECTIVE: &str = "cost";
+const LIST_SIZE_DIRECTIVE: &str = "listSize";
+const WEIGHT_ARGUMENT: &str = "weight";
+const ASSUMED_SIZE_ARGUMENT: &str = "assumedSize";
+const SLICING_ARGUMENTS_ARGUMENT: &str = "slicingArguments";
+const SIZED_FIELDS_ARGUMENT: &str = "sizedFields";
+
+/// A [`CostComputer`] following the [GraphQL Cost Directive specification](https://ibm.github.io/graphql-specs/cost-spec.html).
+///
+/// Weights are read from `@cost(weight:)` on field, argument and input field definitions, and on
+/// object, enum and scalar types. Fields without a weight cost 1 when they return a composite
+/// type and 0 otherwise. Arguments and input fields are only weighed when they are passed in the
+/// operation, either directly or through a variable.
+///
+/// List fields are multiplied by the size given by `@listSize`: the largest value passed for (or
+/// defaulted by) one of its `slicingArguments`, falling back to `assumedSize` and then to
+/// `DEFAULT_LIST_SIZE`. When `sizedFields` is given, the size multiplies those child fields
+/// instead of the annotated field, which is then counted once. When several slicing arguments are passed, the largest is used;
+/// `requireOneSlicingArgument` is enforced separately, by validating the document with the
+/// [`RequireOneSlicingArgument`](crate::executable::document::rules::RequireOneSlicingArgument)
+/// rule.
+pub struct IbmCostComputer<
+    'a,
+    E: ExecutableDocument,
+    S: SchemaDefinition,
+    V: VariableValues,
+    const DEFAULT_LIST_SIZE: usize = 10,
+> {
+    operation_definition: &'a E::OperationDefinition,
+    schema_definition: &'a S,
+    variable_values: &'a V,
+}
+
+impl<
+        'a,
+        E: ExecutableDocument,
+        S: SchemaDefinition,
+        V: VariableValues,
+        const DEFAULT_LIST_SIZE: usize,
+    > CostComputer<'a, E, S, V> for IbmCostComputer<'a, E, S, V, DEFAULT_LIST_SIZE>
+{
+    type FieldMultipliers = IbmFieldMultipliers<E>;
+
+    fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        variable_values: &'a V,
+    ) -> Self {
+        Self {
+            operation_definition,
+            schema_definition,
+            variable_values,
+        }
+    }
+
+    fn cost_for_field_definition(&self, field_definition: &S::FieldDefinition) -> usize {
+        let return_type = field_definition.r#type().base(self.schema_definition);
+
+        cost_weight(field_definition)
+            .or_else(|| {
+                self.schema_definition
+                    .get_type_definition(return_type.name())
+                    .and_then(|type_definition| cost_weight(&type_definition))
+            })
+            .unwrap_or_else(|| if return_type.is_composite() { 1 } else { 0 })
+    }
+
+    fn field_multipliers(
+        &self,
+        field_definition: &S::FieldDefinition,
+        field: &E::Field,
+    ) -> IbmFieldMultipliers<E> {
+        let sized_fields: Vec<String> = list_size_names(field_definition, SIZED_FIELDS_ARGUMENT)
+            .into_iter()
+            .map(ToString::to_string)
+            .collect();
+
+        if sized_fields.is_empty() {
+            return IbmFieldMultipliers::default();
+        }
+
+        IbmFieldMultipliers {
+            sized_fields,
+            size: self.list_size(field_definition, field).0,
+            executable_document: PhantomData,
+        }
+    }
+
+    fn multiplier_for_field(
+        &self,
+        field_definition: &S::FieldDefinition,
+        field: &E::Field,
+        parent_field_multipliers: &IbmFieldMultipliers<E>,
+    ) -> usize {
+        parent_field_multipliers
+            .size_for_field(field)
+            .unwrap_or_else(|| self.own_multiplier(field_definition, field).0)
+    }
+
+    fn multiplier_source(
+        &self,
+        field_definition: &'a S::FieldDefinition,
+        field: &'a E::Field,
+        parent: Option<(&'a S::FieldDefinition, &'a E::Field)>,
+    ) -> MultiplierSource<'a> {
+        match parent {
+            Some((parent_definition, parent))
+                if list_size_names(parent_definition, SIZED_FIELDS_ARGUMENT)
+                    .contains(&field.name()) =>
+            {
+                self.list_size(parent_definition, parent).1
+            }
+            _ => self.own_multiplier(field_definition, field).1,
+        }
+    }
+
+    fn cost_for_field_arguments(
+        &self,
+        field_definition: &S::FieldDefinition,
+        field: &E::Field,
+    ) -> usize {
+        let (Some(arguments), Some(arguments_definition)) =
+            (field.arguments(), field_definition.arguments_definition())
+        else {
+            return 0;
+        };
+
+        arguments
+            .iter()
+            .filter_map(|argument| {
+                arguments_definition
+                    .get(argument.name())
+                    .map(|argument_definition| {
+                        self.input_value_definition_cost(argument_definition, argument.value())
+                    })
+            })
+            .sum()
+    }
+}
+
+impl<
+        E: ExecutableDocument,
+        S: SchemaDefinition,
+        V: VariableValues,
+        const DEFAULT_LIST_SIZE: usize,
+    > IbmCostComputer<'_, E, S, V, DEFAULT_LIST_SIZE>
+{
+    /// The multiplier of a field that is not sized by its parent field.
+    fn own_multiplier<'b, D: FieldDefinition<OutputType = S::OutputType>, F: Field>(
+        &self,
+        field_definition: &'b D,
+        field: &'b F,
+    ) -> (usize, MultiplierSource<'b>) {
+        if !matches!(
+            field_definition.r#type().as_ref(self.schema_definition),
+            OutputTypeReference::List(_, _)
+        ) {
+            (1, MultiplierSource::None)
+        } else if !list_size_names(field_definition, SIZED_FIELDS_ARGUMENT).is_empty() {
+            // the size is carried by the sized fields
+            (1, MultiplierSource::None)
+        } else {
+            self.list_size(field_definition, field)
+        }
+    }
+
+    fn list_size<'b, D: FieldDefinition, F: Field>(
+        &self,
+        field_definition: &'b D,
+        field: &'b F,
+    ) -> (usize, MultiplierSource<'b>) {
+        list_size_names(field_definition, SLICING_ARGUMENTS_ARGUMENT)
+            .into_iter()
+            .filter_map(|argument_name| {
+                self.slicing_argument_value(field_definition, field, argument_name)
+            })
+            .max_by_key(|(size, _)| *size)
+            .or_else(|| {
+                list_size_assumed_size(field_definition)
+                    .map(|size| (size, MultiplierSource::AssumedSize))
+            })
+            .map(|(size, source)| (size.max(0) as usize, source))
+            .unwrap_or((DEFAULT_LIST_SIZE, MultiplierSource::AssumedSize))
+    }
+
+    /// The value passed for the slicing argument, either directly or through a variable, or the
+    /// argument's default value when it is omitted or the variable is unset.
+    fn slicing_argument_value<'b, D: FieldDefinition, F: Field>(
+        &self,
+        field_definition: &'b D,
+        field: &'b F,
+        argument_name: &'b str,
+    ) -> Option<(i32, MultiplierSource<'b>)> {
+        let passed_value = field
+            .arguments()
+            .and_then(|arguments| {
+                arguments
+                    .iter()
+                    .find(|argument| argument.name() == argument_name)
+            })
+            .and_then(|argument| match argument.value().as_ref() {
+                ValueReference::Integer(int) => Some((
+                    int,
+                    MultiplierSource::Argument {
+                        name: argument_name,
+                    },
+                )),
+                ValueReference::Variable(variable) => self
+                    .operation_definition
+                    .evaluate_int(variable, self.variable_values)
+                    .map(|int| {
+                        (
+                            int,
+                            MultiplierSource::Variable {
+                                argument: argument_name,
+                                variable: variable.name(),
+                            },
+                        )
+                    }),
+                _ => None,
+            });
+
+        passed_value.or_else(|| {
+            field_definition
+                .arguments_definition()
+                .and_then(|arguments_definition| arguments_definition.get(argument_name))
+                .and_then(|argument_definition| argument_definition.default_value())
+                .and_then(|value| value.as_ref().as_integer().copied())
+                .map(|int| {
+                    (
+                        int,
+                        MultiplierSource::DefaultValue {
+                            argument: argument_name,
+                        },
+                    )
+                })
+        })
+    }
+
+    fn input_value_definition_cost<const CONST: bool, T: Value<CONST>>(
+        &self,
+        input_value_definition: &S::InputValueDefinition,
+        value: &T,
+    ) -> usize {
+        if matches!(value.as_ref(), ValueReference::Null) {
+            return 0;
+        }
+        cost_weight(input_value_definition).unwrap_or(0)
+            + self.input_value_cost(input_value_definition.r#type(), value)
+    }
+
+    fn input_value_cost<const CONST: bool, T: Value<CONST>>(
+        &self,
+        input_type: &S::InputType,
+        value: &T,
+    ) -> usize {
+        match (input_type.as_ref(self.schema_definition), value.as_ref()) {
+            (_, ValueReference::Null) => 0,
+            (_, ValueReference::Variable(variable)) => {
+                self.variable_cost(input_type, variable.name())
+            }
+            (InputTypeReference::List(inner, _), ValueReference::List(values)) => values
+                .iter()
+                .map(|value| self.input_value_cost(inner, value))
+                .sum(),
+            (InputTypeReference::List(inner, _), _) => self.input_value_cost(inner, value),
+            (InputTypeReference::Base(base, _), value) => {
+                let fields_cost = match (base, value) {
+                    (BaseInputTypeReference::InputObject(iotd), ValueReference::Object(object)) => {
+                        object
+                            .iter()
+                            .filter_map(|(key, value)| {
+                                iotd.input_field_definitions().get(key.as_ref()).map(
+                                    |input_field_definition| {
+                                        self.input_value_definition_cost(
+                                            input_field_definition,
+                                            value,
+                                        )
+                                    },
+                                )
+                            })
+                            .sum()
+                    }
+                    _ => 0,
+                };
+                cost_weight(&base).unwrap_or(0) + fields_cost
+            }
+        }
+    }
+
+    fn variable_cost(&self, input_type: &S::InputType, variable_name: &str) -> usize {
+        if let Some(value) = self.variable_values.get(variable_name) {
+            return self.input_value_cost(input_type, value);
+        }
+
+        self.operation_definition
+            .as_ref()
+            .variable_definitions()
+            .and_then(|variable_definitions| {
+                variable_definitions
+                    .iter()
+                    .find(|variable_definition| variable_definition.variable() == variable_name)
+            })
+            .and_then(|variable_definition| variable_definition.default_value())
+            .map_or(0, |default_value| {
+                self.input_value_cost(input_type, default_value)
+            })
+    }
+}
+
+fn cost_weight<N: HasDirectives>(item: &N) -> Option<usize> {
+    item.directives()
+        .and_then(|directives| {
+            directives
+                .iter()
+                .find(|directive| directive.name() == COST_DIRECTIVE)
+        })
+        .and_then(|directive| directive.arguments())
+        .and_then(|arguments| {
+            arguments
+                .iter()
+                .find(|argument| argument.name() == WEIGHT_ARGUMENT)
+        })
+        .and_then(|argument| match argument.value().as_ref() {
+            ValueReference::String(weight) => weight.parse::<f64>().ok(),
+            ValueReference::Integer(weight) => Some(weight.into()),
+            ValueReference::Float(weight) => Some(weight),
+            _ => None,
+        })
+        .map(|weight| weight.max(0f64) as usize)
+}
+
+fn list_size_assumed_size<N: HasDirectives>(item: &N) -> Option<i32> {
+    item.directives()
+        .and_then(|directives| {
+            directives
+                .iter()
+                .find(|directive| directive.name() == LIST_SIZE_DIRECTIVE)
+        })
+        .and_then(|directive| directive.arguments())
+        .and_then(|arguments| {
+            arguments
+                .iter()
+                .find(|argument| argument.name() == ASSUMED_SIZE_ARGUMENT)
+        })
+        .and_then(|argument| argument.value().as_ref().as_integer().copied())
+}
+
+fn list_size_names<'b, N: HasDirectives>(item: &'b N, argument_name: &str) -> Vec<&'b str> {
+    item.directives()
+        .and_then(|directives| {
+            directives
+                .iter()
+                .find(|directive| directive.name() == LIST_SIZE_DIRECTIVE)
+        })
+        .and_then(|directive| directive.arguments())
+        .and_then(|arguments| {
+            arguments
+                .iter()
+                .find(|argument| argument.name() == argument_name)
+        })
+        .map(|argument| match argument.value().as_ref() {
+            ValueReference::List(values) => values
+                .iter()
+                .filter_map(|value| match value.as_ref() {
+                    ValueReference::String(name) => Some(name),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        })
+        .unwrap_or_default()
+}
+
+pub struct IbmFieldMultipliers<E: ExecutableDocument> {
+    sized_fields: Vec<String>,
+    size: usize,
+    executable_document: PhantomData<E>,
+}
+
+impl<E: ExecutableDocument> IbmFieldMultipliers<E> {
+    fn size_for_field(&self, field: &E::Field) -> Option<usize> {
+        self.sized_fields
+            .iter()
+            .any(|sized_field| sized_field == field.name())
+            .then_some(self.size)
+    }
+}
+
+impl<E: ExecutableDocument> Default for IbmFieldMultipliers<E> {
+    fn default() -> Self {
+        Self {
+            sized_fields: Vec::new(),
+            size: 1,
+            executable_document: PhantomData,
+        }
+    }
+}
+
+impl<E: ExecutableDocument> FieldMultipliers<E> for IbmFieldMultipliers<E> {
+    fn multiplier_for_field(&self, field: &E::Field) -> usize {
+        self.size_for_field(field).unwrap_or(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IbmCostComputer;
+    use crate::executable::{
+        operation::{
+            analyzers::{
+                complexity_cost::{ComplexityCostExplanation, MultiplierSource},
+                ComplexityCost, ExplainComplexityCost,
+            },
+            Orchestrator,
+        },
+        Cache,
+    };
+    use bluejay_parser::ast::{
+        definition::{DefaultContext, DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    };
+    use serde_json::Value as JsonValue;
+
+    type ComplexityAnalyzer<'a, E, S, V> =
+        Orchestrator<'a, E, S, V, ComplexityCost<'a, E, S, V, IbmCostComputer<'a, E, S, V>>>;
+
+    const TEST_SCHEMA: &str = r#"
+        directive @cost(weight: String!) on ARGUMENT_DEFINITION | ENUM | FIELD_DEFINITION | INPUT_FIELD_DEFINITION | OBJECT | SCALAR
+        directive @listSize(
+          assumedSize: Int
+          slicingArguments: [String!]
+          sizedFields: [String!]
+          requireOneSlicingArgument: Boolean = true
+        ) on FIELD_DEFINITION
+
+        scalar Expensive @cost(weight: "3")
+
+        enum Color @cost(weight: "2") {
+          RED
+        }
+
+        type Author @cost(weight: "4") {
+          name: String
+        }
+
+        type Review {
+          body: String
+        }
+
+        type Book {
+          title: String
+          author: Author
+          color: Color
+          reviews: [Review!]!
+        }
+
+        type BookEdge {
+          node: Book
+        }
+
+        type PageInfo {
+          hasNextPage: Boolean
+        }
+
+        type BookConnection {
+          edges: [BookEdge!]!
+          pageInfo: PageInfo
+        }
+
+        input BookFilter {
+          title: String @cost(weight: "2")
+          tags: [String!] @cost(weight: "1")
+        }
+
+        type Query {
+          books(first: Int, last: Int = 5, filter: BookFilter @cost(weight: "1")): [Book!]!
+            @listSize(slicingArguments: ["first", "last"], requireOneSlicingArgument: false)
+          featured: [Book!]! @listSize(assumedSize: 3)
+          everything: [Book!]!
+          bookConnection(first: Int): BookConnection
+            @listSize(slicingArguments: ["first"], sizedFields: ["edges"])
+          bookConnections: [BookConnection!]!
+            @listSize(assumedSize: 2, sizedFields: ["edges"])
+          expensiveScalar: Expensive
+          expensiveField: String @cost(weight: "7")
+          search(term: String @cost(weight: "5")): [Book!]! @listSize(assumedSize: 2)
+        }
+    "#;
+
+    fn check_complexity_with_variables(
+        source: &str,
+        variables: JsonValue,
+        expected_complexity: usize,
+    ) {
+        let definition_document: DefinitionDocument<'_, DefaultContext> =
+            DefinitionDocument::parse(TEST_SCHEMA)
+                .result
+                .expect("Schema had parse errors");
+        let schema_definition =
+            SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+        let executable_document = ExecutableDocument::parse(source)
+            .result
+            .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let cache = Cache::new(&executable_document, &schema_definition);
+        let variables = variables.as_object().expect("Variables must be an object");
+        let complexity = ComplexityAnalyzer::analyze(
+            &executable_document,
+            &schema_definition,
+            None,
+            variables,
+            &cache,
+            (),
+        )
+        .unwrap();
+
+        assert_eq!(complexity, expected_complexity);
+    }
+
+    fn with_explanation(source: &str, f: impl FnOnce(ComplexityCostExplanation)) {
+        let definition_document: DefinitionDocument<'_, DefaultContext> =
+            DefinitionDocument::parse(TEST_SCHEMA)
+                .result
+                .expect("Schema had parse errors");
+        let schema_definition =
+            SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+        let executable_document = ExecutableDocument::parse(source)
+            .result
+            .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let cache = Cache::new(&executable_document, &schema_definition);
+        let variables = serde_json::Map::new();
+        let explanation = Orchestrator::<
+            _,
+            _,
+            _,
+            ExplainComplexityCost<_, _, _, IbmCostComputer<_, _, _>>,
+        >::analyze(
+            &executable_document,
+            &schema_definition,
+            None,
+            &variables,
+            &cache,
+            (),
+        )
+        .unwrap();
+
+        f(explanation)
+    }
+
+    fn check_complexity(source: &str, expected_complexity: usize) {
+        check_complexity_with_variables(source, serde_json::json!({}), expected_complexity)
+    }
+
+    #[test]
+    fn field_and_type_weights() {
+        check_complexity(r#"{ expensiveField }"#, 7);
+        check_complexity(r#"{ expensiveScalar }"#, 3);
+        check_complexity(
+            r#"{ # (1 + 4 + 2) * 3 = 21
+              featured {
+                title # 0
+                author { name } # 4
+                color # 2
+              }
+            }"#,
+            21,
+        );
+    }
+
+    #[test]
+    fn list_sizes() {
+        check_complexity(r#"{ featured { title } }"#, 3);
+        check_complexity(r#"{ everything { title } }"#, 10);
+        check_complexity(
+            r#"{ # (1 + 10) * 3 = 33
+              featured { reviews { body } } # (1 + 0) * 10 = 10
+            }"#,
+            33,
+        );
+    }
+
+    #[test]
+    fn slicing_arguments() {
+        check_complexity(r#"{ books(first: 20) { title } }"#, 20);
+        check_complexity(r#"{ books(first: 2, last: 3) { title } }"#, 3);
+        // `last` defaults to 5
+        check_complexity(r#"{ books { title } }"#, 5);
+        check_complexity(r#"{ books(first: 2) { title } }"#, 5);
+        check_complexity_with_variables(
+            r#"query($n: Int) { books(first: $n) { title } }"#,
+            serde_json::json!({ "n": 30 }),
+            30,
+        );
+        check_complexity_with_variables(
+            r#"query($n: Int = 7) { books(first: $n, last: 1) { title } }"#,
+            serde_json::json!({}),
+            7,
+        );
+        check_complexity_with_variables(
+            r#"query($n: Int) { books(last: $n) { title } }"#,
+            serde_json::json!({}),
+            5,
+        );
+    }
+
+    #[test]
+    fn sized_fields() {
+        check_complexity(
+            r#"{ # 1 + 8 + 1 = 10
+              bookConnection(first: 4) {
+                edges { node { title } } # (1 + 1) * 4 = 8
+                pageInfo { hasNextPage } # 1
+              }
+            }"#,
+            10,
+        );
+        check_complexity(
+            r#"{ # 1 + 20 = 21
+              bookConnection {
+                edges { node { title } } # (1 + 1) * 10 = 20
+              }
+            }"#,
+            21,
+        );
+        check_complexity(
+            r#"{ # 1 + 4 = 5
+              bookConnections {
+                edges { node { title } } # (1 + 1) * 2 = 4
+              }
+            }"#,
+            5,
+        );
+    }
+
+    #[test]
+    fn argument_weights() {
+        check_complexity(r#"{ search { title } }"#, 2);
+        check_complexity(r#"{ search(term: "x") { title } }"#, 7);
+        check_complexity(r#"{ search(term: null) { title } }"#, 2);
+    }
+
+    #[test]
+    fn input_field_weights() {
+        check_complexity(
+            r#"{ # 1 * 5 + (1 + 2 + 1) = 9
+              books(first: 1, filter: { title: "a", tags: ["x", "y"] }) { title }
+            }"#,
+            9,
+        );
+        check_complexity_with_variables(
+            r#"query($filter: BookFilter) { # 1 * 5 + (1 + 2) = 8
+              books(first: 1, filter: $filter) { title }
+            }"#,
+            serde_json::json!({ "filter": { "title": "a" } }),
+            8,
+        );
+        check_complexity_with_variables(
+            r#"query($filter: BookFilter = { tags: [] }) { # 1 * 5 + (1 + 1) = 7
+              books(first: 1, filter: $filter) { title }
+            }"#,
+            serde_json::json!({}),
+            7,
+        );
+    }
+
+    const EXPLAIN_SOURCE: &str = r#"{
+      bookConnection(first: 4) { edges { node { title } } }
+      books { title }
+      featured { title }
+      everything { title }
+    }"#;
+
+    #[test]
+    fn explanation_multiplier_sources() {
+        with_explanation(EXPLAIN_SOURCE, |explanation| {
+            let sources: Vec<_> = explanation
+                .fields
+                .iter()
+                .map(|field| {
+                    (
+                        field.response_name,
+                        field.multiplier,
+                        field.multiplier_source,
+                    )
+                })
+                .collect();
+            assert_eq!(
+                vec![
+                    ("bookConnection", 1, MultiplierSource::None),
+                    (
+                        "books",
+                        5,
+                        MultiplierSource::DefaultValue { argument: "last" }
+                    ),
+                    ("featured", 3, MultiplierSource::AssumedSize),
+                    ("everything", 10, MultiplierSource::AssumedSize),
+                ],
+                sources,
+            );
+
+            let edges = &explanation.fields[0].children[0];
+            assert_eq!(4, edges.multiplier);
+            assert_eq!(
+                MultiplierSource::Argument { name: "first" },
+                edges.multiplier_source
+            );
+        });
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn explanation_serialization() {
+        with_explanation(EXPLAIN_SOURCE, |explanation| {
+            let json = serde_json::to_value(&explanation).unwrap();
+            assert_eq!(27, json["totalCost"]);
+            assert_eq!(
+                serde_json::json!({
+                    "responseName": "edges",
+                    "path": ["bookConnection", "edges"],
+                    "fieldCost": 1,
+                    "argumentsCost": 0,
+                    "multiplier": 4,
+                    "multiplierSource": { "kind": "argument", "name": "first" },
+                    "subtreeCost": 8,
+                    "children": [{
+                        "responseName": "node",
+                        "path": ["bookConnection", "edges", "node"],
+                        "fieldCost": 1,
+                        "argumentsCost": 0,
+                        "multiplier": 1,
+                        "multiplierSource": { "kind": "none" },
+                        "subtreeCost": 1,
+                        "children": [],
+                    }],
+                }),
+                json["fields"][0]["children"][0],
+            );
+        });
+    }
+}
@@ -1,10 +1,10 @@
 use crate::executable::operation::{
-    analyzers::complexity_cost::{CostComputer, FieldMultipliers},
+    analyzers::complexity_cost::{CostComputer, FieldMultipliers, MultiplierSource},
     OperationDefinitionValueEvaluationExt, VariableValues,
 };
-use bluejay_core::definition::{prelude::*, SchemaDefinition};
+use bluejay_core::definition::{prelude::*, FieldDefinition, HasDirectives, SchemaDefinition};
 use bluejay_core::executable::{ExecutableDocument, Field};
-use bluejay_core::{Argument, AsIter, Directive, Value, ValueReference};
+use bluejay_core::{Argument, AsIter, Directive, Value, ValueReference, Variable};
 use std::marker::PhantomData;
 
 const CONNECTION_COST_KIND: &str = "connection";
@@ -75,33 +75,11 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: VariableValues> CostComp
         field_definition: &<S as SchemaDefinition>::FieldDefinition,
         field: &<E as ExecutableDocument>::Field,
     ) -> RelayFieldMultipliers<E> {
-        let kind = field_definition.directives().and_then(|directives| {
-            directives
-                .iter()
-                .find(|directive| directive.name() == COST_ARGUMENT)
-                .and_then(|directive| directive.arguments())
-                .and_then(|arguments| {
-                    arguments
-                        .iter()
-                        .find(|argument| argument.name() == KIND_ARGUMENT)
-                        .and_then(|argument| {
-                            if let ValueReference::String(str) = argument.value().as_ref() {
-                                Some(str)
-                            } else {
-                                None
-                            }
-                        })
-                })
-        });
-
-        match kind {
+        match cost_kind(field_definition) {
             Some(CONNECTION_COST_KIND) => {
-                let (first_size, last_size) = (
-                    self.extract_field_sizing_argument(field, CONNECTION_FIRST_ARGUMENT),
-                    self.extract_field_sizing_argument(field, CONNECTION_LAST_ARGUMENT),
-                );
-
-                let static_size = first_size.into_iter().chain(last_size).max().unwrap_or(0);
+                let static_size = self
+                    .connection_size(field_definition, field)
+                    .map_or(0, |(size, _)| size);
 
                 let multiplier = Self::multiplier_for_static_size(static_size);
 
@@ -116,25 +94,91 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: VariableValues> CostComp
             },
         }
     }
+
+    fn multiplier_source(
+        &self,
+        _field_definition: &'a S::FieldDefinition,
+        field: &'a E::Field,
+        parent: Option<(&'a S::FieldDefinition, &'a E::Field)>,
+    ) -> MultiplierSource<'a> {
+        match (field.name(), parent) {
+            (
+                CONNECTION_EDGES_FIELD | CONNECTION_NODES_FIELD,
+                Some((parent_definition, parent)),
+            ) if cost_kind(parent_definition) == Some(CONNECTION_COST_KIND) => self
+                .connection_size(parent_definition, parent)
+                .map_or(MultiplierSource::None, |(_, source)| source),
+            _ => MultiplierSource::None,
+        }
+    }
 }
 
 impl<E: ExecutableDocument, S: SchemaDefinition, V: VariableValues> RelayCostComputer<'_, E, S, V> {
-    fn extract_field_sizing_argument(
+    /// The largest of the `first` and `last` arguments of a connection field, and where it came from.
+    fn connection_size<'b, D: FieldDefinition, F: Field>(
         &self,
-        field: &<E as ExecutableDocument>::Field,
-        argument_name: &str,
-    ) -> Option<usize> {
-        field
+        field_definition: &'b D,
+        field: &'b F,
+    ) -> Option<(usize, MultiplierSource<'b>)> {
+        let (first_size, last_size) = (
+            self.extract_field_sizing_argument(field_definition, field, CONNECTION_FIRST_ARGUMENT),
+            self.extract_field_sizing_argument(field_definition, field, CONNECTION_LAST_ARGUMENT),
+        );
+
+        first_size
+            .into_iter()
+            .chain(last_size)
+            .max_by_key(|(size, _)| *size)
+    }
+
+    /// The value passed for the sizing argument, either directly or through a variable, or the
+    /// argument's default value when it is omitted or the variable is unset.
+    fn extract_field_sizing_argument<'b, D: FieldDefinition, F: Field>(
+        &self,
+        field_definition: &'b D,
+        field: &'b F,
+        argument_name: &'b str,
+    ) -> Option<(usize, MultiplierSource<'b>)> {
+        let passed_value = field
             .arguments()
             .and_then(|arguments| arguments.iter().find(|arg| arg.name() == argument_name))
             .and_then(|argument| match argument.value().as_ref() {
-                ValueReference::Integer(int) => Some(int.max(0) as usize),
+                ValueReference::Integer(int) => Some((
+                    int.max(0) as usize,
+                    MultiplierSource::Argument {
+                        name: argument.name(),
+                    },
+                )),
                 ValueReference::Variable(var) => self
                     .operation_definition
                     .evaluate_int(var, self.variable_values)
-                    .map(|i| i.max(0) as usize),
+                    .map(|i| {
+                        (
+                            i.max(0) as usize,
+                            MultiplierSource::Variable {
+                                argument: argument.name(),
+                                variable: var.name(),
+                            },
+                        )
+                    }),
                 _ => None,
-            })
+            });
+
+        passed_value.or_else(|| {
+            field_definition
+                .arguments_definition()
+                .and_then(|arguments_definition| arguments_definition.get(argument_name))
+                .and_then(|argument_definition| argument_definition.default_value())
+                .and_then(|value| value.as_ref().as_integer().copied())
+                .map(|int| {
+                    (
+                        int.max(0) as usize,
+                        MultiplierSource::DefaultValue {
+                            argument: argument_name,
+                        },
+                    )
+                })
+        })
     }
 
     fn multiplier_for_static_size(static_size: usize) -> usize {
@@ -172,3 +216,24 @@ impl<E: ExecutableDocument> FieldMultipliers<E> for RelayFieldMultipliers<E> {
         }
     }
 }
+
+fn cost_kind<N: HasDirectives>(item: &N) -> Option<&str> {
+    item.directives().and_then(|directives| {
+        directives
+            .iter()
+            .find(|directive| directive.name() == COST_ARGUMENT)
+            .and_then(|directive| directive.arguments())
+            .and_then(|arguments| {
+                arguments
+                    .iter()
+                    .find(|argument| argument.name() == KIND_ARGUMENT)
+                    .and_then(|argument| {
+                        if let ValueReference::String(str) = argument.value().as_ref() {
+                            Some(str)
+                        } else {
+                            None
+                        }
+                    })
+            })
+    })
+}
@@ -1,6 +1,9 @@
-use crate::executable::{
-    operation::{Analyzer, VariableValues, Visitor},
-    Cache,
+use crate::{
+    executable::{
+        operation::{Analyzer, VariableValues, Visitor},
+        Cache,
+    },
+    JsonPath,
 };
 use bluejay_core::definition::{
     BaseInputTypeReference, EnumTypeDefinition, EnumValueDefinition, HasDirectives,
@@ -25,6 +28,9 @@ pub struct Offender<'a> {
     pub reason: &'a str,
     pub offense_type: UsageType,
     pub name: &'a str,
+    /// For a [`UsageType::Field`], the response path of the field. Otherwise, the path to the
+    /// offending value, starting at the argument or variable name.
+    pub path: JsonPath,
 }
 
 /// The [Deprecation] analyzer will go over all ast-nodes of type Field, EnumValue, Argument and InputField
@@ -36,6 +42,7 @@ pub struct Deprecation<'a, E: ExecutableDocument, S: SchemaDefinition, VV: Varia
     schema_definition: &'a S,
     cache: &'a Cache<'a, E, S>,
     variable_values: &'a VV,
+    response_path: JsonPath,
 }
 
 const DEPRECATED_DIRECTIVE: &str = "deprecated";
@@ -57,6 +64,7 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor
             schema_definition,
             cache,
             variable_values,
+            response_path: JsonPath::default(),
         }
     }
 
@@ -72,13 +80,19 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor
                 .variable_values
                 .get(variable_definition.variable().as_ref())
             {
-                self.find_deprecations_for_value(input_type, value, variable_definition.variable());
+                self.find_deprecations_for_value(
+                    input_type,
+                    value,
+                    variable_definition.variable(),
+                    JsonPath::new(variable_definition.variable()),
+                );
             }
             if let Some(default_value) = variable_definition.default_value() {
                 self.find_deprecations_for_value(
                     input_type,
                     default_value,
                     variable_definition.variable(),
+                    JsonPath::new(variable_definition.variable()),
                 );
             }
         }
@@ -94,6 +108,8 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor
         >,
         included: bool,
     ) {
+        self.response_path.push(field.response_name());
+
         if !included {
             return;
         }
@@ -105,10 +121,24 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor
                 name: field.name(),
                 offense_type: UsageType::Field,
                 reason,
+                path: self.response_path.clone(),
             });
         }
     }
 
+    fn leave_field(
+        &mut self,
+        _field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a <S as SchemaDefinition>::FieldDefinition,
+        _scoped_type: bluejay_core::definition::TypeDefinitionReference<
+            'a,
+            <S as SchemaDefinition>::TypeDefinition,
+        >,
+        _included: bool,
+    ) {
+        self.response_path.pop();
+    }
+
     fn visit_variable_argument(
         &mut self,
         argument: &'a <E as ExecutableDocument>::Argument<false>,
@@ -121,6 +151,7 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor
                 name: argument.name(),
                 offense_type: UsageType::Argument,
                 reason,
+                path: JsonPath::new(argument.name()),
             });
         }
 
@@ -128,6 +159,7 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor
             input_value_definition.r#type(),
             argument.value(),
             argument.name(),
+            JsonPath::new(argument.name()),
         );
     }
 }
@@ -172,13 +204,24 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Depreca
         input_type: &'a I,
         value: &'a V,
         name: &'a str,
+        path: JsonPath,
     ) {
         match input_type.as_ref(self.schema_definition) {
             InputTypeReference::List(inner_list_type, _) => match value.as_ref() {
-                ValueReference::List(list_value) => list_value.iter().for_each(|list_item| {
-                    self.find_deprecations_for_value(inner_list_type, list_item, name)
-                }),
-                _ => self.find_deprecations_for_value(inner_list_type, value, name),
+                ValueReference::List(list_value) => {
+                    list_value
+                        .iter()
+                        .enumerate()
+                        .for_each(|(index, list_item)| {
+                            self.find_deprecations_for_value(
+                                inner_list_type,
+                                list_item,
+                                name,
+                                path.with(index),
+                            )
+                        })
+                }
+                _ => self.find_deprecations_for_value(inner_list_type, value, name, path),
             },
             InputTypeReference::Base(base_input_type, _) => match base_input_type {
                 BaseInputTypeReference::Enum(etd) => {
@@ -204,6 +247,7 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Depreca
                                 name,
                                 offense_type: UsageType::EnumValue,
                                 reason: deprecation_reason,
+                                path,
                             });
                         }
                     }
@@ -218,6 +262,7 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Depreca
                                 });
 
                                 if let Some((_, value)) = found_usage {
+                                    let path = path.with(input_field_definition.name());
                                     if let Some(reason) =
                                         get_deprecation_reason::<S::InputValueDefinition>(
                                             input_field_definition,
@@ -227,6 +272,7 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Depreca
                                             name: input_field_definition.name(),
                                             offense_type: UsageType::InputField,
                                             reason,
+                                            path: path.clone(),
                                         });
                                     }
 
@@ -234,6 +280,7 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Depreca
                                         input_field_definition.r#type(),
                                         value,
                                         name,
+                                        path,
                                     )
                                 }
                             });
@@ -258,9 +305,12 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyze
 #[cfg(test)]
 mod tests {
     use super::{Deprecation, Offender};
-    use crate::executable::{
-        operation::{analyzers::deprecation::UsageType, Orchestrator},
-        Cache,
+    use crate::{
+        executable::{
+            operation::{analyzers::deprecation::UsageType, Orchestrator},
+            Cache,
+        },
+        JsonPath,
     };
     use bluejay_parser::ast::{
         definition::{DefinitionDocument, SchemaDefinition as ParserSchemaDefinition},
@@ -303,6 +353,10 @@ mod tests {
           ): String!
           test_nested_input(nested_input: NestedInput): String!
           test_nested_input_list(nested_input: [NestedInput]): String!
+          test_nested: Nested!
+        }
+        type Nested {
+          test_field: String! @deprecated(reason: "field")
         }
         schema {
           query: Query
@@ -342,6 +396,7 @@ mod tests {
                 name: "test_field",
                 reason: "field",
                 offense_type: UsageType::Field,
+                path: JsonPath::new("test_field"),
             }],
         );
     }
@@ -360,6 +415,7 @@ mod tests {
                 name: "test",
                 reason: "enum_value",
                 offense_type: UsageType::EnumValue,
+                path: JsonPath::new("test"),
             }],
         );
     }
@@ -373,6 +429,7 @@ mod tests {
                 name: "deprecated_enum",
                 reason: "enum_value",
                 offense_type: UsageType::EnumValue,
+                path: JsonPath::new("deprecated_enum"),
             }],
         );
     }
@@ -386,6 +443,7 @@ mod tests {
                 name: "deprecated_arg",
                 reason: "arg",
                 offense_type: UsageType::Argument,
+                path: JsonPath::new("deprecated_arg"),
             }],
         );
     }
@@ -399,6 +457,7 @@ mod tests {
                 name: "deprecated_arg",
                 reason: "arg",
                 offense_type: UsageType::Argument,
+                path: JsonPath::new("deprecated_arg"),
             }],
         );
     }
@@ -412,6 +471,7 @@ mod tests {
                 name: "deprecated_input_field",
                 reason: "input_field",
                 offense_type: UsageType::InputField,
+                path: JsonPath::new("input").with("deprecated_input_field"),
             }],
         );
     }
@@ -425,6 +485,7 @@ mod tests {
                 name: "deprecated_input_field",
                 reason: "input_field",
                 offense_type: UsageType::InputField,
+                path: JsonPath::new("input").with("deprecated_input_field"),
             }],
         );
     }
@@ -440,6 +501,7 @@ mod tests {
                 name: "deprecated_input_field",
                 reason: "input_field",
                 offense_type: UsageType::InputField,
+                path: JsonPath::new("input").with("deprecated_input_field"),
             }],
         );
     }
@@ -453,6 +515,9 @@ mod tests {
                 name: "deprecated_input_field",
                 reason: "input_field",
                 offense_type: UsageType::InputField,
+                path: JsonPath::new("nested_input")
+                    .with("nested")
+                    .with("deprecated_input_field"),
             }],
         );
     }
@@ -466,6 +531,10 @@ mod tests {
                 name: "deprecated_input_field",
                 reason: "input_field",
                 offense_type: UsageType::InputField,
+                path: JsonPath::new("nested_input")
+                    .with(0)
+                    .with("nested")
+                    .with("deprecated_input_field"),
             }],
         );
     }
@@ -479,7 +548,33 @@ mod tests {
                 name: "deprecated_input_field",
                 reason: "input_field",
                 offense_type: UsageType::InputField,
+                path: JsonPath::new("test")
+                    .with(0)
+                    .with("nested")
+                    .with("deprecated_input_field"),
             }],
         );
     }
+
+    #[test]
+    fn nested_field_deprecation_path() {
+        validate_deprecations(
+            r#"query { first: test_nested { test_field } second: test_nested { ...F } } fragment F on Nested { alias: test_field }"#,
+            serde_json::json!({}),
+            vec![
+                Offender {
+                    name: "test_field",
+                    reason: "field",
+                    offense_type: UsageType::Field,
+                    path: JsonPath::new("first").with("test_field"),
+                },
+                Offender {
+                    name: "test_field",
+                    reason: "field",
+                    offense_type: UsageType::Field,
+                    path: JsonPath::new("second").with("alias"),
+                },
+            ],
+        );
+    }
 }
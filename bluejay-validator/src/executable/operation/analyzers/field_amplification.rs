@@ -0,0 +1,428 @@
+use crate::{
+    executable::{
+        operation::{Analyzer, VariableValues, Visitor},
+        Cache,
+    },
+    JsonPath,
+};
+use bluejay_core::definition::{DirectiveLocation, SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::executable::{ExecutableDocument, Field};
+use bluejay_core::AsIter;
+use std::collections::{HashMap, HashSet};
+
+/// The thresholds checked by [`FieldAmplification`]. A limit of `None` is not checked.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FieldAmplificationLimits {
+    /// Maximum number of field selections in the operation, after expanding fragments.
+    pub max_fields: Option<usize>,
+    /// Maximum number of aliased field selections in the operation, after expanding fragments.
+    pub max_aliases: Option<usize>,
+    /// Maximum number of distinct response names a single field can be selected under
+    /// within one selection set, e.g. `a1: expensive a2: expensive`.
+    pub max_field_repetitions: Option<usize>,
+    /// Maximum number of directives on a single field, or on the operation, a fragment spread,
+    /// an inline fragment or a fragment definition.
+    pub max_directives_per_field: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldAmplificationLimit {
+    Fields,
+    Aliases,
+    FieldRepetitions,
+    DirectivesPerField,
+}
+
+/// A limit exceeded by the operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldAmplificationOffender {
+    pub limit: FieldAmplificationLimit,
+    /// The value counted against the limit.
+    pub count: usize,
+    /// Response path of the field at which the limit was first exceeded. For directives on the
+    /// operation or a fragment, this is the path of the enclosing field.
+    pub path: JsonPath,
+}
+
+/// Counts the field selections, aliases, repeated selections of a field and directives per
+/// field of an operation, and reports every limit of [`FieldAmplificationLimits`] that is
+/// exceeded. Fields excluded by `@skip` or `@include` are not counted. Fields selected through
+/// fragments are counted every time they are selected, but a field or fragment with too many
+/// directives is only reported once.
+pub struct FieldAmplification<'a> {
+    limits: FieldAmplificationLimits,
+    path: JsonPath,
+    fields: usize,
+    fields_exceeded_at: Option<JsonPath>,
+    aliases: usize,
+    aliases_exceeded_at: Option<JsonPath>,
+    field_repetition_indices: HashMap<(JsonPath, &'a str), usize>,
+    field_repetitions: Vec<FieldRepetitions<'a>>,
+    directives_offenders: Vec<FieldAmplificationOffender>,
+    /// The directive lists already checked, by address, so that fragments spread several times
+    /// are only reported once.
+    checked_directives: HashSet<*const ()>,
+}
+
+/// The response names a field is selected under within one selection set.
+#[derive(Default)]
+struct FieldRepetitions<'a> {
+    response_names: HashSet<&'a str>,
+    exceeded_at: Option<JsonPath>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor<'a, E, S, VV>
+    for FieldAmplification<'a>
+{
+    type ExtraInfo = FieldAmplificationLimits;
+
+    fn new(
+        _: &'a E::OperationDefinition,
+        _: &'a S,
+        _: &'a VV,
+        _: &'a Cache<'a, E, S>,
+        limits: Self::ExtraInfo,
+    ) -> Self {
+        Self {
+            limits,
+            path: JsonPath::default(),
+            fields: 0,
+            fields_exceeded_at: None,
+            aliases: 0,
+            aliases_exceeded_at: None,
+            field_repetition_indices: HashMap::new(),
+            field_repetitions: Vec::new(),
+            directives_offenders: Vec::new(),
+            checked_directives: HashSet::new(),
+        }
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if !included {
+            return;
+        }
+
+        let field_repetitions_index = *self
+            .field_repetition_indices
+            .entry((self.path.clone(), field.name()))
+            .or_insert_with(|| {
+                self.field_repetitions.push(FieldRepetitions::default());
+                self.field_repetitions.len() - 1
+            });
+
+        self.path.push(field.response_name());
+
+        self.fields += 1;
+        if exceeds(self.fields, self.limits.max_fields) && self.fields_exceeded_at.is_none() {
+            self.fields_exceeded_at = Some(self.path.clone());
+        }
+
+        if field.alias().is_some() {
+            self.aliases += 1;
+            if exceeds(self.aliases, self.limits.max_aliases) && self.aliases_exceeded_at.is_none()
+            {
+                self.aliases_exceeded_at = Some(self.path.clone());
+            }
+        }
+
+        let field_repetitions = &mut self.field_repetitions[field_repetitions_index];
+        field_repetitions
+            .response_names
+            .insert(field.response_name());
+        if exceeds(
+            field_repetitions.response_names.len(),
+            self.limits.max_field_repetitions,
+        ) && field_repetitions.exceeded_at.is_none()
+        {
+            field_repetitions.exceeded_at = Some(self.path.clone());
+        }
+
+        if let Some(directives) = field.directives() {
+            self.check_directives(directives);
+        }
+    }
+
+    fn visit_variable_directives(
+        &mut self,
+        directives: &'a <E as ExecutableDocument>::Directives<false>,
+        location: DirectiveLocation,
+        included: bool,
+    ) {
+        // field directives are checked in `visit_field`, once the field's path is known
+        if included && location != DirectiveLocation::Field {
+            self.check_directives(directives);
+        }
+    }
+
+    fn leave_field(
+        &mut self,
+        _field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if included {
+            self.path.pop();
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'a, E, S, VV>
+    for FieldAmplification<'a>
+{
+    type Output = Vec<FieldAmplificationOffender>;
+
+    fn into_output(self) -> Self::Output {
+        let totals = [
+            (
+                FieldAmplificationLimit::Fields,
+                self.fields,
+                self.fields_exceeded_at,
+            ),
+            (
+                FieldAmplificationLimit::Aliases,
+                self.aliases,
+                self.aliases_exceeded_at,
+            ),
+        ];
+
+        totals
+            .into_iter()
+            .filter_map(|(limit, count, path)| {
+                path.map(|path| FieldAmplificationOffender { limit, count, path })
+            })
+            .chain(
+                self.field_repetitions
+                    .into_iter()
+                    .filter_map(|field_repetitions| {
+                        field_repetitions
+                            .exceeded_at
+                            .map(|path| FieldAmplificationOffender {
+                                limit: FieldAmplificationLimit::FieldRepetitions,
+                                count: field_repetitions.response_names.len(),
+                                path,
+                            })
+                    }),
+            )
+            .chain(self.directives_offenders)
+            .collect()
+    }
+}
+
+impl FieldAmplification<'_> {
+    fn check_directives<T: AsIter>(&mut self, directives: &T) {
+        let count = directives.len();
+        if exceeds(count, self.limits.max_directives_per_field)
+            && self
+                .checked_directives
+                .insert(std::ptr::from_ref(directives).cast())
+        {
+            self.directives_offenders.push(FieldAmplificationOffender {
+                limit: FieldAmplificationLimit::DirectivesPerField,
+                count,
+                path: self.path.clone(),
+            });
+        }
+    }
+}
+
+fn exceeds(count: usize, max: Option<usize>) -> bool {
+    max.is_some_and(|max| count > max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        FieldAmplification, FieldAmplificationLimit, FieldAmplificationLimits,
+        FieldAmplificationOffender,
+    };
+    use crate::executable::{operation::Orchestrator, Cache};
+    use bluejay_parser::ast::{
+        definition::{
+            DefaultContext, DefinitionDocument, SchemaDefinition as ParserSchemaDefinition,
+        },
+        executable::ExecutableDocument as ParserExecutableDocument,
+        Parse,
+    };
+    use serde_json::{Map as JsonMap, Value as JsonValue};
+
+    const TEST_SCHEMA: &str = r#"
+        directive @tag(name: String) repeatable on FIELD | QUERY | FRAGMENT_SPREAD | INLINE_FRAGMENT | FRAGMENT_DEFINITION
+        type Query {
+          expensive: Thing!
+          thing: Thing!
+        }
+        type Thing {
+          id: ID!
+          parent: Thing!
+        }
+        schema {
+          query: Query
+        }
+    "#;
+
+    fn check_offenders(
+        source: &str,
+        variables: JsonValue,
+        limits: FieldAmplificationLimits,
+        expected_offenders: Vec<(FieldAmplificationLimit, usize, Vec<&str>)>,
+    ) {
+        let definition_document: DefinitionDocument<'_, DefaultContext> =
+            DefinitionDocument::parse(TEST_SCHEMA)
+                .result
+                .expect("Schema had parse errors");
+        let schema_definition =
+            ParserSchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+        let executable_document = ParserExecutableDocument::parse(source)
+            .result
+            .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let cache = Cache::new(&executable_document, &schema_definition);
+        let variables = variables.as_object().expect("Variables must be an object");
+        let offenders =
+            Orchestrator::<_, _, JsonMap<String, JsonValue>, FieldAmplification>::analyze(
+                &executable_document,
+                &schema_definition,
+                None,
+                variables,
+                &cache,
+                limits,
+            )
+            .unwrap();
+
+        assert_eq!(
+            expected_offenders
+                .into_iter()
+                .map(|(limit, count, path)| FieldAmplificationOffender {
+                    limit,
+                    count,
+                    path: path.into_iter().map(Into::into).collect(),
+                })
+                .collect::<Vec<_>>(),
+            offenders,
+        );
+    }
+
+    #[test]
+    fn no_limits() {
+        check_offenders(
+            "{ a1: expensive { id } a2: expensive { id } }",
+            serde_json::json!({}),
+            FieldAmplificationLimits::default(),
+            vec![],
+        );
+    }
+
+    #[test]
+    fn aliased_repetitions() {
+        check_offenders(
+            r#"{
+              a1: expensive { id }
+              a2: expensive { id }
+              a3: expensive { id }
+              thing { a: id b: id }
+            }"#,
+            serde_json::json!({}),
+            FieldAmplificationLimits {
+                max_aliases: Some(4),
+                max_field_repetitions: Some(2),
+                ..Default::default()
+            },
+            vec![
+                (FieldAmplificationLimit::Aliases, 5, vec!["thing", "b"]),
+                (FieldAmplificationLimit::FieldRepetitions, 3, vec!["a3"]),
+            ],
+        );
+    }
+
+    #[test]
+    fn fields_through_fragments() {
+        check_offenders(
+            r#"{
+              thing { ...F parent { ...F } }
+            }
+            fragment F on Thing { id parent { id } }"#,
+            serde_json::json!({}),
+            FieldAmplificationLimits {
+                max_fields: Some(5),
+                max_field_repetitions: Some(1),
+                ..Default::default()
+            },
+            vec![(
+                FieldAmplificationLimit::Fields,
+                8,
+                vec!["thing", "parent", "id"],
+            )],
+        );
+    }
+
+    #[test]
+    fn skipped_fields_are_not_counted() {
+        check_offenders(
+            r#"query($skip: Boolean!) {
+              a1: expensive @skip(if: $skip) { id }
+              a2: expensive @include(if: false) { id }
+              a3: expensive { id @tag(name: "a") @tag(name: "b") }
+            }"#,
+            serde_json::json!({ "skip": true }),
+            FieldAmplificationLimits {
+                max_fields: Some(2),
+                max_aliases: Some(1),
+                max_field_repetitions: Some(1),
+                max_directives_per_field: Some(1),
+            },
+            vec![(
+                FieldAmplificationLimit::DirectivesPerField,
+                2,
+                vec!["a3", "id"],
+            )],
+        );
+    }
+
+    #[test]
+    fn directives_on_operations_and_fragments() {
+        check_offenders(
+            r#"query @tag @tag {
+              thing {
+                ...F @tag @tag
+                ... @tag @tag { id }
+                parent { ...F }
+              }
+            }
+            fragment F on Thing @tag @tag { id @tag @tag }"#,
+            serde_json::json!({}),
+            FieldAmplificationLimits {
+                max_directives_per_field: Some(1),
+                ..Default::default()
+            },
+            vec![
+                (FieldAmplificationLimit::DirectivesPerField, 2, vec![]),
+                (
+                    FieldAmplificationLimit::DirectivesPerField,
+                    2,
+                    vec!["thing"],
+                ),
+                (
+                    FieldAmplificationLimit::DirectivesPerField,
+                    2,
+                    vec!["thing"],
+                ),
+                (
+                    FieldAmplificationLimit::DirectivesPerField,
+                    2,
+                    vec!["thing", "id"],
+                ),
+                (
+                    FieldAmplificationLimit::DirectivesPerField,
+                    2,
+                    vec!["thing"],
+                ),
+            ],
+        );
+    }
+}
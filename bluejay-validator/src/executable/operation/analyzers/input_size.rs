@@ -1,6 +1,9 @@
-use crate::executable::{
-    operation::{Analyzer, VariableValues, Visitor},
-    Cache,
+use crate::{
+    executable::{
+        operation::{Analyzer, VariableValues, Visitor},
+        Cache,
+    },
+    JsonPath,
 };
 use bluejay_core::{
     definition::SchemaDefinition,
@@ -14,6 +17,8 @@ use bluejay_core::{
 pub struct Offender {
     pub size: usize,
     pub name: String,
+    /// Path to the offending list, starting at the argument name.
+    pub path: JsonPath,
 }
 
 #[derive(Clone)]
@@ -58,7 +63,7 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor
             &mut self.offenders,
             self.variable_values,
             self.variable_definitions,
-            argument.name().to_string(),
+            JsonPath::new(argument.name()),
             argument.value(),
         );
     }
@@ -78,7 +83,7 @@ fn find_input_size_offenders_arguments<
     offenders: &mut Vec<Offender>,
     variable_values: &VV,
     variable_definitions: Option<&E::VariableDefinitions>,
-    argument_name: String,
+    path: JsonPath,
     argument_value: &<E as bluejay_core::executable::ExecutableDocument>::Value<CONST>,
 ) {
     match argument_value.as_ref() {
@@ -87,7 +92,8 @@ fn find_input_size_offenders_arguments<
             if list_length > max_length {
                 offenders.push(Offender {
                     size: list_length,
-                    name: argument_name,
+                    name: path.to_string(),
+                    path,
                 })
             } else {
                 list.iter().enumerate().for_each(|(index, item)| {
@@ -96,7 +102,7 @@ fn find_input_size_offenders_arguments<
                         offenders,
                         variable_values,
                         variable_definitions,
-                        format!("{argument_name}.{index}"),
+                        path.with(index),
                         item,
                     );
                 })
@@ -109,7 +115,7 @@ fn find_input_size_offenders_arguments<
                     offenders,
                     variable_values,
                     variable_definitions,
-                    format!("{}.{}", argument_name, key.as_ref()),
+                    path.with(key.as_ref()),
                     value,
                 );
             });
@@ -118,17 +124,12 @@ fn find_input_size_offenders_arguments<
             let name = var.name();
             let variable = variable_values.get(name);
             if let Some(value) = variable {
-                find_input_size_offenders_variables::<E, VV>(
-                    max_length,
-                    offenders,
-                    argument_name,
-                    value,
-                );
+                find_input_size_offenders_variables::<E, VV>(max_length, offenders, path, value);
             } else {
                 let variable_definition = variable_definitions.map(|variable_definitions| {
                     variable_definitions
                         .iter()
-                        .find(|def| def.variable() == argument_name)
+                        .find(|def| def.variable() == name)
                 });
                 if let Some(Some(var_def)) = variable_definition {
                     let default_value = var_def.default_value();
@@ -138,7 +139,7 @@ fn find_input_size_offenders_arguments<
                             offenders,
                             variable_values,
                             variable_definitions,
-                            argument_name,
+                            path,
                             default_value,
                         );
                     }
@@ -154,7 +155,7 @@ fn find_input_size_offenders_arguments<
 fn find_input_size_offenders_variables<E: ExecutableDocument, VV: VariableValues>(
     max_length: usize,
     offenders: &mut Vec<Offender>,
-    argument_name: String,
+    path: JsonPath,
     argument_value: &VV::Value,
 ) {
     match argument_value.as_ref() {
@@ -163,14 +164,15 @@ fn find_input_size_offenders_variables<E: ExecutableDocument, VV: VariableValues
             if list_length > max_length {
                 offenders.push(Offender {
                     size: list_length,
-                    name: argument_name,
+                    name: path.to_string(),
+                    path,
                 })
             } else {
                 list.iter().enumerate().for_each(|(index, item)| {
                     find_input_size_offenders_variables::<E, VV>(
                         max_length,
                         offenders,
-                        format!("{argument_name}.{index}"),
+                        path.with(index),
                         item,
                     );
                 })
@@ -181,7 +183,7 @@ fn find_input_size_offenders_variables<E: ExecutableDocument, VV: VariableValues
                 find_input_size_offenders_variables::<E, VV>(
                     max_length,
                     offenders,
-                    format!("{}.{}", argument_name, key.as_ref()),
+                    path.with(key.as_ref()),
                     value,
                 );
             });
@@ -203,7 +205,10 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyze
 #[cfg(test)]
 mod tests {
     use super::{InputSize, Offender};
-    use crate::executable::{operation::Orchestrator, Cache};
+    use crate::{
+        executable::{operation::Orchestrator, Cache},
+        JsonPath,
+    };
     use bluejay_parser::ast::{
         definition::{
             DefaultContext, DefinitionDocument, SchemaDefinition as ParserSchemaDefinition,
@@ -283,6 +288,17 @@ mod tests {
         assert_eq!(result.name, "x");
     }
 
+    #[test]
+    fn variable_default_value_named_differently_from_argument() {
+        let result = analyze_input_size(
+            r#"query ($y: [String] = ["a", "b"]) { simple(x: $y)} "#,
+            serde_json::json!({}),
+        );
+        let result = result.first().unwrap();
+        assert_eq!(result.size, 2);
+        assert_eq!(result.name, "x");
+    }
+
     #[test]
     fn simple_size_variable_default_value() {
         let result = analyze_input_size(
@@ -303,6 +319,7 @@ mod tests {
         let result = result.first().unwrap();
         assert_eq!(result.size, 2);
         assert_eq!(result.name, "x.property");
+        assert_eq!(result.path, JsonPath::new("x").with("property"));
     }
 
     #[test]
@@ -314,6 +331,7 @@ mod tests {
         let result = result.first().unwrap();
         assert_eq!(result.size, 2);
         assert_eq!(result.name, "x.property");
+        assert_eq!(result.path, JsonPath::new("x").with("property"));
     }
 
     #[test]
@@ -349,6 +367,7 @@ mod tests {
         let first = result.first().unwrap();
         assert_eq!(first.size, 2);
         assert_eq!(first.name, "x.0.property");
+        assert_eq!(first.path, JsonPath::new("x").with(0).with("property"));
     }
 
     #[test]
@@ -360,5 +379,6 @@ mod tests {
         let result = result.first().unwrap();
         assert_eq!(result.size, 2);
         assert_eq!(result.name, "x.0.property");
+        assert_eq!(result.path, JsonPath::new("x").with(0).with("property"));
     }
 }
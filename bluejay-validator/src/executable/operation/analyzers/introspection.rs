@@ -0,0 +1,290 @@
+use crate::{
+    executable::{
+        operation::{Analyzer, VariableValues, Visitor},
+        Cache,
+    },
+    JsonPath,
+};
+use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::executable::{ExecutableDocument, Field};
+use std::cmp::max;
+
+const SCHEMA_FIELD: &str = "__schema";
+const TYPE_FIELD: &str = "__type";
+const TYPENAME_FIELD: &str = "__typename";
+
+/// Whether an operation queries introspection, based on its root fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntrospectionKind {
+    /// No root field is an introspection field.
+    None,
+    /// Some, but not all, root fields are introspection fields.
+    Mixed,
+    /// Every root field is `__schema`, `__type` or `__typename`.
+    IntrospectionOnly,
+}
+
+/// The output of [`Introspection`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntrospectionReport {
+    pub kind: IntrospectionKind,
+    /// Response paths of every `__schema`, `__type` and `__typename` field.
+    pub introspection_fields: Vec<JsonPath>,
+    /// Maximum depth of selections within a `__schema` or `__type` field, counting the
+    /// introspection field itself. Unlike [`QueryDepth`](super::QueryDepth), fields outside of
+    /// introspection do not count towards it.
+    pub max_depth: usize,
+    /// Response paths of the `__type` lookups beyond the maximum allowed count.
+    pub excessive_type_lookups: Vec<JsonPath>,
+}
+
+/// Finds the introspection fields of an operation, measures the depth of introspection
+/// queries and flags `__type` lookups beyond the maximum count given as extra info.
+/// Fields excluded by `@skip` or `@include` are ignored.
+pub struct Introspection {
+    max_type_lookups: usize,
+    path: JsonPath,
+    introspection_depth: Option<usize>,
+    max_depth: usize,
+    has_introspection_root_field: bool,
+    has_other_root_field: bool,
+    introspection_fields: Vec<JsonPath>,
+    type_lookups: usize,
+    excessive_type_lookups: Vec<JsonPath>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor<'a, E, S, VV>
+    for Introspection
+{
+    type ExtraInfo = usize;
+
+    fn new(
+        _: &'a E::OperationDefinition,
+        _: &'a S,
+        _: &'a VV,
+        _: &'a Cache<'a, E, S>,
+        max_type_lookups: Self::ExtraInfo,
+    ) -> Self {
+        Self {
+            max_type_lookups,
+            path: JsonPath::default(),
+            introspection_depth: None,
+            max_depth: 0,
+            has_introspection_root_field: false,
+            has_other_root_field: false,
+            introspection_fields: Vec::new(),
+            type_lookups: 0,
+            excessive_type_lookups: Vec::new(),
+        }
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if !included {
+            return;
+        }
+
+        let is_root = self.path.is_empty();
+        let name = field.name();
+        self.path.push(field.response_name());
+
+        let is_introspection_field = matches!(name, SCHEMA_FIELD | TYPE_FIELD | TYPENAME_FIELD);
+        if is_root {
+            if is_introspection_field {
+                self.has_introspection_root_field = true;
+            } else {
+                self.has_other_root_field = true;
+            }
+        }
+        if is_introspection_field {
+            self.introspection_fields.push(self.path.clone());
+        }
+
+        if let Some(depth) = self.introspection_depth.as_mut() {
+            *depth += 1;
+            self.max_depth = max(self.max_depth, *depth);
+        } else if is_root && matches!(name, SCHEMA_FIELD | TYPE_FIELD) {
+            self.introspection_depth = Some(1);
+            self.max_depth = max(self.max_depth, 1);
+        }
+
+        if is_root && name == TYPE_FIELD {
+            self.type_lookups += 1;
+            if self.type_lookups > self.max_type_lookups {
+                self.excessive_type_lookups.push(self.path.clone());
+            }
+        }
+    }
+
+    fn leave_field(
+        &mut self,
+        _field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if !included {
+            return;
+        }
+
+        self.path.pop();
+        if let Some(depth) = self.introspection_depth.as_mut() {
+            *depth -= 1;
+            if *depth == 0 {
+                self.introspection_depth = None;
+            }
+        }
+    }
+}
+
+impl<E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'_, E, S, VV>
+    for Introspection
+{
+    type Output = IntrospectionReport;
+
+    fn into_output(self) -> Self::Output {
+        let kind = match (self.has_introspection_root_field, self.has_other_root_field) {
+            (true, false) => IntrospectionKind::IntrospectionOnly,
+            (true, true) => IntrospectionKind::Mixed,
+            (false, _) => IntrospectionKind::None,
+        };
+
+        IntrospectionReport {
+            kind,
+            introspection_fields: self.introspection_fields,
+            max_depth: self.max_depth,
+            excessive_type_lookups: self.excessive_type_lookups,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Introspection, IntrospectionKind, IntrospectionReport};
+    use crate::{
+        executable::{operation::Orchestrator, Cache},
+        JsonPath,
+    };
+    use bluejay_parser::ast::{
+        definition::{
+            DefaultContext, DefinitionDocument, SchemaDefinition as ParserSchemaDefinition,
+        },
+        executable::ExecutableDocument as ParserExecutableDocument,
+        Parse,
+    };
+    use serde_json::{Map as JsonMap, Value as JsonValue};
+
+    const TEST_SCHEMA: &str = r#"
+        type Query {
+          thing: Thing!
+        }
+        type Thing {
+          id: ID!
+        }
+        schema {
+          query: Query
+        }
+    "#;
+
+    fn check_introspection(
+        source: &str,
+        max_type_lookups: usize,
+        f: impl FnOnce(IntrospectionReport),
+    ) {
+        let definition_document: DefinitionDocument<'_, DefaultContext> =
+            DefinitionDocument::parse(TEST_SCHEMA)
+                .result
+                .expect("Schema had parse errors");
+        let schema_definition =
+            ParserSchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+        let executable_document = ParserExecutableDocument::parse(source)
+            .result
+            .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let cache = Cache::new(&executable_document, &schema_definition);
+        let variables = JsonMap::new();
+        let report = Orchestrator::<_, _, JsonMap<String, JsonValue>, Introspection>::analyze(
+            &executable_document,
+            &schema_definition,
+            None,
+            &variables,
+            &cache,
+            max_type_lookups,
+        )
+        .unwrap();
+
+        f(report);
+    }
+
+    #[test]
+    fn no_introspection() {
+        check_introspection("{ thing { id } }", 1, |report| {
+            assert_eq!(IntrospectionKind::None, report.kind);
+            assert!(report.introspection_fields.is_empty());
+            assert_eq!(0, report.max_depth);
+        });
+    }
+
+    #[test]
+    fn nested_typename_is_not_introspection_query() {
+        check_introspection("{ thing { __typename id } }", 1, |report| {
+            assert_eq!(IntrospectionKind::None, report.kind);
+            assert_eq!(
+                vec![JsonPath::new("thing").with("__typename")],
+                report.introspection_fields
+            );
+            assert_eq!(0, report.max_depth);
+        });
+    }
+
+    #[test]
+    fn introspection_only() {
+        check_introspection(
+            r#"{
+              __typename
+              __schema {
+                types {
+                  fields {
+                    type { ofType { ofType { name } } }
+                  }
+                }
+              }
+            }"#,
+            1,
+            |report| {
+                assert_eq!(IntrospectionKind::IntrospectionOnly, report.kind);
+                assert_eq!(
+                    vec![JsonPath::new("__typename"), JsonPath::new("__schema")],
+                    report.introspection_fields,
+                );
+                assert_eq!(7, report.max_depth);
+            },
+        );
+    }
+
+    #[test]
+    fn mixed_with_type_lookups() {
+        check_introspection(
+            r#"{
+              thing { id }
+              a: __type(name: "Thing") { name }
+              b: __type(name: "Query") { name }
+              c: __type(name: "String") @skip(if: true) { name }
+              d: __type(name: "ID") { fields { name } }
+            }"#,
+            1,
+            |report| {
+                assert_eq!(IntrospectionKind::Mixed, report.kind);
+                assert_eq!(3, report.max_depth);
+                assert_eq!(
+                    vec![JsonPath::new("b"), JsonPath::new("d")],
+                    report.excessive_type_lookups
+                );
+            },
+        );
+    }
+}
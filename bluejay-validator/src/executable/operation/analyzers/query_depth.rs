@@ -1,10 +1,20 @@
-use crate::executable::{
-    operation::{Analyzer, VariableValues, Visitor},
-    Cache,
+use crate::{
+    executable::{
+        operation::{Analyzer, VariableValues, Visitor},
+        Cache,
+    },
+    JsonPath,
 };
-use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
-use bluejay_core::executable::ExecutableDocument;
-use std::cmp::max;
+use bluejay_core::definition::{
+    FieldDefinition, OutputType, SchemaDefinition, ShallowOutputTypeReference,
+    TypeDefinitionReference,
+};
+use bluejay_core::executable::{ExecutableDocument, Field};
+use std::cmp::{max, Ordering};
+
+const SCHEMA_FIELD: &str = "__schema";
+const TYPE_FIELD: &str = "__type";
+const TYPENAME_FIELD: &str = "__typename";
 
 pub struct QueryDepth {
     current_depth: usize,
@@ -65,10 +75,218 @@ impl<E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'_
     }
 }
 
+/// The fields that count towards the depth computed by [`ExplainQueryDepth`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CountedFields {
+    /// Every field counts, as in [`QueryDepth`].
+    #[default]
+    All,
+    /// Only fields of an object, interface or union type count.
+    Composite,
+    /// Only fields of a list type count.
+    List,
+}
+
+/// Options for [`ExplainQueryDepth`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QueryDepthOptions {
+    /// Whether `__typename`, and `__schema` and `__type` along with their selections, are left
+    /// out of the depth.
+    pub ignore_introspection: bool,
+    pub counted_fields: CountedFields,
+    /// When set, the traversal of the operation stops as soon as a field deeper than this is
+    /// found, so that deeply nested operations can be rejected without visiting them in full.
+    pub max_depth: Option<usize>,
+}
+
+/// The output of [`ExplainQueryDepth`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryDepthReport {
+    pub max_depth: usize,
+    /// Response paths of the counted fields at the maximum depth. A path selected more than once,
+    /// e.g. through several fragments, is only included once.
+    pub deepest_paths: Vec<JsonPath>,
+    /// Whether the depth exceeded [`QueryDepthOptions::max_depth`]. When it did, the traversal
+    /// stopped at the first field beyond the limit, and the other fields of the report only
+    /// reflect the part of the operation visited up to that point.
+    pub exceeded: bool,
+}
+
+/// A [`QueryDepth`] that outputs a [`QueryDepthReport`] with the deepest response paths, and
+/// that can be configured with [`QueryDepthOptions`] to only count some fields or to abort
+/// once a maximum depth is exceeded. Fields selected through fragments count at the depth at
+/// which the fragment is spread, including fragments deferred with `@defer`, as their fields
+/// are delivered at that same path.
+pub struct ExplainQueryDepth<'a, S: SchemaDefinition> {
+    schema_definition: &'a S,
+    options: QueryDepthOptions,
+    path: JsonPath,
+    /// Whether each field of `path` counts towards the depth.
+    counted: Vec<bool>,
+    current_depth: usize,
+    /// Length of `path` at the introspection field being ignored, if any.
+    ignored_introspection_at: Option<usize>,
+    max_depth: usize,
+    deepest_paths: Vec<JsonPath>,
+    exceeded: bool,
+    /// The number of fields entered since the traversal should have halted, which happens when
+    /// other visitors of a tuple have not halted yet. These fields are ignored.
+    fields_visited_after_halt: usize,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor<'a, E, S, VV>
+    for ExplainQueryDepth<'a, S>
+{
+    type ExtraInfo = QueryDepthOptions;
+
+    fn new(
+        _: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        _: &'a VV,
+        _: &'a Cache<'a, E, S>,
+        options: Self::ExtraInfo,
+    ) -> Self {
+        Self {
+            schema_definition,
+            options,
+            path: JsonPath::default(),
+            counted: Vec::new(),
+            current_depth: 0,
+            ignored_introspection_at: None,
+            max_depth: 0,
+            deepest_paths: Vec::new(),
+            exceeded: false,
+            fields_visited_after_halt: 0,
+        }
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if !included {
+            return;
+        }
+
+        if self.exceeded {
+            self.fields_visited_after_halt += 1;
+            return;
+        }
+
+        self.path.push(field.response_name());
+
+        if self.options.ignore_introspection
+            && self.ignored_introspection_at.is_none()
+            && matches!(field.name(), SCHEMA_FIELD | TYPE_FIELD)
+        {
+            self.ignored_introspection_at = Some(self.path.len());
+        }
+
+        let counted = self.ignored_introspection_at.is_none()
+            && !(self.options.ignore_introspection && field.name() == TYPENAME_FIELD)
+            && self.counts(field_definition);
+        self.counted.push(counted);
+
+        if !counted {
+            return;
+        }
+
+        self.current_depth += 1;
+        if self
+            .options
+            .max_depth
+            .is_some_and(|max_depth| self.current_depth > max_depth)
+        {
+            self.exceeded = true;
+        }
+
+        match self.current_depth.cmp(&self.max_depth) {
+            Ordering::Greater => {
+                self.max_depth = self.current_depth;
+                self.deepest_paths = vec![self.path.clone()];
+            }
+            Ordering::Equal if !self.deepest_paths.contains(&self.path) => {
+                self.deepest_paths.push(self.path.clone());
+            }
+            _ => {}
+        }
+    }
+
+    fn leave_field(
+        &mut self,
+        _field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if !included {
+            return;
+        }
+
+        if self.fields_visited_after_halt > 0 {
+            self.fields_visited_after_halt -= 1;
+            return;
+        }
+
+        if self.ignored_introspection_at == Some(self.path.len()) {
+            self.ignored_introspection_at = None;
+        }
+        self.path.pop();
+        if self.counted.pop() == Some(true) {
+            self.current_depth -= 1;
+        }
+    }
+
+    fn should_halt(&self) -> bool {
+        self.exceeded
+    }
+}
+
+impl<'a, S: SchemaDefinition> ExplainQueryDepth<'a, S> {
+    fn counts(&self, field_definition: &'a S::FieldDefinition) -> bool {
+        match self.options.counted_fields {
+            CountedFields::All => true,
+            CountedFields::Composite => field_definition
+                .r#type()
+                .base(self.schema_definition)
+                .is_composite(),
+            CountedFields::List => matches!(
+                field_definition.r#type().as_shallow_ref(),
+                ShallowOutputTypeReference::List(..)
+            ),
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'a, E, S, VV>
+    for ExplainQueryDepth<'a, S>
+{
+    type Output = QueryDepthReport;
+
+    fn into_output(self) -> Self::Output {
+        QueryDepthReport {
+            max_depth: self.max_depth,
+            deepest_paths: self.deepest_paths,
+            exceeded: self.exceeded,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::QueryDepth;
-    use crate::executable::{operation::Orchestrator, Cache};
+    use super::{
+        CountedFields, ExplainQueryDepth, QueryDepth, QueryDepthOptions, QueryDepthReport,
+    };
+    use crate::{
+        executable::{
+            operation::{analyzers::ComplexityCost, Orchestrator},
+            Cache,
+        },
+        JsonPath,
+    };
     use bluejay_parser::ast::{
         definition::{
             DefaultContext, DefinitionDocument, SchemaDefinition as ParserSchemaDefinition,
@@ -134,6 +352,39 @@ mod tests {
         assert_eq!(depth, expected_depth);
     }
 
+    fn check_report(source: &str, options: QueryDepthOptions, f: impl FnOnce(QueryDepthReport)) {
+        let definition_document: DefinitionDocument<'_, DefaultContext> =
+            DefinitionDocument::parse(TEST_SCHEMA)
+                .result
+                .expect("Schema had parse errors");
+        let schema_definition =
+            ParserSchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+        let executable_document = ParserExecutableDocument::parse(source)
+            .result
+            .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let cache = Cache::new(&executable_document, &schema_definition);
+        let variables = JsonMap::new();
+        let report =
+            Orchestrator::<_, _, JsonMap<String, JsonValue>, ExplainQueryDepth<_>>::analyze(
+                &executable_document,
+                &schema_definition,
+                None,
+                &variables,
+                &cache,
+                options,
+            )
+            .unwrap();
+
+        f(report);
+    }
+
+    fn paths(paths: &[&[&str]]) -> Vec<JsonPath> {
+        paths
+            .iter()
+            .map(|path| path.iter().copied().map(Into::into).collect())
+            .collect()
+    }
+
     #[test]
     fn basic_depth_metrics() {
         check_depth(r#"{ ping }"#, None, serde_json::json!({}), 1);
@@ -302,4 +553,185 @@ mod tests {
             2,
         );
     }
+
+    #[test]
+    fn depth_with_deferred_fragments() {
+        check_depth(
+            r#"query {
+                d1: ping
+                ... @defer { d3: thing { parent { id } } }
+            }"#,
+            None,
+            serde_json::json!({}),
+            3,
+        );
+
+        check_depth(
+            r#"query {
+                d1: ping
+                ...D3 @defer(label: "d3")
+            }
+            fragment D3 on Query {
+                d3: thing { parent { id } }
+            }"#,
+            None,
+            serde_json::json!({}),
+            3,
+        );
+    }
+
+    #[test]
+    fn deepest_paths() {
+        check_report(
+            r#"{
+                thing { parent { parent { id } } }
+                node {
+                    ...on Product { things { parent { title } } }
+                    ...on Thing { title }
+                }
+                ping
+            }"#,
+            QueryDepthOptions::default(),
+            |report| {
+                assert_eq!(4, report.max_depth);
+                assert_eq!(
+                    paths(&[
+                        &["thing", "parent", "parent", "id"],
+                        &["node", "things", "parent", "title"],
+                    ]),
+                    report.deepest_paths,
+                );
+                assert!(!report.exceeded);
+            },
+        );
+    }
+
+    #[test]
+    fn ignore_introspection() {
+        check_report(
+            r#"{
+                __typename
+                thing { __typename }
+                __schema { types { fields { name } } }
+            }"#,
+            QueryDepthOptions {
+                ignore_introspection: true,
+                ..Default::default()
+            },
+            |report| {
+                assert_eq!(1, report.max_depth);
+                assert_eq!(paths(&[&["thing"]]), report.deepest_paths);
+            },
+        );
+    }
+
+    #[test]
+    fn counted_fields() {
+        let source = r#"{
+            thing { parent { id } }
+            node { ...on Product { things { parent { title } } } }
+        }"#;
+
+        check_report(
+            source,
+            QueryDepthOptions {
+                counted_fields: CountedFields::Composite,
+                ..Default::default()
+            },
+            |report| {
+                assert_eq!(3, report.max_depth);
+                assert_eq!(
+                    paths(&[&["node", "things", "parent"]]),
+                    report.deepest_paths
+                );
+            },
+        );
+
+        check_report(
+            source,
+            QueryDepthOptions {
+                counted_fields: CountedFields::List,
+                ..Default::default()
+            },
+            |report| {
+                assert_eq!(1, report.max_depth);
+                assert_eq!(paths(&[&["node", "things"]]), report.deepest_paths);
+            },
+        );
+    }
+
+    #[test]
+    fn max_depth_halts_traversal() {
+        check_report(
+            r#"{
+                thing { parent { parent { parent { id } } } }
+                node { ...on Product { things { parent { parent { parent { id } } } } } }
+            }"#,
+            QueryDepthOptions {
+                max_depth: Some(2),
+                ..Default::default()
+            },
+            |report| {
+                assert!(report.exceeded);
+                assert_eq!(3, report.max_depth);
+                assert_eq!(
+                    paths(&[&["thing", "parent", "parent"]]),
+                    report.deepest_paths
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn max_depth_only_halts_its_own_traversal_in_a_tuple() {
+        let definition_document: DefinitionDocument<'_, DefaultContext> =
+            DefinitionDocument::parse(TEST_SCHEMA)
+                .result
+                .expect("Schema had parse errors");
+        let schema_definition =
+            ParserSchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+        let executable_document = ParserExecutableDocument::parse(
+            r#"{
+                thing { parent { parent { parent { id } } } }
+                node { ...on Product { things { parent { parent { parent { id } } } } } }
+                ping
+            }"#,
+        )
+        .result
+        .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let cache = Cache::new(&executable_document, &schema_definition);
+        let variables = JsonMap::new();
+        let (report, complexity) = Orchestrator::<
+            _,
+            _,
+            JsonMap<String, JsonValue>,
+            (ExplainQueryDepth<_>, ComplexityCost<_, _, _>),
+        >::analyze(
+            &executable_document,
+            &schema_definition,
+            None,
+            &variables,
+            &cache,
+            (
+                QueryDepthOptions {
+                    max_depth: Some(2),
+                    ..Default::default()
+                },
+                (),
+            ),
+        )
+        .unwrap();
+
+        // the same report as when the depth is analyzed on its own
+        assert_eq!(
+            QueryDepthReport {
+                max_depth: 3,
+                deepest_paths: paths(&[&["thing", "parent", "parent"]]),
+                exceeded: true,
+            },
+            report,
+        );
+        // while every field is still costed: 5 under `thing`, 6 under `node` and `ping`
+        assert_eq!(12, complexity);
+    }
 }
@@ -0,0 +1,675 @@
+use crate::{
+    executable::{
+        operation::{Analyzer, VariableValues, Visitor},
+        Cache,
+    },
+    JsonPath,
+};
+use bluejay_core::definition::{
+    BaseInputTypeReference, DirectiveDefinition, EnumTypeDefinition, EnumValueDefinition,
+    FieldDefinition, InputFieldsDefinition, InputObjectTypeDefinition, InputType,
+    InputTypeReference, InputValueDefinition, ObjectTypeDefinition, OutputType, SchemaDefinition,
+    TypeDefinitionReference,
+};
+use bluejay_core::executable::{
+    ExecutableDocument, Field, InlineFragment, OperationDefinition, VariableDefinition,
+};
+use bluejay_core::{
+    Argument, AsIter, Directive, ObjectValue, OperationType, Value, ValueReference, Variable,
+};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+/// A member of the schema, identified as described by the
+/// [schema coordinates spec](https://github.com/graphql/graphql-wg/blob/main/rfcs/SchemaCoordinates.md).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SchemaCoordinate<'a> {
+    Type {
+        name: &'a str,
+    },
+    Field {
+        type_name: &'a str,
+        field_name: &'a str,
+    },
+    Argument {
+        type_name: &'a str,
+        field_name: &'a str,
+        argument_name: &'a str,
+    },
+    EnumValue {
+        type_name: &'a str,
+        value_name: &'a str,
+    },
+    InputField {
+        type_name: &'a str,
+        field_name: &'a str,
+    },
+    Directive {
+        name: &'a str,
+    },
+    DirectiveArgument {
+        directive_name: &'a str,
+        argument_name: &'a str,
+    },
+}
+
+impl Display for SchemaCoordinate<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Type { name } => write!(f, "{name}"),
+            Self::Field {
+                type_name,
+                field_name,
+            }
+            | Self::InputField {
+                type_name,
+                field_name,
+            } => write!(f, "{type_name}.{field_name}"),
+            Self::Argument {
+                type_name,
+                field_name,
+                argument_name,
+            } => write!(f, "{type_name}.{field_name}({argument_name}:)"),
+            Self::EnumValue {
+                type_name,
+                value_name,
+            } => write!(f, "{type_name}.{value_name}"),
+            Self::Directive { name } => write!(f, "@{name}"),
+            Self::DirectiveArgument {
+                directive_name,
+                argument_name,
+            } => write!(f, "@{directive_name}({argument_name}:)"),
+        }
+    }
+}
+
+/// How often, and where, a [`SchemaCoordinate`] is used by an operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaCoordinateUsage<'a> {
+    pub coordinate: SchemaCoordinate<'a>,
+    /// The number of times the coordinate is used, counting fields selected through fragments
+    /// every time they are selected.
+    pub count: usize,
+    /// The distinct response paths of the fields that use the coordinate, in the order first
+    /// used.
+    pub paths: Vec<JsonPath>,
+}
+
+/// The [SchemaUsage] analyzer collects every [`SchemaCoordinate`] used by an operation:
+/// its root operation type, the type conditions of its fragments, the fields it selects and
+/// their return types, the arguments of those fields and of the directives applied to them, and
+/// the input fields, enum values and input types of the argument values. Values of variables are
+/// taken from the variable values, falling back to their default values, and omitted arguments
+/// with a default value are recorded along with their default value. Fields excluded by `@skip`
+/// or `@include` are ignored, as are meta-fields like `__typename` and the fields of the
+/// introspection types.
+///
+/// This method will output the [`SchemaCoordinateUsage`] of every used coordinate, in the
+/// order first used.
+pub struct SchemaUsage<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> {
+    schema_definition: &'a S,
+    variable_values: &'a VV,
+    variable_definitions: Option<&'a E::VariableDefinitions>,
+    path: JsonPath,
+    usages: Vec<SchemaCoordinateUsage<'a>>,
+    usage_indices: HashMap<SchemaCoordinate<'a>, usize>,
+    /// The paths of each usage, indexed like `usages`, to check for duplicates in constant time.
+    usage_paths: Vec<HashSet<JsonPath>>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor<'a, E, S, VV>
+    for SchemaUsage<'a, E, S, VV>
+{
+    type ExtraInfo = ();
+
+    fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        variable_values: &'a VV,
+        _: &'a Cache<'a, E, S>,
+        _: Self::ExtraInfo,
+    ) -> Self {
+        let mut instance = Self {
+            schema_definition,
+            variable_values,
+            variable_definitions: operation_definition.as_ref().variable_definitions(),
+            path: JsonPath::default(),
+            usages: Vec::new(),
+            usage_indices: HashMap::new(),
+            usage_paths: Vec::new(),
+        };
+
+        let root_operation_type = match operation_definition.as_ref().operation_type() {
+            OperationType::Query => Some(schema_definition.query()),
+            OperationType::Mutation => schema_definition.mutation(),
+            OperationType::Subscription => schema_definition.subscription(),
+        };
+        if let Some(root_operation_type) = root_operation_type {
+            instance.record(SchemaCoordinate::Type {
+                name: root_operation_type.name(),
+            });
+        }
+
+        instance
+    }
+
+    fn visit_inline_fragment(
+        &mut self,
+        inline_fragment: &'a <E as ExecutableDocument>::InlineFragment,
+        fragment_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if included && inline_fragment.type_condition().is_some() {
+            self.record(SchemaCoordinate::Type {
+                name: fragment_type.name(),
+            });
+        }
+    }
+
+    fn visit_fragment_spread(
+        &mut self,
+        _fragment_spread: &'a <E as ExecutableDocument>::FragmentSpread,
+        _fragment_definition: &'a <E as ExecutableDocument>::FragmentDefinition,
+        fragment_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if included {
+            self.record(SchemaCoordinate::Type {
+                name: fragment_type.name(),
+            });
+        }
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        field_definition: &'a S::FieldDefinition,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if !included {
+            return;
+        }
+
+        self.path.push(field.response_name());
+
+        let type_name = scoped_type.name();
+        let field_name = field_definition.name();
+        if field_name.starts_with("__") || type_name.starts_with("__") {
+            return;
+        }
+
+        self.record(SchemaCoordinate::Field {
+            type_name,
+            field_name,
+        });
+        self.record(SchemaCoordinate::Type {
+            name: field_definition.r#type().base_name(),
+        });
+
+        self.record_arguments_and_directives(field, field_definition, type_name);
+    }
+
+    fn leave_field(
+        &mut self,
+        _field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if included {
+            self.path.pop();
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> SchemaUsage<'a, E, S, VV> {
+    fn record_arguments_and_directives<F: Field>(
+        &mut self,
+        field: &'a F,
+        field_definition: &'a S::FieldDefinition,
+        type_name: &'a str,
+    ) {
+        let field_name = field_definition.name();
+
+        if let Some(arguments_definition) = field_definition.arguments_definition() {
+            self.record_arguments(field.arguments(), arguments_definition, |argument_name| {
+                SchemaCoordinate::Argument {
+                    type_name,
+                    field_name,
+                    argument_name,
+                }
+            });
+        }
+
+        if let Some(directives) = field.directives() {
+            directives.iter().for_each(|directive| {
+                let Some(directive_definition) = self
+                    .schema_definition
+                    .get_directive_definition(directive.name())
+                else {
+                    return;
+                };
+                let directive_name = directive_definition.name();
+                self.record(SchemaCoordinate::Directive {
+                    name: directive_name,
+                });
+
+                if let Some(arguments_definition) = directive_definition.arguments_definition() {
+                    self.record_arguments(
+                        directive.arguments(),
+                        arguments_definition,
+                        |argument_name| SchemaCoordinate::DirectiveArgument {
+                            directive_name,
+                            argument_name,
+                        },
+                    );
+                }
+            });
+        }
+    }
+
+    /// Records the arguments passed, and the omitted arguments that have a default value. An
+    /// argument passed a variable without a value or default value is treated as omitted.
+    fn record_arguments<const CONST: bool, A: Argument<CONST>>(
+        &mut self,
+        arguments: Option<&'a impl AsIter<Item = A>>,
+        arguments_definition: &'a S::ArgumentsDefinition,
+        coordinate: impl Fn(&'a str) -> SchemaCoordinate<'a>,
+    ) {
+        arguments_definition.iter().for_each(|ivd| {
+            let value = arguments
+                .and_then(|arguments| {
+                    arguments
+                        .iter()
+                        .find(|argument| argument.name() == ivd.name())
+                })
+                .map(Argument::value)
+                .filter(|value| !self.is_unset_variable(*value));
+
+            match (value, ivd.default_value()) {
+                (Some(value), _) => {
+                    self.record(coordinate(ivd.name()));
+                    self.record_value(ivd.r#type(), value);
+                }
+                (None, Some(default_value)) => {
+                    self.record(coordinate(ivd.name()));
+                    self.record_value(ivd.r#type(), default_value);
+                }
+                (None, None) => {}
+            }
+        });
+    }
+
+    fn is_unset_variable<const CONST: bool, V: Value<CONST>>(&self, value: &V) -> bool {
+        match value.as_ref() {
+            ValueReference::Variable(variable) => {
+                self.variable_values.get(variable.name()).is_none()
+                    && self
+                        .variable_definitions
+                        .and_then(|defs: &E::VariableDefinitions| {
+                            defs.iter().find(|def| def.variable() == variable.name())
+                        })
+                        .is_none_or(|def| def.default_value().is_none())
+            }
+            _ => false,
+        }
+    }
+
+    fn record(&mut self, coordinate: SchemaCoordinate<'a>) {
+        let index = *self.usage_indices.entry(coordinate).or_insert_with(|| {
+            self.usages.push(SchemaCoordinateUsage {
+                coordinate,
+                count: 0,
+                paths: Vec::new(),
+            });
+            self.usages.len() - 1
+        });
+        let usage = &mut self.usages[index];
+        usage.count += 1;
+        if index == self.usage_paths.len() {
+            self.usage_paths.push(HashSet::new());
+        }
+        if !self.usage_paths[index].contains(&self.path) {
+            self.usage_paths[index].insert(self.path.clone());
+            usage.paths.push(self.path.clone());
+        }
+    }
+
+    fn record_value<
+        const CONST: bool,
+        I: InputType<
+            CustomScalarTypeDefinition = S::CustomScalarTypeDefinition,
+            InputObjectTypeDefinition = S::InputObjectTypeDefinition,
+            EnumTypeDefinition = S::EnumTypeDefinition,
+        >,
+        V: Value<CONST>,
+    >(
+        &mut self,
+        input_type: &'a I,
+        value: &V,
+    ) {
+        if let ValueReference::Variable(variable) = value.as_ref() {
+            if let Some(value) = self.variable_values.get(variable.name()) {
+                self.record_value(input_type, value);
+            } else if let Some(default_value) =
+                self.variable_definitions
+                    .and_then(|defs: &E::VariableDefinitions| {
+                        defs.iter()
+                            .find(|def| def.variable() == variable.name())
+                            .and_then(VariableDefinition::default_value)
+                    })
+            {
+                self.record_value(input_type, default_value);
+            }
+            return;
+        }
+
+        if matches!(value.as_ref(), ValueReference::Null) {
+            return;
+        }
+
+        match input_type.as_ref(self.schema_definition) {
+            InputTypeReference::List(inner_list_type, _) => match value.as_ref() {
+                ValueReference::List(list_value) => list_value
+                    .iter()
+                    .for_each(|list_item| self.record_value(inner_list_type, list_item)),
+                _ => self.record_value(inner_list_type, value),
+            },
+            InputTypeReference::Base(base_input_type, _) => {
+                self.record(SchemaCoordinate::Type {
+                    name: base_input_type.name(),
+                });
+                match base_input_type {
+                    BaseInputTypeReference::Enum(etd) => {
+                        let enum_value = match value.as_ref() {
+                            ValueReference::Enum(enum_value) => Some(enum_value),
+                            ValueReference::String(string_value)
+                                if V::can_coerce_string_value_to_enum() =>
+                            {
+                                Some(string_value)
+                            }
+                            _ => None,
+                        };
+                        if let Some(evd) = enum_value.and_then(|enum_value| {
+                            etd.enum_value_definitions()
+                                .iter()
+                                .find(|evd| evd.name() == enum_value)
+                        }) {
+                            self.record(SchemaCoordinate::EnumValue {
+                                type_name: etd.name(),
+                                value_name: evd.name(),
+                            });
+                        }
+                    }
+                    BaseInputTypeReference::InputObject(iotd) => {
+                        if let ValueReference::Object(object_value) = value.as_ref() {
+                            object_value.iter().for_each(|(key, value)| {
+                                if let Some(ifd) = iotd.input_field_definitions().get(key.as_ref())
+                                {
+                                    self.record(SchemaCoordinate::InputField {
+                                        type_name: iotd.name(),
+                                        field_name: ifd.name(),
+                                    });
+                                    self.record_value(ifd.r#type(), value);
+                                }
+                            });
+                        }
+                    }
+                    BaseInputTypeReference::BuiltinScalar(_)
+                    | BaseInputTypeReference::CustomScalar(_) => {}
+                }
+            }
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'a, E, S, VV>
+    for SchemaUsage<'a, E, S, VV>
+{
+    type Output = Vec<SchemaCoordinateUsage<'a>>;
+
+    fn into_output(self) -> Self::Output {
+        self.usages
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SchemaUsage;
+    use crate::executable::{operation::Orchestrator, Cache};
+    use bluejay_parser::ast::{
+        definition::{
+            DefaultContext, DefinitionDocument, SchemaDefinition as ParserSchemaDefinition,
+        },
+        executable::ExecutableDocument as ParserExecutableDocument,
+        Parse,
+    };
+    use serde_json::{Map as JsonMap, Value as JsonValue};
+
+    const TEST_SCHEMA: &str = r#"
+        directive @tag(name: String) on FIELD
+        enum Order {
+          ASC
+          DESC
+        }
+        input Filter {
+          term: String
+          order: Order
+          nested: [Filter!]
+        }
+        type Query {
+          things(filter: Filter, first: Int): [Thing!]!
+          thing(id: ID!): Thing
+        }
+        type Thing {
+          id: ID!
+          name: String
+          children(order: Order = ASC): [Thing!]!
+        }
+        schema {
+          query: Query
+        }
+    "#;
+
+    fn usages(source: &str, variables: JsonValue) -> Vec<(String, usize, Vec<String>)> {
+        let definition_document: DefinitionDocument<'_, DefaultContext> =
+            DefinitionDocument::parse(TEST_SCHEMA)
+                .result
+                .expect("Schema had parse errors");
+        let schema_definition =
+            ParserSchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+        let executable_document = ParserExecutableDocument::parse(source)
+            .result
+            .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let cache = Cache::new(&executable_document, &schema_definition);
+        let variables = variables.as_object().expect("Variables must be an object");
+        Orchestrator::<_, _, JsonMap<String, JsonValue>, SchemaUsage<_, _, _>>::analyze(
+            &executable_document,
+            &schema_definition,
+            None,
+            variables,
+            &cache,
+            (),
+        )
+        .unwrap()
+        .into_iter()
+        .map(|usage| {
+            (
+                usage.coordinate.to_string(),
+                usage.count,
+                usage.paths.iter().map(ToString::to_string).collect(),
+            )
+        })
+        .collect()
+    }
+
+    fn usage(coordinate: &str, count: usize, paths: &[&str]) -> (String, usize, Vec<String>) {
+        (
+            coordinate.to_string(),
+            count,
+            paths.iter().map(ToString::to_string).collect(),
+        )
+    }
+
+    #[test]
+    fn fields_and_types() {
+        assert_eq!(
+            vec![
+                usage("Query", 1, &[""]),
+                usage("Query.thing", 1, &["thing"]),
+                usage("Thing", 3, &["thing", "thing.children"]),
+                usage("Query.thing(id:)", 1, &["thing"]),
+                usage("ID", 2, &["thing", "thing.id"]),
+                usage("Thing.id", 1, &["thing.id"]),
+                usage("Thing.children", 1, &["thing.children"]),
+                usage("Thing.children(order:)", 1, &["thing.children"]),
+                usage("Order", 1, &["thing.children"]),
+                usage("Order.ASC", 1, &["thing.children"]),
+                usage(
+                    "Thing.name",
+                    2,
+                    &["thing.children.name", "thing.children.n"]
+                ),
+                usage("String", 2, &["thing.children.name", "thing.children.n"]),
+            ],
+            usages(
+                r#"{
+                  thing(id: "1") {
+                    id
+                    children { ...F n: name skipped: id @skip(if: true) }
+                  }
+                }
+                fragment F on Thing { name }"#,
+                serde_json::json!({}),
+            ),
+        );
+    }
+
+    #[test]
+    fn argument_values_and_variables() {
+        assert_eq!(
+            vec![
+                usage("Query", 1, &[""]),
+                usage("Query.things", 1, &["things"]),
+                usage("Thing", 2, &["things", "things.children"]),
+                usage("Query.things(filter:)", 1, &["things"]),
+                usage("Filter", 2, &["things"]),
+                usage("Filter.nested", 1, &["things"]),
+                usage("Filter.order", 1, &["things"]),
+                usage("Order", 2, &["things", "things.children"]),
+                usage("Order.DESC", 2, &["things", "things.children"]),
+                usage("Query.things(first:)", 1, &["things"]),
+                usage("Thing.children", 1, &["things.children"]),
+                usage("Thing.children(order:)", 1, &["things.children"]),
+                usage("@tag", 1, &["things.children"]),
+                usage("@tag(name:)", 1, &["things.children"]),
+                usage("String", 1, &["things.children"]),
+            ],
+            usages(
+                r#"query($filter: Filter, $order: Order = DESC, $first: Int) {
+                  things(filter: $filter, first: $first) {
+                    children(order: $order) @tag(name: "x") { __typename }
+                  }
+                }"#,
+                serde_json::json!({
+                    "filter": { "nested": [{ "order": "DESC" }] },
+                    "first": null,
+                }),
+            ),
+        );
+    }
+
+    #[test]
+    fn fragment_type_conditions() {
+        assert_eq!(
+            vec![
+                usage("Query", 3, &[""]),
+                usage("Query.thing", 1, &["thing"]),
+                usage("Thing", 3, &["thing"]),
+                usage("Query.thing(id:)", 1, &["thing"]),
+                usage("ID", 3, &["thing", "thing.id"]),
+                usage("Thing.id", 2, &["thing.id"]),
+                usage("Thing.name", 1, &["thing.name"]),
+                usage("String", 1, &["thing.name"]),
+            ],
+            usages(
+                r#"{
+                  ... on Query {
+                    thing(id: "1") @skip(if: true) { id }
+                    ...Q
+                  }
+                }
+                fragment Q on Query {
+                  thing(id: "1") {
+                    ... on Thing { id }
+                    ... { name }
+                    ...F
+                    ...F @include(if: false)
+                  }
+                }
+                fragment F on Thing { id }"#,
+                serde_json::json!({}),
+            ),
+        );
+    }
+
+    #[test]
+    fn meta_fields_are_ignored() {
+        assert_eq!(
+            vec![
+                usage("Query", 1, &[""]),
+                usage("Query.thing", 1, &["thing"]),
+                usage("Thing", 1, &["thing"]),
+                usage("Query.thing(id:)", 1, &["thing"]),
+                usage("ID", 1, &["thing"]),
+            ],
+            usages(
+                r#"{
+                  __typename
+                  thing(id: "1") { __typename }
+                  __schema { queryType { name } }
+                }"#,
+                serde_json::json!({}),
+            ),
+        );
+    }
+
+    #[test]
+    fn argument_default_values() {
+        assert_eq!(
+            vec![
+                usage("Query", 1, &[""]),
+                usage("Query.things", 1, &["things"]),
+                usage(
+                    "Thing",
+                    4,
+                    &["things", "things.children", "things.c", "things.n"]
+                ),
+                usage(
+                    "Thing.children",
+                    3,
+                    &["things.children", "things.c", "things.n"]
+                ),
+                usage(
+                    "Thing.children(order:)",
+                    3,
+                    &["things.children", "things.c", "things.n"]
+                ),
+                usage("Order", 2, &["things.children", "things.c"]),
+                usage("Order.ASC", 1, &["things.children"]),
+                usage("Order.DESC", 1, &["things.c"]),
+            ],
+            usages(
+                r#"query($order: Order, $desc: Order) {
+                  things {
+                    children(order: $order) { __typename }
+                    c: children(order: $desc) { __typename }
+                    n: children(order: null) { __typename }
+                  }
+                }"#,
+                serde_json::json!({ "desc": "DESC" }),
+            ),
+        );
+    }
+}
@@ -0,0 +1,485 @@
+use crate::{
+    executable::{
+        operation::{Analyzer, VariableValues, Visitor},
+        Cache,
+    },
+    value::input_coercion::{CoerceInput, CoercedValue},
+    JsonPath,
+};
+use bluejay_core::definition::{
+    BaseInputTypeReference, InputFieldsDefinition, InputObjectTypeDefinition, InputType,
+    InputTypeReference, InputValueDefinition, SchemaDefinition,
+};
+use bluejay_core::executable::{
+    ExecutableDocument, VariableDefinition, VariableType, VariableTypeReference,
+};
+use bluejay_core::{Argument, AsIter, ObjectValue, Value, ValueReference, Variable};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// The [`VariableHygiene`] analyzer joins the variable definitions of the operation with the
+/// variable values and the positions the variables are used in, to surface variables that
+/// clients could simplify. Its [`VariableHygieneReport`] describes a single request, and is
+/// meant to be aggregated across traffic, e.g. a variable with a [`VariableFinding::ValueEqualsDefault`]
+/// for every request of an operation can be removed from the operation.
+pub struct VariableHygiene<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> {
+    schema_definition: &'a S,
+    variable_values: &'a VV,
+    cache: &'a Cache<'a, E, S>,
+    usages: HashMap<&'a str, Vec<VariableUsage>>,
+    report: VariableHygieneReport<'a>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
+pub struct VariableHygieneReport<'a> {
+    /// Statistics for every variable definition of the operation, in definition order.
+    pub variables: Vec<VariableStatistics<'a>>,
+    pub findings: Vec<VariableFinding<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
+pub struct VariableStatistics<'a> {
+    pub name: &'a str,
+    /// The type of the variable as written in the variable definition, e.g. `[ID!]!`.
+    pub type_name: String,
+    pub has_default_value: bool,
+    pub value_source: VariableValueSource,
+    /// The length of the list value of the variable after input coercion, which wraps a single
+    /// item in a list, when the variable has a list type.
+    pub list_length: Option<usize>,
+    /// The number of positions the variable is used in, counting the uses within a fragment
+    /// once for every time the fragment is spread.
+    pub usage_count: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
+pub enum VariableValueSource {
+    /// The variable values contain a value for the variable, which may be `null`.
+    Provided,
+    /// The variable values contain no value for the variable, so its default value is used.
+    DefaultValue,
+    /// The variable values contain no value for the variable, which has no default value.
+    Omitted,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(tag = "kind", rename_all = "camelCase")
+)]
+pub enum VariableFinding<'a> {
+    /// The provided value is the same as the default value of the variable after input
+    /// coercion, so it could have been omitted.
+    ValueEqualsDefault { variable: &'a str },
+    /// A value different from the default value of the variable was provided. When this holds
+    /// for all traffic, the default value is never used.
+    DefaultValueOverridden { variable: &'a str },
+    /// A nullable variable is used in a position that requires a non-null value, which is only
+    /// allowed because the variable or the position has a default value.
+    NullableVariableInNonNullPosition {
+        variable: &'a str,
+        /// The argument the variable is used in, followed by the input fields and list indices
+        /// leading to the position, e.g. `["input", "ids", 0]`.
+        position: JsonPath,
+    },
+    /// The value of a variable of a list type has exactly one item.
+    SingleItemList { variable: &'a str },
+}
+
+impl VariableFinding<'_> {
+    pub fn variable(&self) -> &str {
+        match self {
+            Self::ValueEqualsDefault { variable }
+            | Self::DefaultValueOverridden { variable }
+            | Self::NullableVariableInNonNullPosition { variable, .. }
+            | Self::SingleItemList { variable } => variable,
+        }
+    }
+}
+
+struct VariableUsage {
+    position: JsonPath,
+    is_required: bool,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor<'a, E, S, VV>
+    for VariableHygiene<'a, E, S, VV>
+{
+    type ExtraInfo = ();
+
+    fn new(
+        _: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        variable_values: &'a VV,
+        cache: &'a Cache<'a, E, S>,
+        _: Self::ExtraInfo,
+    ) -> Self {
+        Self {
+            schema_definition,
+            variable_values,
+            cache,
+            usages: HashMap::new(),
+            report: VariableHygieneReport::default(),
+        }
+    }
+
+    fn visit_variable_argument(
+        &mut self,
+        argument: &'a <E as ExecutableDocument>::Argument<false>,
+        input_value_definition: &'a <S as SchemaDefinition>::InputValueDefinition,
+    ) {
+        self.visit_value(
+            argument.value(),
+            input_value_definition.r#type(),
+            JsonPath::new(argument.name()),
+        );
+    }
+
+    // variable definitions are visited after all of the selections of the operation, so all of
+    // the usages of the variables are known at this point
+    fn visit_variable_definition(
+        &mut self,
+        variable_definition: &'a <E as ExecutableDocument>::VariableDefinition,
+    ) {
+        let name = variable_definition.variable();
+        let variable_type = variable_definition.r#type().as_ref();
+        let value = self.variable_values.get(name);
+        let default_value = variable_definition.default_value();
+        let usages = self.usages.remove(name).unwrap_or_default();
+
+        let value_source = match (value, default_value) {
+            (Some(_), _) => VariableValueSource::Provided,
+            (None, Some(_)) => VariableValueSource::DefaultValue,
+            (None, None) => VariableValueSource::Omitted,
+        };
+
+        let coerced_value = self.coerce(variable_definition, value);
+        let coerced_default_value = self.coerce(variable_definition, default_value);
+
+        if value.is_some() && default_value.is_some() {
+            self.report
+                .findings
+                .push(match (&coerced_value, &coerced_default_value) {
+                    (Some(coerced_value), Some(coerced_default_value))
+                        if coerced_value == coerced_default_value =>
+                    {
+                        VariableFinding::ValueEqualsDefault { variable: name }
+                    }
+                    _ => VariableFinding::DefaultValueOverridden { variable: name },
+                });
+        }
+
+        if !variable_type.is_required() {
+            self.report
+                .findings
+                .extend(
+                    usages
+                        .iter()
+                        .filter(|usage| usage.is_required)
+                        .map(|usage| VariableFinding::NullableVariableInNonNullPosition {
+                            variable: name,
+                            position: usage.position.clone(),
+                        }),
+                );
+        }
+
+        let list_length = match variable_type {
+            VariableTypeReference::List(..) => match coerced_value.or(coerced_default_value) {
+                Some(CoercedValue::List(items)) => Some(items.len()),
+                _ => None,
+            },
+            VariableTypeReference::Named(..) => None,
+        };
+
+        if list_length == Some(1) {
+            self.report
+                .findings
+                .push(VariableFinding::SingleItemList { variable: name });
+        }
+
+        self.report.variables.push(VariableStatistics {
+            name,
+            type_name: variable_type.display_name(),
+            has_default_value: default_value.is_some(),
+            value_source,
+            list_length,
+            usage_count: usages.len(),
+        });
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues>
+    VariableHygiene<'a, E, S, VV>
+{
+    fn visit_value(
+        &mut self,
+        value: &'a <E as ExecutableDocument>::Value<false>,
+        r#type: &'a S::InputType,
+        position: JsonPath,
+    ) {
+        match value.as_ref() {
+            ValueReference::Variable(variable) => {
+                self.usages
+                    .entry(variable.name())
+                    .or_default()
+                    .push(VariableUsage {
+                        position,
+                        is_required: r#type.as_ref(self.schema_definition).is_required(),
+                    });
+            }
+            ValueReference::List(items) => {
+                if let InputTypeReference::List(item_type, _) =
+                    r#type.as_ref(self.schema_definition)
+                {
+                    items.iter().enumerate().for_each(|(index, item)| {
+                        self.visit_value(item, item_type, position.with(index));
+                    });
+                }
+            }
+            ValueReference::Object(object) => {
+                if let BaseInputTypeReference::InputObject(iotd) =
+                    r#type.base(self.schema_definition)
+                {
+                    object.iter().for_each(|(key, value)| {
+                        if let Some(ivd) = iotd.input_field_definitions().get(key.as_ref()) {
+                            self.visit_value(value, ivd.r#type(), position.with(key.as_ref()));
+                        }
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn coerce<V: Value<true>>(
+        &self,
+        variable_definition: &'a E::VariableDefinition,
+        value: Option<&V>,
+    ) -> Option<CoercedValue> {
+        let input_type = self
+            .cache
+            .variable_definition_input_type(variable_definition.r#type())?;
+        self.schema_definition
+            .coerce_const_value_to_owned(input_type, value?, Default::default())
+            .ok()
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'a, E, S, VV>
+    for VariableHygiene<'a, E, S, VV>
+{
+    type Output = VariableHygieneReport<'a>;
+
+    fn into_output(self) -> Self::Output {
+        self.report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        VariableFinding, VariableHygiene, VariableHygieneReport, VariableStatistics,
+        VariableValueSource,
+    };
+    use crate::{
+        executable::{operation::Orchestrator, Cache},
+        JsonPath,
+    };
+    use bluejay_parser::ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    };
+    use once_cell::sync::Lazy;
+    use serde_json::{json, Map as JsonMap, Value as JsonValue};
+
+    const TEST_SCHEMA_SDL: &str = r#"
+        type Query {
+            products(first: Int! = 10, ids: [ID!], filter: ProductFilter): [String!]!
+            product(id: ID!): String
+        }
+
+        enum Order { ASC DESC }
+
+        input ProductFilter {
+            order: Order! = ASC
+            tags: [String!]
+        }
+    "#;
+
+    static TEST_DEFINITION_DOCUMENT: Lazy<DefinitionDocument<'static>> =
+        Lazy::new(|| DefinitionDocument::parse(TEST_SCHEMA_SDL).result.unwrap());
+
+    static TEST_SCHEMA_DEFINITION: Lazy<SchemaDefinition<'static>> =
+        Lazy::new(|| SchemaDefinition::try_from(&*TEST_DEFINITION_DOCUMENT).unwrap());
+
+    type VariableHygieneAnalyzer<'a, E, S> = Orchestrator<
+        'a,
+        E,
+        S,
+        JsonMap<String, JsonValue>,
+        VariableHygiene<'a, E, S, JsonMap<String, JsonValue>>,
+    >;
+
+    fn analyze(source: &str, variable_values: JsonValue, f: fn(VariableHygieneReport)) {
+        let executable_document = ExecutableDocument::parse(source).result.unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        f(VariableHygieneAnalyzer::analyze(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            variable_values.as_object().unwrap(),
+            &cache,
+            (),
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn test_default_values() {
+        analyze(
+            r#"
+            query($first: Int = 10, $order: Order = DESC, $id: ID!) {
+                products(first: $first, filter: { order: $order })
+                product(id: $id)
+            }
+            "#,
+            json!({ "first": 10, "order": "ASC", "id": 1 }),
+            |report| {
+                assert_eq!(
+                    vec![
+                        VariableFinding::ValueEqualsDefault { variable: "first" },
+                        VariableFinding::NullableVariableInNonNullPosition {
+                            variable: "first",
+                            position: JsonPath::new("first"),
+                        },
+                        VariableFinding::DefaultValueOverridden { variable: "order" },
+                        VariableFinding::NullableVariableInNonNullPosition {
+                            variable: "order",
+                            position: JsonPath::new("filter").with("order"),
+                        },
+                    ],
+                    report.findings,
+                );
+                assert_eq!(
+                    vec![
+                        VariableStatistics {
+                            name: "first",
+                            type_name: "Int".to_owned(),
+                            has_default_value: true,
+                            value_source: VariableValueSource::Provided,
+                            list_length: None,
+                            usage_count: 1,
+                        },
+                        VariableStatistics {
+                            name: "order",
+                            type_name: "Order".to_owned(),
+                            has_default_value: true,
+                            value_source: VariableValueSource::Provided,
+                            list_length: None,
+                            usage_count: 1,
+                        },
+                        VariableStatistics {
+                            name: "id",
+                            type_name: "ID!".to_owned(),
+                            has_default_value: false,
+                            value_source: VariableValueSource::Provided,
+                            list_length: None,
+                            usage_count: 1,
+                        },
+                    ],
+                    report.variables,
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_omitted_values() {
+        analyze(
+            r#"
+            query($first: Int = 5, $ids: [ID!]) {
+                products(first: $first, ids: $ids)
+            }
+            "#,
+            json!({}),
+            |report| {
+                assert_eq!(
+                    vec![VariableFinding::NullableVariableInNonNullPosition {
+                        variable: "first",
+                        position: JsonPath::new("first"),
+                    }],
+                    report.findings,
+                );
+                assert_eq!(
+                    vec![
+                        VariableValueSource::DefaultValue,
+                        VariableValueSource::Omitted
+                    ],
+                    report
+                        .variables
+                        .iter()
+                        .map(|statistics| statistics.value_source)
+                        .collect::<Vec<_>>(),
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_list_lengths() {
+        analyze(
+            r#"
+            query($ids: [ID!], $tags: [String!], $tag: String!, $empty: [ID!]) {
+                products(ids: $ids, filter: { tags: $tags })
+                other: products(ids: $empty, filter: { tags: [$tag] })
+            }
+            "#,
+            json!({ "ids": ["1"], "tags": "coerced", "tag": "hat", "empty": [] }),
+            |report| {
+                assert_eq!(
+                    vec![
+                        VariableFinding::SingleItemList { variable: "ids" },
+                        VariableFinding::SingleItemList { variable: "tags" },
+                    ],
+                    report.findings,
+                );
+                assert_eq!(
+                    vec![Some(1), Some(1), None, Some(0)],
+                    report
+                        .variables
+                        .iter()
+                        .map(|statistics| statistics.list_length)
+                        .collect::<Vec<_>>(),
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_usages_in_fragments_counted_per_spread() {
+        analyze(
+            r#"
+            query($id: ID!) {
+                ...Product
+                alias: product(id: $id)
+                ... on Query { ...Product }
+            }
+
+            fragment Product on Query {
+                product(id: $id)
+            }
+            "#,
+            json!({ "id": "1" }),
+            |report| {
+                assert!(report.findings.is_empty());
+                assert_eq!(3, report.variables[0].usage_count);
+            },
+        );
+    }
+}
@@ -3,29 +3,42 @@ use std::marker::PhantomData;
 
 use crate::{
     executable::{
-        operation::{Analyzer, VariableValues, Visitor},
+        operation::{Analyzer, CoercedVariableValues, VariableValues, Visitor},
         Cache,
     },
-    value::input_coercion::{CoerceInput, Error as CoerceInputError},
+    value::input_coercion::{CoerceInput, CoercedValue, Error as CoerceInputError},
+    JsonPath,
 };
 use bluejay_core::definition::SchemaDefinition;
 use bluejay_core::executable::{ExecutableDocument, VariableDefinition};
+use bluejay_core::ErrorCode;
 
+/// Validates the variable values against the variable definitions of the operation. When
+/// `COERCE` is true, also produces the coerced variable values, see [`CoerceVariableValues`].
 pub struct VariableValuesAreValid<
     'a,
     E: ExecutableDocument,
     S: SchemaDefinition,
     VV: VariableValues,
+    const COERCE: bool = false,
 > {
     executable_document: PhantomData<E>,
     schema_definition: &'a S,
     indexed_variable_values: HashMap<&'a str, (&'a VV::Key, &'a VV::Value)>,
     cache: &'a Cache<'a, E, S>,
     errors: Vec<VariableValueError<'a, E, VV>>,
+    coerced_variable_values: CoercedVariableValues,
 }
 
-impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor<'a, E, S, VV>
-    for VariableValuesAreValid<'a, E, S, VV>
+/// Implements the spec's
+/// [`CoerceVariableValues`](https://spec.graphql.org/October2021/#CoerceVariableValues()):
+/// outputs the coerced variable values, with default values applied, along with the same
+/// errors as [`VariableValuesAreValid`]. Variables with errors are left out of the coerced
+/// values.
+pub type CoerceVariableValues<'a, E, S, VV> = VariableValuesAreValid<'a, E, S, VV, true>;
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues, const COERCE: bool>
+    Visitor<'a, E, S, VV> for VariableValuesAreValid<'a, E, S, VV, COERCE>
 {
     type ExtraInfo = ();
 
@@ -45,6 +58,7 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor
                 .collect(),
             cache,
             errors: Vec::new(),
+            coerced_variable_values: CoercedVariableValues::default(),
         }
     }
 
@@ -62,6 +76,22 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor
             return;
         };
         match key_and_value {
+            Some((_, value)) if COERCE => {
+                match self.schema_definition.coerce_const_value_to_owned(
+                    variable_definition_input_type,
+                    value,
+                    Default::default(),
+                ) {
+                    Ok(coerced_value) => self
+                        .coerced_variable_values
+                        .push(variable_definition.variable(), coerced_value),
+                    Err(errors) => self.errors.push(VariableValueError::InvalidValue {
+                        variable_definition,
+                        value,
+                        errors,
+                    }),
+                }
+            }
             Some((_, value)) => {
                 if let Err(errors) = self.schema_definition.coerce_const_value(
                     variable_definition_input_type,
@@ -76,6 +106,22 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor
                 }
             }
             None => {
+                if COERCE {
+                    if let Some(default_value) = variable_definition.default_value() {
+                        // default values are validated with the executable document
+                        let coerced_value = self
+                            .schema_definition
+                            .coerce_const_value_to_owned(
+                                variable_definition_input_type,
+                                default_value,
+                                Default::default(),
+                            )
+                            .unwrap_or_else(|_| CoercedValue::from_value(default_value));
+                        self.coerced_variable_values
+                            .push(variable_definition.variable(), coerced_value);
+                        return;
+                    }
+                }
                 if variable_definition.is_required() {
                     self.errors.push(VariableValueError::MissingValue {
                         variable_definition,
@@ -91,7 +137,26 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyze
 {
     type Output = Vec<VariableValueError<'a, E, VV>>;
 
+    fn into_output(self) -> Self::Output {
+        self.into_errors()
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'a, E, S, VV>
+    for CoerceVariableValues<'a, E, S, VV>
+{
+    type Output = (CoercedVariableValues, Vec<VariableValueError<'a, E, VV>>);
+
     fn into_output(mut self) -> Self::Output {
+        let coerced_variable_values = std::mem::take(&mut self.coerced_variable_values);
+        (coerced_variable_values, self.into_errors())
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues, const COERCE: bool>
+    VariableValuesAreValid<'a, E, S, VV, COERCE>
+{
+    fn into_errors(mut self) -> Vec<VariableValueError<'a, E, VV>> {
         self.errors.extend(
             self.indexed_variable_values
                 .into_values()
@@ -118,7 +183,43 @@ pub enum VariableValueError<'a, E: ExecutableDocument, VV: VariableValues> {
     },
 }
 
+impl<E: ExecutableDocument, VV: VariableValues> ErrorCode for VariableValueError<'_, E, VV> {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::MissingValue { .. } => "BAD_USER_INPUT/MissingVariableValue",
+            Self::InvalidValue { .. } => "BAD_USER_INPUT/InvalidVariableValue",
+            Self::UnusedValue { .. } => "BAD_USER_INPUT/UnusedVariableValue",
+        }
+    }
+}
+
 impl<E: ExecutableDocument, VV: VariableValues> VariableValueError<'_, E, VV> {
+    /// Path to the variable value within the variables, i.e. the variable name or the unused key.
+    pub fn path(&self) -> JsonPath {
+        match self {
+            Self::MissingValue {
+                variable_definition,
+            }
+            | Self::InvalidValue {
+                variable_definition,
+                ..
+            } => JsonPath::new(variable_definition.variable()),
+            Self::UnusedValue { key, .. } => JsonPath::new(key.as_ref()),
+        }
+    }
+
+    /// Paths to each of the offending values within the variables. For an invalid value, there is
+    /// one path per coercion error, each prefixed by the variable name.
+    pub fn paths(&self) -> Vec<JsonPath> {
+        match self {
+            Self::InvalidValue { errors, .. } => {
+                let path = self.path();
+                errors.iter().map(|error| path.join(error.path())).collect()
+            }
+            _ => vec![self.path()],
+        }
+    }
+
     pub fn message(&self) -> String {
         match self {
             Self::MissingValue {
@@ -157,13 +258,23 @@ mod tests {
     };
     use once_cell::sync::Lazy;
 
-    use super::VariableValuesAreValid;
+    use super::{CoerceVariableValues, VariableValuesAreValid};
+    use crate::JsonPath;
 
     const TEST_SCHEMA_SDL: &str = r#"
         type Query {
             noArgs: String!
             optionalArg(arg: String): String!
             requiredArg(arg: String!): String!
+            coercedArgs(input: CoercedInput!, ids: [ID!], order: Order): String!
+        }
+
+        enum Order { ASC DESC }
+
+        input CoercedInput {
+            ratio: Float!
+            orders: [Order!]! = [ASC]
+            nested: CoercedInput
         }
     "#;
 
@@ -374,4 +485,121 @@ mod tests {
             },
         );
     }
+
+    fn coerce_variable_values(
+        source: &str,
+        variable_values: &serde_json::Value,
+    ) -> (serde_json::Value, Vec<String>) {
+        let executable_document = ExecutableDocument::parse(source).result.unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        let (coerced_variable_values, errors) =
+            Orchestrator::<_, _, _, CoerceVariableValues<_, _, _>>::analyze(
+                &executable_document,
+                &*TEST_SCHEMA_DEFINITION,
+                None,
+                variable_values
+                    .as_object()
+                    .expect("Variables must be an object"),
+                &cache,
+                (),
+            )
+            .unwrap();
+        (
+            serde_json::Map::from(coerced_variable_values).into(),
+            errors.into_iter().map(|err| err.message()).collect(),
+        )
+    }
+
+    #[test]
+    fn test_coerce_variable_values() {
+        let (coerced, errors) = coerce_variable_values(
+            r#"
+                query($input: CoercedInput!, $ids: [ID!], $order: Order = DESC, $arg: String) {
+                    coercedArgs(input: $input, ids: $ids, order: $order)
+                    optionalArg(arg: $arg)
+                }
+            "#,
+            &serde_json::json!({
+                "input": { "ratio": 1, "nested": { "ratio": 0.5, "orders": "DESC" } },
+                "ids": 1,
+            }),
+        );
+        assert!(errors.is_empty(), "Expected errors to be empty: {errors:?}");
+        assert_eq!(
+            serde_json::json!({
+                "input": {
+                    "ratio": 1.0,
+                    "orders": ["ASC"],
+                    "nested": { "ratio": 0.5, "orders": ["DESC"] },
+                },
+                "ids": ["1"],
+                "order": "DESC",
+            }),
+            coerced,
+        );
+    }
+
+    #[test]
+    fn test_coerce_variable_values_with_errors() {
+        let (coerced, errors) = coerce_variable_values(
+            r#"
+                query($arg: String, $order: Order) {
+                    optionalArg(arg: $arg)
+                    coercedArgs(input: { ratio: 1 }, order: $order)
+                }
+            "#,
+            &serde_json::json!({ "arg": null, "order": "UP" }),
+        );
+        assert_eq!(
+            errors,
+            vec!["Invalid value for variable $order:\n- No member `UP` on enum Order"],
+        );
+        assert_eq!(serde_json::json!({ "arg": null }), coerced);
+    }
+
+    #[test]
+    fn test_error_paths() {
+        let executable_document = ExecutableDocument::parse(
+            r#"
+                query($input: CoercedInput!, $ids: [ID!]) {
+                    coercedArgs(input: $input, ids: $ids)
+                }
+            "#,
+        )
+        .result
+        .unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        let variable_values = serde_json::json!({
+            "input": { "ratio": 1.5, "nested": { "ratio": "high" } },
+            "ids": [1, null],
+            "extra": true,
+        });
+        let errors = Orchestrator::<_, _, _, VariableValuesAreValid<_, _, _>>::analyze(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            variable_values.as_object().unwrap(),
+            &cache,
+            (),
+        )
+        .unwrap();
+
+        let paths: Vec<Vec<JsonPath>> = errors.iter().map(|error| error.paths()).collect();
+        assert_eq!(
+            vec![
+                vec![JsonPath::new("input").with("nested").with("ratio")],
+                vec![JsonPath::new("ids").with(1)],
+                vec![JsonPath::new("extra")],
+            ],
+            paths,
+        );
+        assert_eq!(
+            vec![
+                JsonPath::new("input"),
+                JsonPath::new("ids"),
+                JsonPath::new("extra")
+            ],
+            errors.iter().map(|error| error.path()).collect::<Vec<_>>(),
+        );
+    }
 }
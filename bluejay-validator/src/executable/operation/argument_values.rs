@@ -0,0 +1,522 @@
+use crate::executable::operation::VariableValues;
+use crate::value::input_coercion::{CoerceInput, CoercedValue, Error as CoercionError};
+use crate::{JsonPath, Path};
+use bluejay_core::definition::{
+    BaseInputTypeReference, InputFieldsDefinition, InputObjectTypeDefinition, InputType,
+    InputTypeReference, InputValueDefinition, SchemaDefinition,
+};
+use bluejay_core::executable::{OperationDefinition, VariableDefinition};
+use bluejay_core::{
+    Argument, AsIter, ErrorCode, ObjectValue, Value, ValueReference, Variable, VariableArguments,
+};
+use std::borrow::Cow;
+
+/// The coerced values of the arguments of a field or directive, as computed by
+/// [`OperationDefinitionValueEvaluationExt::coerce_argument_values`](super::OperationDefinitionValueEvaluationExt::coerce_argument_values),
+/// in the order of the argument definitions. Arguments without a value or default value are
+/// absent.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CoercedArgumentValues<'a>(Vec<(&'a str, CoercedValue)>);
+
+impl<'a> CoercedArgumentValues<'a> {
+    pub fn get(&self, name: &str) -> Option<&CoercedValue> {
+        self.0
+            .iter()
+            .find(|(argument_name, _)| *argument_name == name)
+            .map(|(_, value)| value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, &CoercedValue)> {
+        self.0.iter().map(|(name, value)| (*name, value))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn into_inner(self) -> Vec<(&'a str, CoercedValue)> {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArgumentValueError<'a> {
+    MissingValue {
+        argument_name: &'a str,
+    },
+    InvalidValue {
+        argument_name: &'a str,
+        message: Cow<'static, str>,
+        /// Path to the invalid value, starting with the argument name.
+        path: JsonPath,
+    },
+}
+
+impl ErrorCode for ArgumentValueError<'_> {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::MissingValue { .. } => "BAD_USER_INPUT/MissingArgumentValue",
+            Self::InvalidValue { .. } => "BAD_USER_INPUT/InvalidArgumentValue",
+        }
+    }
+}
+
+impl ArgumentValueError<'_> {
+    pub fn message(&self) -> String {
+        match self {
+            Self::MissingValue { argument_name } => {
+                format!("Missing value for required argument `{argument_name}`")
+            }
+            Self::InvalidValue {
+                argument_name,
+                message,
+                ..
+            } => format!("Invalid value for argument `{argument_name}`: {message}"),
+        }
+    }
+}
+
+/// Implements [`CoerceArgumentValues`](https://spec.graphql.org/October2021/#CoerceArgumentValues()).
+/// The values of variables are coerced against the input type at the position they are used
+/// in, so that raw variable values can be given. Variables without a value fall back to the
+/// default value of their definition in `operation_definition`.
+pub(super) fn coerce_argument_values<
+    'a,
+    O: OperationDefinition,
+    S: SchemaDefinition,
+    A: VariableArguments,
+    VV: VariableValues,
+>(
+    operation_definition: &'a O,
+    schema_definition: &'a S,
+    arguments_definition: Option<&'a S::ArgumentsDefinition>,
+    arguments: Option<&'a A>,
+    variable_values: &'a VV,
+) -> Result<CoercedArgumentValues<'a>, Vec<ArgumentValueError<'a>>> {
+    let mut coerced_values = Vec::new();
+    let mut errors = Vec::new();
+
+    arguments_definition
+        .into_iter()
+        .flat_map(|arguments_definition| arguments_definition.iter())
+        .for_each(|ivd| {
+            let argument_name = ivd.name();
+            let argument_coercion = ArgumentCoercion {
+                operation_definition,
+                schema_definition,
+                variable_values,
+                argument_name,
+            };
+            let value = arguments
+                .and_then(|arguments| {
+                    arguments
+                        .iter()
+                        .find(|argument| argument.name() == argument_name)
+                })
+                .map(|argument| {
+                    argument_coercion.substitute_variables(
+                        ivd.r#type(),
+                        argument.value(),
+                        Path::new(argument_name),
+                    )
+                })
+                .transpose()
+                .map(Option::flatten);
+
+            let coerced_value = match (value, ivd.default_value()) {
+                (Err(argument_errors), _) => Err(argument_errors),
+                (Ok(Some(value)), _) => schema_definition
+                    .coerce_const_value_to_owned(ivd.r#type(), &value, Path::new(argument_name))
+                    .map_err(|coercion_errors| {
+                        argument_coercion.invalid_value_errors(coercion_errors)
+                    }),
+                (Ok(None), Some(default_value)) => Ok(coerce_default_value(
+                    schema_definition,
+                    ivd.r#type(),
+                    default_value,
+                )),
+                (Ok(None), None) if ivd.r#type().is_required() => {
+                    Err(vec![ArgumentValueError::MissingValue { argument_name }])
+                }
+                (Ok(None), None) => return,
+            };
+
+            match coerced_value {
+                Ok(coerced_value) => coerced_values.push((argument_name, coerced_value)),
+                Err(argument_errors) => errors.extend(argument_errors),
+            }
+        });
+
+    if errors.is_empty() {
+        Ok(CoercedArgumentValues(coerced_values))
+    } else {
+        Err(errors)
+    }
+}
+
+/// Default values are validated with the schema, so coercion is not expected to fail. If it
+/// does, the value is used as-is.
+fn coerce_default_value<
+    S: SchemaDefinition,
+    I: InputType<
+        CustomScalarTypeDefinition = S::CustomScalarTypeDefinition,
+        InputObjectTypeDefinition = S::InputObjectTypeDefinition,
+        EnumTypeDefinition = S::EnumTypeDefinition,
+    >,
+    V: Value<true>,
+>(
+    schema_definition: &S,
+    input_type: &I,
+    default_value: &V,
+) -> CoercedValue {
+    schema_definition
+        .coerce_const_value_to_owned(input_type, default_value, Path::default())
+        .unwrap_or_else(|_| CoercedValue::from_value(default_value))
+}
+
+struct ArgumentCoercion<'a, O: OperationDefinition, S: SchemaDefinition, VV: VariableValues> {
+    operation_definition: &'a O,
+    schema_definition: &'a S,
+    variable_values: &'a VV,
+    argument_name: &'a str,
+}
+
+impl<'a, O: OperationDefinition, S: SchemaDefinition, VV: VariableValues>
+    ArgumentCoercion<'a, O, S, VV>
+{
+    /// Replaces the variables in `value` with their coerced values, returning `None` when
+    /// `value` is a variable without a value. Per the spec, an input object field whose value is
+    /// such a variable is treated as absent, and a list item as null.
+    fn substitute_variables<
+        I: InputType<
+            CustomScalarTypeDefinition = S::CustomScalarTypeDefinition,
+            InputObjectTypeDefinition = S::InputObjectTypeDefinition,
+            EnumTypeDefinition = S::EnumTypeDefinition,
+        >,
+        V: Value<false>,
+    >(
+        &self,
+        input_type: &'a I,
+        value: &'a V,
+        path: Path<'a>,
+    ) -> Result<Option<CoercedValue>, Vec<ArgumentValueError<'a>>> {
+        match (value.as_ref(), input_type.as_ref(self.schema_definition)) {
+            (ValueReference::Variable(variable), _) => {
+                self.coerce_variable(input_type, variable.name(), path)
+            }
+            (ValueReference::List(list), InputTypeReference::List(inner_type, _)) => {
+                let mut errors = Vec::new();
+                let items = list
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| {
+                        self.substitute_variables(inner_type, item, path.push(index))
+                            .unwrap_or_else(|item_errors| {
+                                errors.extend(item_errors);
+                                None
+                            })
+                            .unwrap_or(CoercedValue::Null)
+                    })
+                    .collect();
+                if errors.is_empty() {
+                    Ok(Some(CoercedValue::List(items)))
+                } else {
+                    Err(errors)
+                }
+            }
+            (_, InputTypeReference::List(inner_type, _)) => {
+                self.substitute_variables(inner_type, value, path)
+            }
+            (
+                ValueReference::Object(object),
+                InputTypeReference::Base(BaseInputTypeReference::InputObject(iotd), _),
+            ) => {
+                let mut errors = Vec::new();
+                let fields = object
+                    .iter()
+                    .filter_map(|(key, value)| {
+                        let value = match iotd.input_field_definitions().get(key.as_ref()) {
+                            Some(ifd) => self
+                                .substitute_variables(ifd.r#type(), value, path.push(key.as_ref()))
+                                .unwrap_or_else(|field_errors| {
+                                    errors.extend(field_errors);
+                                    None
+                                }),
+                            None => Some(CoercedValue::from_value(value)),
+                        };
+                        value.map(|value| (key.as_ref().to_owned(), value))
+                    })
+                    .collect();
+                if errors.is_empty() {
+                    Ok(Some(CoercedValue::Object(fields)))
+                } else {
+                    Err(errors)
+                }
+            }
+            _ => Ok(Some(CoercedValue::from_value(value))),
+        }
+    }
+
+    fn coerce_variable<
+        I: InputType<
+            CustomScalarTypeDefinition = S::CustomScalarTypeDefinition,
+            InputObjectTypeDefinition = S::InputObjectTypeDefinition,
+            EnumTypeDefinition = S::EnumTypeDefinition,
+        >,
+    >(
+        &self,
+        input_type: &'a I,
+        name: &str,
+        path: Path<'a>,
+    ) -> Result<Option<CoercedValue>, Vec<ArgumentValueError<'a>>> {
+        if let Some(value) = self.variable_values.get(name) {
+            return self
+                .schema_definition
+                .coerce_const_value_to_owned(input_type, value, path)
+                .map(Some)
+                .map_err(|errors| self.invalid_value_errors(errors));
+        }
+
+        let default_value = self
+            .operation_definition
+            .as_ref()
+            .variable_definitions()
+            .and_then(|variable_definitions| {
+                variable_definitions
+                    .iter()
+                    .find(|variable_definition| variable_definition.variable() == name)
+            })
+            .and_then(VariableDefinition::default_value);
+
+        default_value
+            .map(|default_value| {
+                self.schema_definition
+                    .coerce_const_value_to_owned(input_type, default_value, path)
+                    .map_err(|errors| self.invalid_value_errors(errors))
+            })
+            .transpose()
+    }
+
+    fn invalid_value_errors<V: Value<true>>(
+        &self,
+        errors: Vec<CoercionError<'_, true, V>>,
+    ) -> Vec<ArgumentValueError<'a>> {
+        errors
+            .into_iter()
+            .map(|error| ArgumentValueError::InvalidValue {
+                argument_name: self.argument_name,
+                message: error.message(),
+                path: error.path().clone(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArgumentValueError, CoercedArgumentValues};
+    use crate::executable::operation::OperationDefinitionValueEvaluationExt;
+    use crate::value::input_coercion::CoercedValue;
+    use crate::JsonPath;
+    use bluejay_core::definition::{
+        DirectiveDefinition, FieldDefinition, FieldsDefinition, ObjectTypeDefinition,
+        SchemaDefinition,
+    };
+    use bluejay_core::executable::{Field, OperationDefinition, Selection, SelectionReference};
+    use bluejay_core::{AsIter, Directive};
+    use bluejay_parser::ast::{
+        definition::{
+            DefaultContext, DefinitionDocument, SchemaDefinition as ParserSchemaDefinition,
+        },
+        executable::ExecutableDocument as ParserExecutableDocument,
+        Parse,
+    };
+    use serde_json::Value as JsonValue;
+
+    const TEST_SCHEMA: &str = r#"
+        directive @cached(ttl: Int = 60, scopes: [String!]) on FIELD
+        enum Order {
+          ASC
+          DESC
+        }
+        input Filter {
+          ids: [ID!]
+          order: Order = ASC
+          limit: Int
+        }
+        type Query {
+          things(filter: Filter!, first: Int = 10, after: String): [String!]!
+        }
+        schema {
+          query: Query
+        }
+    "#;
+
+    /// Coerces the arguments of the first root field of `source`, and of its first directive
+    /// when `directive` is set.
+    fn check_argument_values(
+        source: &str,
+        variables: JsonValue,
+        directive: bool,
+        f: impl FnOnce(Result<CoercedArgumentValues, Vec<ArgumentValueError>>),
+    ) {
+        let definition_document: DefinitionDocument<'_, DefaultContext> =
+            DefinitionDocument::parse(TEST_SCHEMA)
+                .result
+                .expect("Schema had parse errors");
+        let schema_definition =
+            ParserSchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+        let executable_document = ParserExecutableDocument::parse(source)
+            .result
+            .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let variables = variables.as_object().expect("Variables must be an object");
+        let operation_definition = executable_document
+            .operation_definitions()
+            .first()
+            .expect("Document had no operation");
+        let field = operation_definition
+            .as_ref()
+            .selection_set()
+            .iter()
+            .find_map(|selection| match selection.as_ref() {
+                SelectionReference::Field(field) => Some(field),
+                _ => None,
+            })
+            .expect("Operation had no field");
+
+        let result = if directive {
+            let directive = field
+                .directives()
+                .and_then(|directives| directives.iter().next())
+                .expect("Field had no directive");
+            operation_definition.coerce_argument_values(
+                &schema_definition,
+                schema_definition
+                    .get_directive_definition(Directive::name(directive))
+                    .and_then(DirectiveDefinition::arguments_definition),
+                directive.arguments(),
+                variables,
+            )
+        } else {
+            operation_definition.coerce_argument_values(
+                &schema_definition,
+                schema_definition
+                    .query()
+                    .fields_definition()
+                    .get(Field::name(field))
+                    .and_then(FieldDefinition::arguments_definition),
+                field.arguments(),
+                variables,
+            )
+        };
+
+        f(result);
+    }
+
+    #[test]
+    fn literals_variables_and_defaults() {
+        check_argument_values(
+            r#"query($id: ID!, $order: Order, $limit: Int = 5, $after: String) {
+              things(filter: { ids: [1, $id], limit: $limit, order: $order }, after: $after)
+            }"#,
+            serde_json::json!({ "id": 2, "order": "DESC" }),
+            false,
+            |result| {
+                assert_eq!(
+                    vec![
+                        (
+                            "filter",
+                            CoercedValue::Object(vec![
+                                (
+                                    "ids".to_owned(),
+                                    CoercedValue::List(vec![
+                                        CoercedValue::String("1".to_owned()),
+                                        CoercedValue::String("2".to_owned()),
+                                    ]),
+                                ),
+                                ("order".to_owned(), CoercedValue::Enum("DESC".to_owned())),
+                                ("limit".to_owned(), CoercedValue::Int(5)),
+                            ]),
+                        ),
+                        ("first", CoercedValue::Int(10)),
+                    ],
+                    result.unwrap().into_inner(),
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn input_field_defaults_and_implicit_lists() {
+        check_argument_values(
+            r#"query($ids: [ID!]) { things(filter: { ids: 1 }, first: null) }"#,
+            serde_json::json!({}),
+            false,
+            |result| {
+                assert_eq!(
+                    vec![
+                        (
+                            "filter",
+                            CoercedValue::Object(vec![
+                                (
+                                    "ids".to_owned(),
+                                    CoercedValue::List(vec![CoercedValue::String("1".to_owned())]),
+                                ),
+                                ("order".to_owned(), CoercedValue::Enum("ASC".to_owned())),
+                            ]),
+                        ),
+                        ("first", CoercedValue::Null),
+                    ],
+                    result.unwrap().into_inner(),
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn missing_and_invalid_values() {
+        check_argument_values(
+            r#"query($filter: Filter, $first: Int) { things(filter: $filter, first: $first) }"#,
+            serde_json::json!({ "first": "ten" }),
+            false,
+            |result| {
+                assert_eq!(
+                    vec![
+                        ArgumentValueError::MissingValue {
+                            argument_name: "filter"
+                        },
+                        ArgumentValueError::InvalidValue {
+                            argument_name: "first",
+                            message: "No implicit conversion of string to Int".into(),
+                            path: JsonPath::new("first"),
+                        },
+                    ],
+                    result.unwrap_err(),
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn directive_arguments() {
+        check_argument_values(
+            r#"query($scope: String!) { things(filter: {}) @cached(scopes: $scope) }"#,
+            serde_json::json!({ "scope": "public" }),
+            true,
+            |result| {
+                let argument_values = result.unwrap();
+                assert_eq!(Some(&CoercedValue::Int(60)), argument_values.get("ttl"));
+                assert_eq!(
+                    Some(&CoercedValue::List(vec![CoercedValue::String(
+                        "public".to_owned()
+                    )])),
+                    argument_values.get("scopes"),
+                );
+            },
+        );
+    }
+}
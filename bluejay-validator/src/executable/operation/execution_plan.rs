@@ -0,0 +1,743 @@
+//! Builds the plan of an operation's execution, as per
+//! [`CollectFields`](https://spec.graphql.org/October2021/#CollectFields()) and
+//! [`MergeSelectionSets`](https://spec.graphql.org/October2021/#MergeSelectionSets()):
+//! for every selection set, and every object type it can be executed on, the fields selected
+//! under each response key, with `@skip` and `@include` evaluated and fragments expanded.
+//!
+//! Only the root selection set is planned up front. The plan of a selection set for an object
+//! type is built the first time it is requested, so that the plan of an operation nesting
+//! abstract types does not grow with every combination of their possible types.
+//!
+//! The executable document is expected to be valid.
+
+use crate::executable::{
+    operation::{
+        orchestrator::resolve_operation, OperationDefinitionValueEvaluationExt,
+        OperationResolutionError, VariableValues,
+    },
+    Cache,
+};
+use bluejay_core::definition::{
+    FieldDefinition, FieldsDefinition, InterfaceImplementation, ObjectTypeDefinition, OutputType,
+    SchemaDefinition, TypeDefinitionReference, UnionMemberType, UnionTypeDefinition,
+};
+use bluejay_core::executable::{
+    ExecutableDocument, Field, FragmentDefinition, FragmentSpread, InlineFragment,
+    OperationDefinition, Selection, SelectionReference,
+};
+use bluejay_core::{AsIter, ErrorCode, OperationType};
+use std::borrow::Cow;
+use std::cell::OnceCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// The plan of execution of an operation, starting from its root operation type.
+///
+/// As the plans of nested selection sets are built lazily, the plan is not [`Sync`] and must be
+/// used on the thread that built it.
+pub struct ExecutionPlan<'a, E: ExecutableDocument, S: SchemaDefinition> {
+    operation_definition: &'a E::OperationDefinition,
+    root_type: &'a S::ObjectTypeDefinition,
+    fields: GroupedFieldSet<'a, E, S>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition> ExecutionPlan<'a, E, S> {
+    pub fn new<'b, VV: VariableValues>(
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        operation_name: Option<&'b str>,
+        variable_values: &'a VV,
+        cache: &'a Cache<'a, E, S>,
+    ) -> Result<Self, ExecutionPlanError<'b>> {
+        let operation_definition = resolve_operation(executable_document, operation_name)
+            .map_err(ExecutionPlanError::OperationResolution)?;
+        let operation_type = operation_definition.as_ref().operation_type();
+        let root_type = match operation_type {
+            OperationType::Query => Some(schema_definition.query()),
+            OperationType::Mutation => schema_definition.mutation(),
+            OperationType::Subscription => schema_definition.subscription(),
+        }
+        .ok_or(ExecutionPlanError::NoRootOperationType { operation_type })?;
+
+        let builder = Rc::new(PlanBuilder {
+            schema_definition,
+            operation_definition,
+            variable_values,
+            cache,
+        });
+        let fields = builder.collect_fields(
+            root_type,
+            &[(operation_definition.as_ref().selection_set(), Vec::new())],
+        );
+
+        Ok(Self {
+            operation_definition,
+            root_type,
+            fields,
+        })
+    }
+
+    pub fn operation_definition(&self) -> &'a E::OperationDefinition {
+        self.operation_definition
+    }
+
+    pub fn root_type(&self) -> &'a S::ObjectTypeDefinition {
+        self.root_type
+    }
+
+    /// The grouped field set of the root selection set.
+    pub fn fields(&self) -> &GroupedFieldSet<'a, E, S> {
+        &self.fields
+    }
+}
+
+/// The fields of one or more selection sets executed on an object type, grouped by response
+/// key in the order the response keys are first selected.
+pub struct GroupedFieldSet<'a, E: ExecutableDocument, S: SchemaDefinition> {
+    field_groups: Vec<FieldGroup<'a, E, S>>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition> GroupedFieldSet<'a, E, S> {
+    pub fn iter(&self) -> std::slice::Iter<'_, FieldGroup<'a, E, S>> {
+        self.field_groups.iter()
+    }
+
+    pub fn get(&self, response_key: &str) -> Option<&FieldGroup<'a, E, S>> {
+        self.field_groups
+            .iter()
+            .find(|field_group| field_group.response_key == response_key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.field_groups.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.field_groups.is_empty()
+    }
+}
+
+/// The fields selected under a single response key, which are merged into one entry of the
+/// response.
+pub struct FieldGroup<'a, E: ExecutableDocument, S: SchemaDefinition> {
+    response_key: &'a str,
+    fields: Vec<&'a E::Field>,
+    field_definition: &'a S::FieldDefinition,
+    selection: Option<SelectionPlan<'a, E, S>>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition> FieldGroup<'a, E, S> {
+    pub fn response_key(&self) -> &'a str {
+        self.response_key
+    }
+
+    /// The fields of the group, in the order they are selected. There is at least one.
+    pub fn fields(&self) -> &[&'a E::Field] {
+        &self.fields
+    }
+
+    /// The first field of the group, which the arguments are taken from when executing it.
+    pub fn field(&self) -> &'a E::Field {
+        self.fields[0]
+    }
+
+    pub fn field_definition(&self) -> &'a S::FieldDefinition {
+        self.field_definition
+    }
+
+    /// The plan of the merged selection sets of the fields, or `None` for fields of a leaf type.
+    pub fn selection(&self) -> Option<&SelectionPlan<'a, E, S>> {
+        self.selection.as_ref()
+    }
+}
+
+/// The grouped field sets of a selection set, for each object type that the type of its field
+/// can resolve to. The grouped field set of an object type is built when first requested.
+pub struct SelectionPlan<'a, E: ExecutableDocument, S: SchemaDefinition> {
+    selection_sets: Vec<(&'a E::SelectionSet, Vec<&'a str>)>,
+    object_types: Vec<(&'a S::ObjectTypeDefinition, LazyGroupedFieldSet<'a, E, S>)>,
+    builder: Rc<dyn CollectFields<'a, E, S> + 'a>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition> SelectionPlan<'a, E, S> {
+    /// The grouped field set of every possible object type, building those not yet built.
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&'a S::ObjectTypeDefinition, &GroupedFieldSet<'a, E, S>)> {
+        self.object_types
+            .iter()
+            .map(|(object_type, fields)| (*object_type, self.fields(object_type, fields)))
+    }
+
+    /// The possible object types, without building their grouped field sets.
+    pub fn object_types(&self) -> impl Iterator<Item = &'a S::ObjectTypeDefinition> + '_ {
+        self.object_types
+            .iter()
+            .map(|(object_type, _)| *object_type)
+    }
+
+    /// The grouped field set to execute when the value resolves to the object type with the
+    /// given name.
+    pub fn for_type(&self, object_type_name: &str) -> Option<&GroupedFieldSet<'a, E, S>> {
+        self.object_types
+            .iter()
+            .find(|(object_type, _)| object_type.name() == object_type_name)
+            .map(|(object_type, fields)| self.fields(object_type, fields))
+    }
+
+    fn fields<'b>(
+        &'b self,
+        object_type: &'a S::ObjectTypeDefinition,
+        fields: &'b LazyGroupedFieldSet<'a, E, S>,
+    ) -> &'b GroupedFieldSet<'a, E, S> {
+        fields.get_or_init(|| {
+            Rc::clone(&self.builder).collect_fields_dyn(object_type, &self.selection_sets)
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum ExecutionPlanError<'a> {
+    OperationResolution(OperationResolutionError<'a>),
+    NoRootOperationType { operation_type: OperationType },
+}
+
+impl ErrorCode for ExecutionPlanError<'_> {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::OperationResolution(error) => error.code(),
+            Self::NoRootOperationType { .. } => "EXECUTION_PLAN_FAILURE/NoRootOperationType",
+        }
+    }
+}
+
+impl ExecutionPlanError<'_> {
+    pub fn message(&self) -> Cow<'static, str> {
+        match self {
+            Self::OperationResolution(error) => error.message(),
+            Self::NoRootOperationType { operation_type } => {
+                format!("Schema does not define a {operation_type} root operation type").into()
+            }
+        }
+    }
+}
+
+/// A field collected for a response key, along with the fragments spread to reach it, which
+/// are not spread again within its selection set so that fragment cycles terminate.
+struct CollectedField<'a, E: ExecutableDocument> {
+    field: &'a E::Field,
+    spread_fragments: Vec<&'a str>,
+}
+
+/// A selection set to collect fields from, along with the fragments spread to reach it.
+type SpreadSelectionSet<'a, E> = (&'a <E as ExecutableDocument>::SelectionSet, Vec<&'a str>);
+
+/// A grouped field set that is built the first time it is requested.
+type LazyGroupedFieldSet<'a, E, S> = OnceCell<GroupedFieldSet<'a, E, S>>;
+
+/// Builds the grouped field sets of a [`SelectionPlan`] on demand, without the plan having to
+/// know the type of the variable values.
+trait CollectFields<'a, E: ExecutableDocument, S: SchemaDefinition> {
+    fn collect_fields_dyn(
+        self: Rc<Self>,
+        object_type: &'a S::ObjectTypeDefinition,
+        selection_sets: &[SpreadSelectionSet<'a, E>],
+    ) -> GroupedFieldSet<'a, E, S>;
+}
+
+struct PlanBuilder<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> {
+    schema_definition: &'a S,
+    operation_definition: &'a E::OperationDefinition,
+    variable_values: &'a VV,
+    cache: &'a Cache<'a, E, S>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> CollectFields<'a, E, S>
+    for PlanBuilder<'a, E, S, VV>
+{
+    fn collect_fields_dyn(
+        self: Rc<Self>,
+        object_type: &'a S::ObjectTypeDefinition,
+        selection_sets: &[SpreadSelectionSet<'a, E>],
+    ) -> GroupedFieldSet<'a, E, S> {
+        self.collect_fields(object_type, selection_sets)
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> PlanBuilder<'a, E, S, VV> {
+    fn collect_fields(
+        self: &Rc<Self>,
+        object_type: &'a S::ObjectTypeDefinition,
+        selection_sets: &[SpreadSelectionSet<'a, E>],
+    ) -> GroupedFieldSet<'a, E, S> {
+        let mut collected_fields: Vec<(&'a str, Vec<CollectedField<'a, E>>)> = Vec::new();
+        let mut indices: HashMap<&'a str, usize> = HashMap::new();
+
+        selection_sets
+            .iter()
+            .for_each(|(selection_set, spread_fragments)| {
+                self.collect_selection_set(
+                    object_type,
+                    selection_set,
+                    &mut spread_fragments.to_vec(),
+                    &mut collected_fields,
+                    &mut indices,
+                )
+            });
+
+        let field_groups = collected_fields
+            .into_iter()
+            .filter_map(|(response_key, fields)| {
+                let field_definition = object_type
+                    .fields_definition()
+                    .get(fields[0].field.name())?;
+                let selection = self.selection_plan(field_definition, &fields);
+                Some(FieldGroup {
+                    response_key,
+                    fields: fields
+                        .into_iter()
+                        .map(|collected_field| collected_field.field)
+                        .collect(),
+                    field_definition,
+                    selection,
+                })
+            })
+            .collect();
+
+        GroupedFieldSet { field_groups }
+    }
+
+    fn collect_selection_set(
+        &self,
+        object_type: &'a S::ObjectTypeDefinition,
+        selection_set: &'a E::SelectionSet,
+        spread_fragments: &mut Vec<&'a str>,
+        collected_fields: &mut Vec<(&'a str, Vec<CollectedField<'a, E>>)>,
+        indices: &mut HashMap<&'a str, usize>,
+    ) {
+        selection_set.iter().for_each(|selection| {
+            if !selection
+                .as_ref()
+                .directives()
+                .is_none_or(|directives| self.is_included(directives))
+            {
+                return;
+            }
+
+            match selection.as_ref() {
+                SelectionReference::Field(field) => {
+                    let response_key = field.response_name();
+                    let index = *indices.entry(response_key).or_insert_with(|| {
+                        collected_fields.push((response_key, Vec::new()));
+                        collected_fields.len() - 1
+                    });
+                    let fields = &mut collected_fields[index].1;
+                    // the same field is reached again when a fragment is spread more than once
+                    if !fields
+                        .iter()
+                        .any(|collected_field| std::ptr::eq(collected_field.field, field))
+                    {
+                        fields.push(CollectedField {
+                            field,
+                            spread_fragments: spread_fragments.clone(),
+                        });
+                    }
+                }
+                SelectionReference::FragmentSpread(fragment_spread) => {
+                    let name = fragment_spread.name();
+                    if spread_fragments.contains(&name) {
+                        return;
+                    }
+                    let Some(fragment_definition) = self.cache.fragment_definition(name) else {
+                        return;
+                    };
+                    if !self
+                        .does_fragment_type_apply(object_type, fragment_definition.type_condition())
+                    {
+                        return;
+                    }
+                    spread_fragments.push(name);
+                    self.collect_selection_set(
+                        object_type,
+                        fragment_definition.selection_set(),
+                        spread_fragments,
+                        collected_fields,
+                        indices,
+                    );
+                    spread_fragments.pop();
+                }
+                SelectionReference::InlineFragment(inline_fragment) => {
+                    if inline_fragment
+                        .type_condition()
+                        .is_none_or(|type_condition| {
+                            self.does_fragment_type_apply(object_type, type_condition)
+                        })
+                    {
+                        self.collect_selection_set(
+                            object_type,
+                            inline_fragment.selection_set(),
+                            spread_fragments,
+                            collected_fields,
+                            indices,
+                        );
+                    }
+                }
+            }
+        });
+    }
+
+    fn selection_plan(
+        self: &Rc<Self>,
+        field_definition: &'a S::FieldDefinition,
+        fields: &[CollectedField<'a, E>],
+    ) -> Option<SelectionPlan<'a, E, S>> {
+        let selection_sets: Vec<SpreadSelectionSet<'a, E>> = fields
+            .iter()
+            .filter_map(|collected_field| {
+                collected_field
+                    .field
+                    .selection_set()
+                    .map(|selection_set| (selection_set, collected_field.spread_fragments.clone()))
+            })
+            .collect();
+        if selection_sets.is_empty() {
+            return None;
+        }
+
+        let type_definition = self
+            .schema_definition
+            .get_type_definition(field_definition.r#type().base_name())?;
+
+        let object_types = self
+            .possible_object_types(type_definition)
+            .into_iter()
+            .map(|object_type| (object_type, OnceCell::new()))
+            .collect();
+
+        Some(SelectionPlan {
+            selection_sets,
+            object_types,
+            builder: Rc::clone(self) as Rc<dyn CollectFields<'a, E, S> + 'a>,
+        })
+    }
+
+    fn possible_object_types(
+        &self,
+        type_definition: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) -> Vec<&'a S::ObjectTypeDefinition> {
+        match type_definition {
+            TypeDefinitionReference::Object(otd) => vec![otd],
+            TypeDefinitionReference::Interface(itd) => self
+                .schema_definition
+                .get_interface_implementors(itd)
+                .collect(),
+            TypeDefinitionReference::Union(utd) => utd
+                .union_member_types()
+                .iter()
+                .filter_map(|union_member_type| {
+                    match self
+                        .schema_definition
+                        .get_type_definition(union_member_type.name())
+                    {
+                        Some(TypeDefinitionReference::Object(otd)) => Some(otd),
+                        _ => None,
+                    }
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Implements [`DoesFragmentTypeApply`](https://spec.graphql.org/October2021/#DoesFragmentTypeApply()).
+    fn does_fragment_type_apply(
+        &self,
+        object_type: &'a S::ObjectTypeDefinition,
+        type_condition: &str,
+    ) -> bool {
+        if object_type.name() == type_condition {
+            return true;
+        }
+
+        match self.schema_definition.get_type_definition(type_condition) {
+            Some(TypeDefinitionReference::Interface(_)) => object_type
+                .interface_implementations()
+                .is_some_and(|interface_implementations| {
+                    interface_implementations
+                        .iter()
+                        .any(|interface_implementation| {
+                            interface_implementation.name() == type_condition
+                        })
+                }),
+            Some(TypeDefinitionReference::Union(utd)) => utd
+                .union_member_types()
+                .iter()
+                .any(|union_member_type| union_member_type.name() == object_type.name()),
+            _ => false,
+        }
+    }
+
+    fn is_included(&self, directives: &'a E::Directives<false>) -> bool {
+        self.operation_definition
+            .evaluate_selection_inclusion(directives, self.variable_values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExecutionPlan, ExecutionPlanError, GroupedFieldSet};
+    use crate::executable::Cache;
+    use bluejay_core::definition::{ObjectTypeDefinition, SchemaDefinition};
+    use bluejay_core::executable::{ExecutableDocument, Field};
+    use bluejay_core::ErrorCode;
+    use bluejay_parser::ast::{
+        definition::{
+            DefaultContext, DefinitionDocument, SchemaDefinition as ParserSchemaDefinition,
+        },
+        executable::ExecutableDocument as ParserExecutableDocument,
+        Parse,
+    };
+    use serde_json::Value as JsonValue;
+
+    const TEST_SCHEMA: &str = r#"
+        type Query {
+          node(id: ID!): Node
+          thing: Thing!
+          ping: String!
+        }
+        interface Node {
+          id: ID!
+          related: Node
+        }
+        type Product implements Node {
+          id: ID!
+          related: Node
+          title: String!
+          things: [Thing!]!
+        }
+        type Thing implements Node {
+          id: ID!
+          related: Node
+          title: String!
+          parent: Thing
+        }
+        union SearchResult = Product | Thing
+        schema {
+          query: Query
+        }
+    "#;
+
+    /// Describes a grouped field set as its response keys, each followed by the names of its
+    /// fields and the description of its selection plan for every possible type.
+    fn describe<E: ExecutableDocument, S: SchemaDefinition>(
+        fields: &GroupedFieldSet<'_, E, S>,
+    ) -> String {
+        fields
+            .iter()
+            .map(|field_group| {
+                let mut description = field_group.response_key().to_owned();
+                if field_group.fields().len() > 1 {
+                    description.push_str(&format!("x{}", field_group.fields().len()));
+                }
+                if field_group.response_key() != field_group.field().name() {
+                    description.push_str(&format!(":{}", field_group.field().name()));
+                }
+                if let Some(selection) = field_group.selection() {
+                    let object_types = selection
+                        .iter()
+                        .map(|(object_type, fields)| {
+                            format!("{}{{{}}}", object_type.name(), describe(fields))
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    description.push_str(&format!(" {object_types}"));
+                }
+                description
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn with_plan(
+        source: &str,
+        operation_name: Option<&str>,
+        variables: JsonValue,
+        f: impl FnOnce(
+            Result<
+                ExecutionPlan<'_, ParserExecutableDocument, ParserSchemaDefinition>,
+                ExecutionPlanError,
+            >,
+        ),
+    ) {
+        let definition_document: DefinitionDocument<'_, DefaultContext> =
+            DefinitionDocument::parse(TEST_SCHEMA)
+                .result
+                .expect("Schema had parse errors");
+        let schema_definition =
+            ParserSchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+        let executable_document = ParserExecutableDocument::parse(source)
+            .result
+            .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let cache = Cache::new(&executable_document, &schema_definition);
+        let variables = variables.as_object().expect("Variables must be an object");
+        let plan = ExecutionPlan::new(
+            &executable_document,
+            &schema_definition,
+            operation_name,
+            variables,
+            &cache,
+        );
+
+        f(plan);
+    }
+
+    fn check_plan(
+        source: &str,
+        operation_name: Option<&str>,
+        variables: JsonValue,
+        f: impl FnOnce(Result<String, ExecutionPlanError>),
+    ) {
+        with_plan(source, operation_name, variables, |plan| {
+            f(plan.map(|plan| {
+                assert_eq!("Query", plan.root_type().name());
+                describe(&plan.fields)
+            }))
+        });
+    }
+
+    #[test]
+    fn merges_fields_in_response_order() {
+        check_plan(
+            r#"query($skip: Boolean!) {
+              ping
+              thing { id }
+              ...QueryFields
+              ...QueryFields
+              skipped: ping @skip(if: $skip)
+              thing @include(if: true) { title parent { id } }
+            }
+            fragment QueryFields on Query {
+              thing { id }
+              aliased: ping
+            }"#,
+            None,
+            serde_json::json!({ "skip": true }),
+            |description| {
+                assert_eq!(
+                    "ping, thingx3 Thing{idx2, title, parent Thing{id}}, aliased:ping",
+                    description.unwrap(),
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn plans_every_possible_type() {
+        check_plan(
+            r#"{
+              node(id: "1") {
+                __typename
+                ... on Product { title things { id } }
+                ... on Thing { parent { id } }
+                ...ThingTitle
+              }
+            }
+            fragment ThingTitle on Thing { title }"#,
+            None,
+            serde_json::json!({}),
+            |description| {
+                assert_eq!(
+                    "node Product{__typename, title, things Thing{id}} Thing{__typename, parent Thing{id}, title}",
+                    description.unwrap(),
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn fragment_cycles_terminate() {
+        check_plan(
+            r#"{ thing { ...Parent } }
+            fragment Parent on Thing { id parent { ...Parent } }"#,
+            None,
+            serde_json::json!({}),
+            |description| {
+                assert_eq!("thing Thing{id, parent Thing{}}", description.unwrap());
+            },
+        );
+    }
+
+    #[test]
+    fn nested_abstract_types_are_planned_lazily() {
+        const DEPTH: usize = 32;
+        let source = format!(
+            "{{ node(id: \"1\") {{ {}id{} }} }}",
+            "related { ".repeat(DEPTH),
+            " }".repeat(DEPTH),
+        );
+
+        with_plan(&source, None, serde_json::json!({}), |plan| {
+            let plan = plan.unwrap();
+            let mut field_group = plan.fields().get("node").unwrap();
+            for _ in 0..DEPTH {
+                let selection = field_group.selection().unwrap();
+                assert_eq!(
+                    vec!["Product", "Thing"],
+                    selection
+                        .object_types()
+                        .map(ObjectTypeDefinition::name)
+                        .collect::<Vec<_>>(),
+                );
+                field_group = selection
+                    .for_type("Product")
+                    .and_then(|fields| fields.get("related"))
+                    .unwrap();
+                // only the plan of the type that was requested has been built
+                assert!(selection.object_types[1].1.get().is_none());
+            }
+            assert_eq!(
+                "Product{id} Thing{id}",
+                field_group
+                    .selection()
+                    .unwrap()
+                    .iter()
+                    .map(|(object_type, fields)| {
+                        format!("{}{{{}}}", object_type.name(), describe(fields))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            );
+        });
+    }
+
+    #[test]
+    fn operation_resolution() {
+        check_plan(
+            "query A { ping } query B { thing { id } }",
+            Some("B"),
+            serde_json::json!({}),
+            |description| assert_eq!("thing Thing{id}", description.unwrap()),
+        );
+        check_plan(
+            "query A { ping } query B { ping }",
+            None,
+            serde_json::json!({}),
+            |description| {
+                assert_eq!(
+                    "OPERATION_RESOLUTION_FAILURE/AnonymousNotEligible",
+                    description.unwrap_err().code(),
+                )
+            },
+        );
+        check_plan(
+            "mutation { ping }",
+            None,
+            serde_json::json!({}),
+            |description| {
+                assert_eq!(
+                    "EXECUTION_PLAN_FAILURE/NoRootOperationType",
+                    description.unwrap_err().code(),
+                )
+            },
+        );
+    }
+}
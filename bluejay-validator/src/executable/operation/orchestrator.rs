@@ -1,7 +1,12 @@
 use crate::executable::{
+    document::OperationWalk,
     operation::{Analyzer, OperationDefinitionValueEvaluationExt, VariableValues, Visitor},
     Cache,
 };
+use bluejay_core::definition::{
+    FieldDefinition, FieldsDefinition, ObjectTypeDefinition, OutputType, SchemaDefinition,
+    TypeDefinitionReference,
+};
 use bluejay_core::{
     definition::{ArgumentsDefinition, DirectiveDefinition, DirectiveLocation},
     executable::{
@@ -9,14 +14,7 @@ use bluejay_core::{
         OperationDefinition, Selection, SelectionReference,
     },
 };
-use bluejay_core::{
-    definition::{
-        FieldDefinition, FieldsDefinition, ObjectTypeDefinition, OutputType, SchemaDefinition,
-        TypeDefinitionReference,
-    },
-    ValueReference,
-};
-use bluejay_core::{Argument, AsIter, Directive, OperationType, Value};
+use bluejay_core::{Argument, AsIter, Directive, ErrorCode, OperationType};
 use std::borrow::Cow;
 use std::collections::HashSet;
 
@@ -43,10 +41,6 @@ impl<
         V: Visitor<'a, E, S, VV>,
     > Orchestrator<'a, E, S, VV, V>
 {
-    const SKIP_DIRECTIVE_NAME: &'static str = "skip";
-    const INCLUDE_DIRECTIVE_NAME: &'static str = "include";
-    const SKIP_INCLUDE_CONDITION_ARGUMENT: &'static str = "if";
-
     fn new(
         operation_definition: &'a E::OperationDefinition,
         schema_definition: &'a S,
@@ -78,7 +72,10 @@ impl<
         &mut self,
         directives: &'a E::Directives<false>,
         location: DirectiveLocation,
+        included: bool,
     ) {
+        self.visitor
+            .visit_variable_directives(directives, location, included);
         directives
             .iter()
             .for_each(|directive| self.visit_variable_directive(directive, location));
@@ -101,8 +98,9 @@ impl<
     }
 
     fn visit_operation_definition(&mut self, operation_definition: &'a E::OperationDefinition) {
-        let core_operation_definition = operation_definition.as_ref();
+        self.enter_operation_definition(operation_definition);
 
+        let core_operation_definition = operation_definition.as_ref();
         let root_operation_type_definition_name = match core_operation_definition.operation_type() {
             OperationType::Query => Some(self.schema_definition.query().name()),
             OperationType::Mutation => self
@@ -115,15 +113,6 @@ impl<
                 .map(ObjectTypeDefinition::name),
         };
 
-        if let Some(directives) = core_operation_definition.directives() {
-            self.visit_variable_directives(
-                directives,
-                core_operation_definition
-                    .operation_type()
-                    .associated_directive_location(),
-            )
-        }
-
         if let Some(root_operation_type_definition_name) = root_operation_type_definition_name {
             self.visit_selection_set(
                 core_operation_definition.selection_set(),
@@ -139,6 +128,23 @@ impl<
             );
         }
 
+        self.leave_operation_definition(operation_definition);
+    }
+
+    fn enter_operation_definition(&mut self, operation_definition: &'a E::OperationDefinition) {
+        let core_operation_definition = operation_definition.as_ref();
+        if let Some(directives) = core_operation_definition.directives() {
+            self.visit_variable_directives(
+                directives,
+                core_operation_definition
+                    .operation_type()
+                    .associated_directive_location(),
+                true,
+            )
+        }
+    }
+
+    fn leave_operation_definition(&mut self, operation_definition: &'a E::OperationDefinition) {
         if let Some(variable_definitions) = operation_definition.as_ref().variable_definitions() {
             variable_definitions.iter().for_each(|variable_definition| {
                 self.visitor.visit_variable_definition(variable_definition)
@@ -152,9 +158,12 @@ impl<
         scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
         included: bool,
     ) {
-        selection_set
-            .iter()
-            .for_each(|selection| match selection.as_ref() {
+        for selection in selection_set.iter() {
+            if self.visitor.should_halt() {
+                break;
+            }
+
+            match selection.as_ref() {
                 SelectionReference::Field(f) => {
                     let field_definition = scoped_type
                         .fields_definition()
@@ -168,7 +177,8 @@ impl<
                     self.visit_inline_fragment(i, scoped_type, included)
                 }
                 SelectionReference::FragmentSpread(fs) => self.visit_fragment_spread(fs, included),
-            })
+            }
+        }
     }
 
     fn visit_field(
@@ -178,13 +188,37 @@ impl<
         owner_type: TypeDefinitionReference<'a, S::TypeDefinition>,
         included: bool,
     ) {
-        if let Some(directives) = field.directives() {
-            self.visit_variable_directives(directives, DirectiveLocation::Field);
+        let included = self.enter_field(field, field_definition, owner_type, included);
+
+        if let Some(selection_set) = field.selection_set() {
+            if let Some(nested_type) = self
+                .schema_definition
+                .get_type_definition(field_definition.r#type().base_name())
+            {
+                self.visit_selection_set(selection_set, nested_type, included);
+            }
         }
+
+        self.visitor
+            .leave_field(field, field_definition, owner_type, included);
+    }
+
+    /// Visits the field and its directives and arguments, but not its selection set, and returns
+    /// whether the field is included.
+    fn enter_field(
+        &mut self,
+        field: &'a E::Field,
+        field_definition: &'a S::FieldDefinition,
+        owner_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) -> bool {
         let included = included
             && field
                 .directives()
                 .is_none_or(|directives| self.evaluate_selection_inclusion(directives));
+        if let Some(directives) = field.directives() {
+            self.visit_variable_directives(directives, DirectiveLocation::Field, included);
+        }
 
         self.visitor
             .visit_field(field, field_definition, owner_type, included);
@@ -199,17 +233,7 @@ impl<
             }
         }
 
-        if let Some(selection_set) = field.selection_set() {
-            if let Some(nested_type) = self
-                .schema_definition
-                .get_type_definition(field_definition.r#type().base_name())
-            {
-                self.visit_selection_set(selection_set, nested_type, included);
-            }
-        }
-
-        self.visitor
-            .leave_field(field, field_definition, owner_type, included);
+        included
     }
 
     fn visit_variable_arguments(
@@ -239,35 +263,51 @@ impl<
         scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
         included: bool,
     ) {
-        if let Some(directives) = inline_fragment.directives() {
-            self.visit_variable_directives(directives, DirectiveLocation::InlineFragment);
-        }
-
-        let included = included
-            && inline_fragment
-                .directives()
-                .is_none_or(|directives| self.evaluate_selection_inclusion(directives));
-
         let fragment_type = if let Some(type_condition) = inline_fragment.type_condition() {
             self.schema_definition.get_type_definition(type_condition)
         } else {
             Some(scoped_type)
         };
 
+        let included = self.enter_inline_fragment(inline_fragment, fragment_type, included);
+
         if let Some(fragment_type) = fragment_type {
             self.visit_selection_set(inline_fragment.selection_set(), fragment_type, included);
         }
     }
 
-    fn visit_fragment_spread(&mut self, fragment_spread: &'a E::FragmentSpread, included: bool) {
-        if let Some(directives) = fragment_spread.directives() {
-            self.visit_variable_directives(directives, DirectiveLocation::FragmentSpread);
+    /// Visits the inline fragment and its directives, but not its selection set, and returns
+    /// whether the inline fragment is included.
+    fn enter_inline_fragment(
+        &mut self,
+        inline_fragment: &'a E::InlineFragment,
+        fragment_type: Option<TypeDefinitionReference<'a, S::TypeDefinition>>,
+        included: bool,
+    ) -> bool {
+        let included = included
+            && inline_fragment
+                .directives()
+                .is_none_or(|directives| self.evaluate_selection_inclusion(directives));
+        if let Some(directives) = inline_fragment.directives() {
+            self.visit_variable_directives(directives, DirectiveLocation::InlineFragment, included);
+        }
+
+        if let Some(fragment_type) = fragment_type {
+            self.visitor
+                .visit_inline_fragment(inline_fragment, fragment_type, included);
         }
 
+        included
+    }
+
+    fn visit_fragment_spread(&mut self, fragment_spread: &'a E::FragmentSpread, included: bool) {
         let included = included
             && fragment_spread
                 .directives()
                 .is_none_or(|directives| self.evaluate_selection_inclusion(directives));
+        if let Some(directives) = fragment_spread.directives() {
+            self.visit_variable_directives(directives, DirectiveLocation::FragmentSpread, included);
+        }
         if self
             .currently_spread_fragments
             .insert(fragment_spread.name())
@@ -275,10 +315,23 @@ impl<
             if let Some(fragment_definition) =
                 self.cache.fragment_definition(fragment_spread.name())
             {
+                if let Some(directives) = fragment_definition.directives() {
+                    self.visit_variable_directives(
+                        directives,
+                        DirectiveLocation::FragmentDefinition,
+                        included,
+                    );
+                }
                 if let Some(type_condition) = self
                     .schema_definition
                     .get_type_definition(fragment_definition.type_condition())
                 {
+                    self.visitor.visit_fragment_spread(
+                        fragment_spread,
+                        fragment_definition,
+                        type_condition,
+                        included,
+                    );
                     self.visit_selection_set(
                         fragment_definition.selection_set(),
                         type_condition,
@@ -292,45 +345,8 @@ impl<
     }
 
     fn evaluate_selection_inclusion(&mut self, directives: &'a E::Directives<false>) -> bool {
-        let skip_directive_value = self.evaluate_boolean_directive_argument_value(
-            directives,
-            Self::SKIP_DIRECTIVE_NAME,
-            Self::SKIP_INCLUDE_CONDITION_ARGUMENT,
-        );
-
-        let include_directive_value = self.evaluate_boolean_directive_argument_value(
-            directives,
-            Self::INCLUDE_DIRECTIVE_NAME,
-            Self::SKIP_INCLUDE_CONDITION_ARGUMENT,
-        );
-
-        !matches!(
-            (skip_directive_value, include_directive_value),
-            (Some(true), _) | (_, Some(false))
-        )
-    }
-
-    fn evaluate_boolean_directive_argument_value(
-        &self,
-        directives: &'a E::Directives<false>,
-        directive_name: &str,
-        arg_name: &str,
-    ) -> Option<bool> {
-        directives
-            .iter()
-            .find(|directive| directive.name() == directive_name)
-            .and_then(|directive| {
-                directive
-                    .arguments()
-                    .and_then(|arguments| arguments.iter().find(|arg| arg.name() == arg_name))
-                    .and_then(|argument| match argument.value().as_ref() {
-                        ValueReference::Boolean(val) => Some(val),
-                        ValueReference::Variable(v) => self
-                            .operation_definition
-                            .evaluate_bool(v, self.variable_values),
-                        _ => None,
-                    })
-            })
+        self.operation_definition
+            .evaluate_selection_inclusion(directives, self.variable_values)
     }
 
     pub fn analyze<'b>(
@@ -344,34 +360,181 @@ impl<
     where
         V: Analyzer<'a, E, S, VV>,
     {
-        let operation_definition = match operation_name {
-            Some(operation_name) => executable_document
-                .operation_definitions()
-                .find(|operation_definition| {
-                    operation_definition.as_ref().name() == Some(operation_name)
-                })
-                .ok_or(OperationResolutionError::NoOperationWithName {
-                    name: operation_name,
-                })?,
-            None => {
-                let [operation_definition]: [&'a E::OperationDefinition; 1] = executable_document
-                    .operation_definitions()
-                    .collect::<Vec<_>>()
-                    .as_slice()
-                    .try_into()
-                    .map_err(|_| OperationResolutionError::AnonymousNotEligible)?;
-                operation_definition
-            }
-        };
-        let mut instance = Self::new(
-            operation_definition,
+        let mut instance = Self::for_operation(
+            executable_document,
             schema_definition,
+            operation_name,
             variable_values,
             cache,
             extra_info,
-        );
+        )?;
         instance.visit();
-        Ok(instance.visitor.into_output())
+        Ok(instance.into_output())
+    }
+
+    /// Constructs the visitor for the operation selected by `operation_name`, to walk it as part
+    /// of a walk of the whole document.
+    pub(crate) fn for_operation<'b>(
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        operation_name: Option<&'b str>,
+        variable_values: &'a VV,
+        cache: &'a Cache<'a, E, S>,
+        extra_info: V::ExtraInfo,
+    ) -> Result<Self, OperationResolutionError<'b>> {
+        resolve_operation(executable_document, operation_name).map(|operation_definition| {
+            Self::new(
+                operation_definition,
+                schema_definition,
+                variable_values,
+                cache,
+                extra_info,
+            )
+        })
+    }
+
+    pub(crate) fn into_output(self) -> <V as Analyzer<'a, E, S, VV>>::Output
+    where
+        V: Analyzer<'a, E, S, VV>,
+    {
+        self.visitor.into_output()
+    }
+}
+
+/// The selections of the operation are walked along with the document, so that they are only
+/// walked once when validating the document and analyzing the operation. Fragments are walked at
+/// each spread, as their selections are visited in the scope of the spread.
+impl<
+        'a,
+        E: ExecutableDocument,
+        S: SchemaDefinition,
+        VV: VariableValues,
+        V: Visitor<'a, E, S, VV>,
+    > OperationWalk<'a, E, S> for Orchestrator<'a, E, S, VV, V>
+{
+    /// Whether the selections are included, or `None` when they are not walked: they are not
+    /// part of the operation, or the visitor halted.
+    type State = Option<bool>;
+
+    fn excluded() -> Self::State {
+        None
+    }
+
+    #[cfg(feature = "validation-cache")]
+    fn walk(mut self) -> Self {
+        self.visit();
+        self
+    }
+
+    fn enter_operation_definition(
+        &mut self,
+        operation_definition: &'a E::OperationDefinition,
+    ) -> Self::State {
+        std::ptr::eq(operation_definition, self.operation_definition).then(|| {
+            self.enter_operation_definition(operation_definition);
+            true
+        })
+    }
+
+    fn leave_operation_definition(
+        &mut self,
+        operation_definition: &'a E::OperationDefinition,
+        state: Self::State,
+    ) {
+        if state.is_some() {
+            self.leave_operation_definition(operation_definition);
+        }
+    }
+
+    fn enter_selection(&mut self, state: Self::State) -> Self::State {
+        state.filter(|_| !self.visitor.should_halt())
+    }
+
+    fn enter_field(
+        &mut self,
+        field: &'a E::Field,
+        field_definition: &'a S::FieldDefinition,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        state: Self::State,
+    ) -> Self::State {
+        state.map(|included| self.enter_field(field, field_definition, scoped_type, included))
+    }
+
+    fn leave_field(
+        &mut self,
+        field: &'a E::Field,
+        field_definition: &'a S::FieldDefinition,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        state: Self::State,
+    ) {
+        if let Some(included) = state {
+            self.visitor
+                .leave_field(field, field_definition, scoped_type, included);
+        }
+    }
+
+    fn enter_inline_fragment(
+        &mut self,
+        inline_fragment: &'a E::InlineFragment,
+        fragment_type: Option<TypeDefinitionReference<'a, S::TypeDefinition>>,
+        state: Self::State,
+    ) -> Self::State {
+        state.map(|included| self.enter_inline_fragment(inline_fragment, fragment_type, included))
+    }
+
+    fn visit_fragment_spread(
+        &mut self,
+        fragment_spread: &'a E::FragmentSpread,
+        state: Self::State,
+    ) {
+        if let Some(included) = state {
+            self.visit_fragment_spread(fragment_spread, included);
+        }
+    }
+}
+
+/// Finds the operation to execute in `executable_document`, as per
+/// [`GetOperation`](https://spec.graphql.org/October2021/#GetOperation()).
+///
+/// Documents with fragments that define variables, from the fragment arguments proposal, are
+/// rejected: variable values are evaluated in the scope of the operation only, so the arguments
+/// of fragment spreads would otherwise be ignored.
+pub(super) fn resolve_operation<'a, 'b, E: ExecutableDocument>(
+    executable_document: &'a E,
+    operation_name: Option<&'b str>,
+) -> Result<&'a E::OperationDefinition, OperationResolutionError<'b>> {
+    if let Some(fragment_definition) =
+        executable_document
+            .fragment_definitions()
+            .find(|fragment_definition| {
+                fragment_definition
+                    .variable_definitions()
+                    .is_some_and(|variable_definitions| !variable_definitions.is_empty())
+            })
+    {
+        return Err(OperationResolutionError::FragmentVariablesNotSupported {
+            fragment_name: fragment_definition.name().to_owned(),
+        });
+    }
+
+    match operation_name {
+        Some(operation_name) => executable_document
+            .operation_definitions()
+            .find(|operation_definition| {
+                operation_definition.as_ref().name() == Some(operation_name)
+            })
+            .ok_or(OperationResolutionError::NoOperationWithName {
+                name: operation_name,
+            }),
+        None => {
+            let [operation_definition]: [&'a E::OperationDefinition; 1] = executable_document
+                .operation_definitions()
+                .collect::<Vec<_>>()
+                .as_slice()
+                .try_into()
+                .map_err(|_| OperationResolutionError::AnonymousNotEligible)?;
+            Ok(operation_definition)
+        }
     }
 }
 
@@ -379,6 +542,19 @@ impl<
 pub enum OperationResolutionError<'a> {
     NoOperationWithName { name: &'a str },
     AnonymousNotEligible,
+    FragmentVariablesNotSupported { fragment_name: String },
+}
+
+impl ErrorCode for OperationResolutionError<'_> {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::NoOperationWithName { .. } => "OPERATION_RESOLUTION_FAILURE/NoOperationWithName",
+            Self::AnonymousNotEligible => "OPERATION_RESOLUTION_FAILURE/AnonymousNotEligible",
+            Self::FragmentVariablesNotSupported { .. } => {
+                "OPERATION_RESOLUTION_FAILURE/FragmentVariablesNotSupported"
+            }
+        }
+    }
 }
 
 impl OperationResolutionError<'_> {
@@ -388,6 +564,10 @@ impl OperationResolutionError<'_> {
                 format!("No operation defined with name {name}").into()
             }
             Self::AnonymousNotEligible => "Anonymous operation can only be used when the document contains exactly one operation definition".into(),
+            Self::FragmentVariablesNotSupported { fragment_name } => format!(
+                "Fragment {fragment_name} defines variables, which are not supported when analyzing or executing an operation"
+            )
+            .into(),
         }
     }
 }
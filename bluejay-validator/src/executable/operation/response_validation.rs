@@ -0,0 +1,596 @@
+use crate::executable::operation::execution_plan::{ExecutionPlan, FieldGroup, GroupedFieldSet};
+use crate::{JsonPath, Path};
+use bluejay_core::definition::{
+    BaseOutputTypeReference, EnumTypeDefinition, EnumValueDefinition, FieldDefinition,
+    ObjectTypeDefinition, OutputType, OutputTypeReference, SchemaDefinition,
+};
+use bluejay_core::executable::ExecutableDocument;
+use bluejay_core::{AsIter, BuiltinScalarDefinition, ErrorCode};
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use std::borrow::Cow;
+
+const TYPENAME_FIELD: &str = "__typename";
+
+/// Validates the `data` of a response to the operation of `execution_plan`, which must be built
+/// with the same variable values as the response was executed with. A `data` of `null` is
+/// accepted, as it is the result of a field error propagating to the root.
+///
+/// Every selected response key must be present and no other, values of non-null types must not
+/// be null, leaf values must be valid for their type, and `__typename` values must name a
+/// possible type. When the selection set of a field of an abstract type does not include
+/// `__typename`, the value must be valid for at least one possible type.
+pub fn validate_response<'a, 'p: 'a, E: ExecutableDocument, S: SchemaDefinition>(
+    schema_definition: &'a S,
+    execution_plan: &'a ExecutionPlan<'p, E, S>,
+    data: &'a JsonValue,
+) -> Result<(), Vec<ResponseError<'a>>> {
+    let validator = ResponseValidator { schema_definition };
+    let mut errors = Vec::new();
+
+    match data {
+        JsonValue::Null => {}
+        JsonValue::Object(object) => validator.validate_object(
+            execution_plan.root_type(),
+            execution_plan.fields(),
+            object,
+            Path::default(),
+            &mut errors,
+        ),
+        _ => errors.push(ResponseError::ExpectedObject {
+            value: data,
+            type_name: execution_plan.root_type().name().to_owned(),
+            path: JsonPath::default(),
+        }),
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ResponseError<'a> {
+    MissingKey {
+        response_key: &'a str,
+        path: JsonPath,
+    },
+    UnexpectedKey {
+        key: &'a str,
+        path: JsonPath,
+    },
+    NullValueForRequiredType {
+        type_name: String,
+        path: JsonPath,
+    },
+    ExpectedList {
+        value: &'a JsonValue,
+        type_name: String,
+        path: JsonPath,
+    },
+    ExpectedObject {
+        value: &'a JsonValue,
+        type_name: String,
+        path: JsonPath,
+    },
+    InvalidLeafValue {
+        value: &'a JsonValue,
+        type_name: &'a str,
+        path: JsonPath,
+    },
+    ImpossibleTypename {
+        typename: &'a str,
+        type_name: &'a str,
+        path: JsonPath,
+    },
+    NoMatchingPossibleType {
+        value: &'a JsonValue,
+        type_name: &'a str,
+        path: JsonPath,
+    },
+}
+
+impl ErrorCode for ResponseError<'_> {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::MissingKey { .. } => "INVALID_RESPONSE/MissingKey",
+            Self::UnexpectedKey { .. } => "INVALID_RESPONSE/UnexpectedKey",
+            Self::NullValueForRequiredType { .. } => "INVALID_RESPONSE/NullValueForRequiredType",
+            Self::ExpectedList { .. } => "INVALID_RESPONSE/ExpectedList",
+            Self::ExpectedObject { .. } => "INVALID_RESPONSE/ExpectedObject",
+            Self::InvalidLeafValue { .. } => "INVALID_RESPONSE/InvalidLeafValue",
+            Self::ImpossibleTypename { .. } => "INVALID_RESPONSE/ImpossibleTypename",
+            Self::NoMatchingPossibleType { .. } => "INVALID_RESPONSE/NoMatchingPossibleType",
+        }
+    }
+}
+
+impl ResponseError<'_> {
+    /// Path to the invalid value, relative to the `data` of the response.
+    pub fn path(&self) -> &JsonPath {
+        match self {
+            Self::MissingKey { path, .. }
+            | Self::UnexpectedKey { path, .. }
+            | Self::NullValueForRequiredType { path, .. }
+            | Self::ExpectedList { path, .. }
+            | Self::ExpectedObject { path, .. }
+            | Self::InvalidLeafValue { path, .. }
+            | Self::ImpossibleTypename { path, .. }
+            | Self::NoMatchingPossibleType { path, .. } => path,
+        }
+    }
+
+    pub fn message(&self) -> Cow<'static, str> {
+        let path = self.path();
+        match self {
+            Self::MissingKey { response_key, .. } => {
+                format!("Missing value for response key `{response_key}` at path `{path}`").into()
+            }
+            Self::UnexpectedKey { key, .. } => {
+                format!("Unexpected key `{key}` at path `{path}`, which is not selected").into()
+            }
+            Self::NullValueForRequiredType { type_name, .. } => {
+                format!("Null value for non-nullable type {type_name} at path `{path}`").into()
+            }
+            Self::ExpectedList {
+                value, type_name, ..
+            } => format!("Expected a list for type {type_name} at path `{path}`, got: {value}")
+                .into(),
+            Self::ExpectedObject {
+                value, type_name, ..
+            } => format!("Expected an object for type {type_name} at path `{path}`, got: {value}")
+                .into(),
+            Self::InvalidLeafValue {
+                value, type_name, ..
+            } => format!("{type_name} cannot represent value {value} at path `{path}`").into(),
+            Self::ImpossibleTypename {
+                typename,
+                type_name,
+                ..
+            } => format!(
+                "`{TYPENAME_FIELD}` value {typename} at path `{path}` is not a possible type of {type_name}"
+            )
+            .into(),
+            Self::NoMatchingPossibleType {
+                value, type_name, ..
+            } => format!(
+                "Value at path `{path}` is not valid for any possible type of {type_name}, got: {value}"
+            )
+            .into(),
+        }
+    }
+}
+
+struct ResponseValidator<'a, S: SchemaDefinition> {
+    schema_definition: &'a S,
+}
+
+impl<'a, S: SchemaDefinition> ResponseValidator<'a, S> {
+    fn validate_object<'p: 'a, E: ExecutableDocument>(
+        &self,
+        object_type: &'a S::ObjectTypeDefinition,
+        grouped_field_set: &'a GroupedFieldSet<'p, E, S>,
+        object: &'a JsonMap<String, JsonValue>,
+        path: Path<'a>,
+        errors: &mut Vec<ResponseError<'a>>,
+    ) {
+        grouped_field_set.iter().for_each(|field_group| {
+            let response_key = field_group.response_key();
+            let value_path = path.push(response_key);
+            let Some(value) = object.get(response_key) else {
+                errors.push(ResponseError::MissingKey {
+                    response_key,
+                    path: value_path.into(),
+                });
+                return;
+            };
+
+            if field_group.field_definition().name() == TYPENAME_FIELD {
+                if let Some(typename) = value.as_str() {
+                    if typename != object_type.name() {
+                        errors.push(ResponseError::ImpossibleTypename {
+                            typename,
+                            type_name: object_type.name(),
+                            path: value_path.into(),
+                        });
+                        return;
+                    }
+                }
+            }
+
+            self.validate_value(
+                field_group.field_definition().r#type(),
+                field_group,
+                value,
+                value_path,
+                errors,
+            );
+        });
+
+        object
+            .keys()
+            .filter(|key| grouped_field_set.get(key).is_none())
+            .for_each(|key| {
+                errors.push(ResponseError::UnexpectedKey {
+                    key,
+                    path: path.push(key.as_str()).into(),
+                })
+            });
+    }
+
+    fn validate_value<'p: 'a, E: ExecutableDocument>(
+        &self,
+        field_type: &'a <S::FieldDefinition as FieldDefinition>::OutputType,
+        field_group: &'a FieldGroup<'p, E, S>,
+        value: &'a JsonValue,
+        path: Path<'a>,
+        errors: &mut Vec<ResponseError<'a>>,
+    ) {
+        if value.is_null() {
+            if field_type.is_required() {
+                errors.push(ResponseError::NullValueForRequiredType {
+                    type_name: field_type.display_name(),
+                    path: path.into(),
+                });
+            }
+            return;
+        }
+
+        match field_type.as_ref(self.schema_definition) {
+            OutputTypeReference::List(inner_type, _) => match value {
+                JsonValue::Array(items) => items.iter().enumerate().for_each(|(index, item)| {
+                    self.validate_value(inner_type, field_group, item, path.push(index), errors)
+                }),
+                _ => errors.push(ResponseError::ExpectedList {
+                    value,
+                    type_name: field_type.display_name(),
+                    path: path.into(),
+                }),
+            },
+            OutputTypeReference::Base(base_type, _) => {
+                let is_valid_leaf = match base_type {
+                    BaseOutputTypeReference::BuiltinScalar(builtin_scalar) => {
+                        is_valid_builtin_scalar(builtin_scalar, value)
+                    }
+                    BaseOutputTypeReference::CustomScalar(_) => true,
+                    BaseOutputTypeReference::Enum(etd) => value.as_str().is_some_and(|name| {
+                        etd.enum_value_definitions()
+                            .iter()
+                            .any(|evd| evd.name() == name)
+                    }),
+                    BaseOutputTypeReference::Object(_)
+                    | BaseOutputTypeReference::Interface(_)
+                    | BaseOutputTypeReference::Union(_) => {
+                        return self.validate_composite(
+                            base_type,
+                            field_type,
+                            field_group,
+                            value,
+                            path,
+                            errors,
+                        );
+                    }
+                };
+
+                if !is_valid_leaf {
+                    errors.push(ResponseError::InvalidLeafValue {
+                        value,
+                        type_name: base_type.name(),
+                        path: path.into(),
+                    });
+                }
+            }
+        }
+    }
+
+    fn validate_composite<'p: 'a, E: ExecutableDocument>(
+        &self,
+        base_type: BaseOutputTypeReference<'a, <S::FieldDefinition as FieldDefinition>::OutputType>,
+        field_type: &'a <S::FieldDefinition as FieldDefinition>::OutputType,
+        field_group: &'a FieldGroup<'p, E, S>,
+        value: &'a JsonValue,
+        path: Path<'a>,
+        errors: &mut Vec<ResponseError<'a>>,
+    ) {
+        let JsonValue::Object(object) = value else {
+            errors.push(ResponseError::ExpectedObject {
+                value,
+                type_name: field_type.display_name(),
+                path: path.into(),
+            });
+            return;
+        };
+        let Some(selection) = field_group.selection() else {
+            return;
+        };
+
+        // the `__typename` of the value determines its object type when it is selected
+        let typename = selection.iter().find_map(|(_, grouped_field_set)| {
+            grouped_field_set
+                .iter()
+                .find(|field_group| field_group.field_definition().name() == TYPENAME_FIELD)
+                .and_then(|field_group| object.get(field_group.response_key()))
+                .and_then(JsonValue::as_str)
+        });
+
+        if let Some(typename) = typename {
+            match selection
+                .iter()
+                .find(|(object_type, _)| object_type.name() == typename)
+            {
+                Some((object_type, grouped_field_set)) => {
+                    self.validate_object(object_type, grouped_field_set, object, path, errors)
+                }
+                None => errors.push(ResponseError::ImpossibleTypename {
+                    typename,
+                    type_name: base_type.name(),
+                    path: path.into(),
+                }),
+            }
+            return;
+        }
+
+        let mut possible_types = selection.iter();
+        if let (Some((object_type, grouped_field_set)), None) =
+            (possible_types.next(), possible_types.next())
+        {
+            // report the errors for the only possible type
+            self.validate_object(object_type, grouped_field_set, object, path, errors);
+        } else if !selection.iter().any(|(object_type, grouped_field_set)| {
+            let mut object_errors = Vec::new();
+            self.validate_object(
+                object_type,
+                grouped_field_set,
+                object,
+                path.clone(),
+                &mut object_errors,
+            );
+            object_errors.is_empty()
+        }) {
+            errors.push(ResponseError::NoMatchingPossibleType {
+                value,
+                type_name: base_type.name(),
+                path: path.into(),
+            });
+        }
+    }
+}
+
+fn is_valid_builtin_scalar(builtin_scalar: BuiltinScalarDefinition, value: &JsonValue) -> bool {
+    match builtin_scalar {
+        BuiltinScalarDefinition::Int => {
+            value.as_i64().is_some_and(|int| i32::try_from(int).is_ok())
+        }
+        BuiltinScalarDefinition::Float => value.is_number(),
+        BuiltinScalarDefinition::String | BuiltinScalarDefinition::ID => value.is_string(),
+        BuiltinScalarDefinition::Boolean => value.is_boolean(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate_response, ResponseError};
+    use crate::executable::{operation::execution_plan::ExecutionPlan, Cache};
+    use bluejay_core::ErrorCode;
+    use bluejay_parser::ast::{
+        definition::{
+            DefaultContext, DefinitionDocument, SchemaDefinition as ParserSchemaDefinition,
+        },
+        executable::ExecutableDocument as ParserExecutableDocument,
+        Parse,
+    };
+    use serde_json::{json, Value as JsonValue};
+
+    const TEST_SCHEMA: &str = r#"
+        type Query {
+          node(id: ID!): Node
+          search: [SearchResult!]!
+          products: [Product]
+          count: Int!
+        }
+        interface Node {
+          id: ID!
+        }
+        type Product implements Node {
+          id: ID!
+          title: String!
+          status: Status
+        }
+        type Collection implements Node {
+          id: ID!
+          title: String!
+        }
+        union SearchResult = Product | Collection
+        enum Status {
+          ACTIVE
+          ARCHIVED
+        }
+        schema {
+          query: Query
+        }
+    "#;
+
+    fn check_response(
+        source: &str,
+        variables: JsonValue,
+        data: JsonValue,
+        f: impl FnOnce(Vec<(&'static str, String)>),
+    ) {
+        let definition_document: DefinitionDocument<'_, DefaultContext> =
+            DefinitionDocument::parse(TEST_SCHEMA)
+                .result
+                .expect("Schema had parse errors");
+        let schema_definition =
+            ParserSchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+        let executable_document = ParserExecutableDocument::parse(source)
+            .result
+            .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let cache = Cache::new(&executable_document, &schema_definition);
+        let variables = variables.as_object().expect("Variables must be an object");
+        let execution_plan = ExecutionPlan::new(
+            &executable_document,
+            &schema_definition,
+            None,
+            variables,
+            &cache,
+        )
+        .expect("Execution plan could not be built");
+
+        f(
+            validate_response(&schema_definition, &execution_plan, &data)
+                .err()
+                .unwrap_or_default()
+                .iter()
+                .map(|error: &ResponseError| (error.code(), error.message().into_owned()))
+                .collect(),
+        );
+    }
+
+    #[test]
+    fn test_valid_response() {
+        check_response(
+            r#"query($withTitle: Boolean!) {
+              count
+              node(id: "1") { __typename id ... on Product { status } }
+              products { id title @include(if: $withTitle) }
+              search { ...on Product { title } }
+            }"#,
+            json!({ "withTitle": false }),
+            json!({
+                "count": 1,
+                "node": { "__typename": "Product", "id": "1", "status": "ACTIVE" },
+                "products": [{ "id": "2" }, null],
+                "search": [{ "title": "Hat" }, {}],
+            }),
+            |errors| assert_eq!(Vec::<(&str, String)>::new(), errors),
+        );
+        check_response("{ count }", json!({}), JsonValue::Null, |errors| {
+            assert!(errors.is_empty())
+        });
+    }
+
+    #[test]
+    fn test_shape_errors() {
+        check_response(
+            r#"{ count products { id title } }"#,
+            json!({}),
+            json!({
+                "count": null,
+                "products": [{ "id": "1", "title": null, "extra": 1 }, { "id": "2" }, 3],
+            }),
+            |errors| {
+                assert_eq!(
+                    vec![
+                        (
+                            "INVALID_RESPONSE/NullValueForRequiredType",
+                            "Null value for non-nullable type Int! at path `count`".to_owned(),
+                        ),
+                        (
+                            "INVALID_RESPONSE/NullValueForRequiredType",
+                            "Null value for non-nullable type String! at path `products.0.title`"
+                                .to_owned(),
+                        ),
+                        (
+                            "INVALID_RESPONSE/UnexpectedKey",
+                            "Unexpected key `extra` at path `products.0.extra`, which is not selected"
+                                .to_owned(),
+                        ),
+                        (
+                            "INVALID_RESPONSE/MissingKey",
+                            "Missing value for response key `title` at path `products.1.title`"
+                                .to_owned(),
+                        ),
+                        (
+                            "INVALID_RESPONSE/ExpectedObject",
+                            "Expected an object for type Product at path `products.2`, got: 3"
+                                .to_owned(),
+                        ),
+                    ],
+                    errors,
+                );
+            },
+        );
+        check_response(
+            "{ products { id } }",
+            json!({}),
+            json!({ "products": { "id": "1" } }),
+            |errors| {
+                assert_eq!(
+                    vec![(
+                        "INVALID_RESPONSE/ExpectedList",
+                        "Expected a list for type [Product] at path `products`, got: {\"id\":\"1\"}"
+                            .to_owned(),
+                    )],
+                    errors,
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_leaf_value_errors() {
+        check_response(
+            r#"{ count node(id: "1") { __typename id ... on Product { status } } }"#,
+            json!({}),
+            json!({
+                "count": 4_294_967_296_i64,
+                "node": { "__typename": "Product", "id": 1, "status": "DELETED" },
+            }),
+            |errors| {
+                assert_eq!(
+                    vec![
+                        (
+                            "INVALID_RESPONSE/InvalidLeafValue",
+                            "Int cannot represent value 4294967296 at path `count`".to_owned(),
+                        ),
+                        (
+                            "INVALID_RESPONSE/InvalidLeafValue",
+                            "ID cannot represent value 1 at path `node.id`".to_owned(),
+                        ),
+                        (
+                            "INVALID_RESPONSE/InvalidLeafValue",
+                            "Status cannot represent value \"DELETED\" at path `node.status`"
+                                .to_owned(),
+                        ),
+                    ],
+                    errors,
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_abstract_type_errors() {
+        check_response(
+            r#"{
+              product: node(id: "1") { kind: __typename id }
+              shop: node(id: "2") { __typename id }
+              search { ... on Product { status } ... on Collection { title } }
+            }"#,
+            json!({}),
+            json!({
+                "product": { "kind": "Product", "id": "1" },
+                "shop": { "__typename": "Shop", "id": "2" },
+                "search": [{ "status": "ACTIVE" }, { "title": "Hats" }, { "id": "3" }],
+            }),
+            |errors| {
+                assert_eq!(
+                    vec![
+                        (
+                            "INVALID_RESPONSE/ImpossibleTypename",
+                            "`__typename` value Shop at path `shop` is not a possible type of Node"
+                                .to_owned(),
+                        ),
+                        (
+                            "INVALID_RESPONSE/NoMatchingPossibleType",
+                            "Value at path `search.2` is not valid for any possible type of SearchResult, got: {\"id\":\"3\"}"
+                                .to_owned(),
+                        ),
+                    ],
+                    errors,
+                );
+            },
+        );
+    }
+}
@@ -1,7 +1,17 @@
+use super::argument_values::{self, ArgumentValueError, CoercedArgumentValues};
+use crate::value::input_coercion::CoercedValue;
+use bluejay_core::definition::SchemaDefinition;
 use bluejay_core::executable::{
     OperationDefinition, VariableDefinition, VariableType, VariableTypeReference,
 };
-use bluejay_core::{AsIter, BuiltinScalarDefinition, Value, Variable};
+use bluejay_core::{
+    Argument, AsIter, BuiltinScalarDefinition, Directive, Value, ValueReference, Variable,
+    VariableArguments, VariableDirectives,
+};
+
+const SKIP_DIRECTIVE_NAME: &str = "skip";
+const INCLUDE_DIRECTIVE_NAME: &str = "include";
+const SKIP_INCLUDE_CONDITION_ARGUMENT: &str = "if";
 
 pub trait VariableValues {
     type Key: AsRef<str>;
@@ -29,6 +39,30 @@ pub trait OperationDefinitionValueEvaluationExt: OperationDefinition {
         variable: &V,
         variable_values: &VV,
     ) -> Option<i32>;
+
+    /// Whether a selection with the given `directives` is included in the response, based on
+    /// `@skip(if:)` and `@include(if:)` evaluated against `variable_values`.
+    ///
+    /// Only the variables of the operation are in scope, so the directives must not be within a
+    /// fragment that defines variables.
+    fn evaluate_selection_inclusion<D: VariableDirectives, VV: VariableValues>(
+        &self,
+        directives: &D,
+        variable_values: &VV,
+    ) -> bool;
+
+    /// Coerces the `arguments` of a field or directive against its `arguments_definition`,
+    /// resolving variable references with `variable_values` and applying default values.
+    ///
+    /// Only the variables of the operation are in scope, so the arguments must not be within a
+    /// fragment that defines variables.
+    fn coerce_argument_values<'a, S: SchemaDefinition, A: VariableArguments, VV: VariableValues>(
+        &'a self,
+        schema_definition: &'a S,
+        arguments_definition: Option<&'a S::ArgumentsDefinition>,
+        arguments: Option<&'a A>,
+        variable_values: &'a VV,
+    ) -> Result<CoercedArgumentValues<'a>, Vec<ArgumentValueError<'a>>>;
 }
 
 impl<T: OperationDefinition> OperationDefinitionValueEvaluationExt for T {
@@ -87,6 +121,58 @@ impl<T: OperationDefinition> OperationDefinitionValueEvaluationExt for T {
                 .and_then(|value| value.as_ref().as_integer().copied())
         }
     }
+
+    fn evaluate_selection_inclusion<D: VariableDirectives, VV: VariableValues>(
+        &self,
+        directives: &D,
+        variable_values: &VV,
+    ) -> bool {
+        let evaluate_condition = |directive_name: &str| {
+            directives
+                .iter()
+                .find(|directive| directive.name() == directive_name)
+                .and_then(|directive| {
+                    directive
+                        .arguments()
+                        .and_then(|arguments| {
+                            arguments
+                                .iter()
+                                .find(|argument| argument.name() == SKIP_INCLUDE_CONDITION_ARGUMENT)
+                        })
+                        .and_then(|argument| match argument.value().as_ref() {
+                            ValueReference::Boolean(value) => Some(value),
+                            ValueReference::Variable(variable) => {
+                                self.evaluate_bool(variable, variable_values)
+                            }
+                            _ => None,
+                        })
+                })
+        };
+
+        !matches!(
+            (
+                evaluate_condition(SKIP_DIRECTIVE_NAME),
+                evaluate_condition(INCLUDE_DIRECTIVE_NAME),
+            ),
+            (Some(true), _) | (_, Some(false))
+        )
+    }
+
+    fn coerce_argument_values<'a, S: SchemaDefinition, A: VariableArguments, VV: VariableValues>(
+        &'a self,
+        schema_definition: &'a S,
+        arguments_definition: Option<&'a S::ArgumentsDefinition>,
+        arguments: Option<&'a A>,
+        variable_values: &'a VV,
+    ) -> Result<CoercedArgumentValues<'a>, Vec<ArgumentValueError<'a>>> {
+        argument_values::coerce_argument_values(
+            self,
+            schema_definition,
+            arguments_definition,
+            arguments,
+            variable_values,
+        )
+    }
 }
 
 #[cfg(feature = "serde_json")]
@@ -99,3 +185,43 @@ impl VariableValues for serde_json::Map<String, serde_json::Value> {
         self.iter()
     }
 }
+
+/// Variable values after input coercion, as produced by
+/// [`CoerceVariableValues`](super::analyzers::CoerceVariableValues), in the order of the
+/// variable definitions of the operation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CoercedVariableValues(Vec<(String, CoercedValue)>);
+
+impl CoercedVariableValues {
+    pub(crate) fn push(&mut self, name: &str, value: CoercedValue) {
+        self.0.push((name.to_owned(), value));
+    }
+
+    pub fn into_inner(self) -> Vec<(String, CoercedValue)> {
+        self.0
+    }
+}
+
+impl VariableValues for CoercedVariableValues {
+    type Key = String;
+    type Value = CoercedValue;
+    type Iterator<'a> = std::iter::Map<
+        std::slice::Iter<'a, (String, CoercedValue)>,
+        fn(&'a (String, CoercedValue)) -> (&'a String, &'a CoercedValue),
+    >;
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.0.iter().map(|(key, value)| (key, value))
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl From<CoercedVariableValues> for serde_json::Map<String, serde_json::Value> {
+    fn from(value: CoercedVariableValues) -> Self {
+        value
+            .0
+            .into_iter()
+            .map(|(key, value)| (key, value.into()))
+            .collect()
+    }
+}
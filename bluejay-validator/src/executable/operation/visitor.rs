@@ -1,5 +1,5 @@
 use crate::executable::{operation::VariableValues, Cache};
-use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::definition::{DirectiveLocation, SchemaDefinition, TypeDefinitionReference};
 use bluejay_core::executable::ExecutableDocument;
 
 pub trait Visitor<'a, E: ExecutableDocument, S: SchemaDefinition, V: VariableValues> {
@@ -51,11 +51,73 @@ pub trait Visitor<'a, E: ExecutableDocument, S: SchemaDefinition, V: VariableVal
     ) {
     }
 
+    /// Visits the inline fragment, before its selections.
+    /// # Variables
+    /// - `inline_fragment` is the inline fragment being visited
+    /// - `fragment_type` is the type its selections are scoped to: its type condition, or the
+    ///   enclosing type when it has none
+    /// - `included` is true when the inline fragment is known to be included in the response
+    ///   (see `visit_field`)
+    #[allow(unused_variables)]
+    fn visit_inline_fragment(
+        &mut self,
+        inline_fragment: &'a E::InlineFragment,
+        fragment_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+    }
+
+    /// Visits the fragment spread, before the selections of the fragment definition it spreads.
+    /// This is not called for spreads that would form a cycle, or of unknown fragments.
+    /// # Variables
+    /// - `fragment_spread` is the fragment spread being visited
+    /// - `fragment_definition` is the definition of the spread fragment
+    /// - `fragment_type` is the type condition of the fragment definition
+    /// - `included` is true when the fragment spread is known to be included in the response
+    ///   (see `visit_field`)
+    #[allow(unused_variables)]
+    fn visit_fragment_spread(
+        &mut self,
+        fragment_spread: &'a E::FragmentSpread,
+        fragment_definition: &'a E::FragmentDefinition,
+        fragment_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+    }
+
+    /// Visits the directives of the operation, or of a field, fragment spread, inline fragment
+    /// or fragment definition. Like fields, the directives of a fragment definition are visited
+    /// every time the fragment is spread.
+    /// # Variables
+    /// - `directives` are the directives being visited
+    /// - `location` is the location of the directives
+    /// - `included` is true when the node the directives are on is known to be included in the
+    ///   response (see `visit_field`)
+    #[allow(unused_variables)]
+    fn visit_variable_directives(
+        &mut self,
+        directives: &'a E::Directives<false>,
+        location: DirectiveLocation,
+        included: bool,
+    ) {
+    }
+
     /// Visits the variable definition.
     /// # Variables
     /// - `variable_definition` is the variable definition being visited
     #[allow(unused_variables)]
     fn visit_variable_definition(&mut self, variable_definition: &'a E::VariableDefinition) {}
+
+    /// Whether the traversal should stop early, e.g. because the visitor has already found
+    /// enough to reject the operation. Once this returns true, no further selections are visited,
+    /// but `leave_field` is still called for every field that has been visited.
+    ///
+    /// A tuple of visitors only halts once all of its members halt, so that one member halting
+    /// does not cut short the others. A member that halts first keeps being visited, and should
+    /// ignore the fields it is visited with from then on.
+    fn should_halt(&self) -> bool {
+        false
+    }
 }
 
 macro_rules! impl_visitor {
@@ -104,6 +166,34 @@ macro_rules! impl_visitor {
                     #(self.N.leave_field(field, field_definition, owner_type, included);)*
                 }
 
+                fn visit_inline_fragment(
+                    &mut self,
+                    inline_fragment: &'a E::InlineFragment,
+                    fragment_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+                    included: bool,
+                ) {
+                    #(self.N.visit_inline_fragment(inline_fragment, fragment_type, included);)*
+                }
+
+                fn visit_fragment_spread(
+                    &mut self,
+                    fragment_spread: &'a E::FragmentSpread,
+                    fragment_definition: &'a E::FragmentDefinition,
+                    fragment_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+                    included: bool,
+                ) {
+                    #(self.N.visit_fragment_spread(fragment_spread, fragment_definition, fragment_type, included);)*
+                }
+
+                fn visit_variable_directives(
+                    &mut self,
+                    directives: &'a E::Directives<false>,
+                    location: DirectiveLocation,
+                    included: bool,
+                ) {
+                    #(self.N.visit_variable_directives(directives, location, included);)*
+                }
+
                 fn visit_variable_definition(&mut self, variable_definition: &'a E::VariableDefinition) {
                     #(self.N.visit_variable_definition(variable_definition);)*
                 }
@@ -111,6 +201,10 @@ macro_rules! impl_visitor {
                 fn visit_variable_argument(&mut self, argument: &'a E::Argument<false>, input_value_definition: &'a S::InputValueDefinition) {
                     #(self.N.visit_variable_argument(argument, input_value_definition);)*
                 }
+
+                fn should_halt(&self) -> bool {
+                    #(self.N.should_halt() &&)* true
+                }
             }
         });
     }
@@ -0,0 +1,257 @@
+use crate::executable::{
+    document::{Error, OperationWalk, RuleRegistry, ValidationReport},
+    operation::{Analyzer, OperationResolutionError, Orchestrator, VariableValues},
+    Cache, SchemaCache,
+};
+#[cfg(feature = "validation-cache")]
+use crate::executable::{SchemaFingerprint, SharedValidationCache, ValidationCacheKey};
+use bluejay_core::definition::SchemaDefinition;
+use bluejay_core::executable::ExecutableDocument;
+#[cfg(feature = "validation-cache")]
+use std::sync::Arc;
+
+/// Validates an executable document and, only if it is valid, analyzes the operation to execute
+/// with a set of operation [`Analyzer`]s, in one call.
+///
+/// The document is walked once: the analyzers visit the selections of the operation as the
+/// document rules walk them, and their output is discarded when the document turns out to be
+/// invalid. The selections of fragments are visited by the rules once, in the fragment
+/// definition, and by the analyzers at every spread. The [`SchemaCache`] is built once and
+/// shared by every document run through the pipeline. Multiple analyzers are run by passing
+/// them as a tuple, e.g. `(QueryDepth, ComplexityCost<_, _, _, _>, Deprecation<_, _, _>)`.
+///
+/// With the `validation-cache` feature, [`Pipeline::with_validation_cache`] remembers documents
+/// that validated without errors or warnings, and only walks the operation for them.
+pub struct Pipeline<'a, S: SchemaDefinition> {
+    schema_definition: &'a S,
+    schema_cache: SchemaCache<'a, S>,
+    rule_registry: RuleRegistry,
+    #[cfg(feature = "validation-cache")]
+    validation_cache: Option<(SchemaFingerprint, Arc<SharedValidationCache<()>>)>,
+}
+
+/// A document prepared by [`Pipeline::run`], to analyze with [`PipelineRun::analyze`].
+pub struct PipelineRun<'a, E: ExecutableDocument, S: SchemaDefinition> {
+    executable_document: &'a E,
+    schema_definition: &'a S,
+    cache: &'a Cache<'a, E, S>,
+    rule_registry: &'a RuleRegistry,
+    #[cfg(feature = "validation-cache")]
+    validation_cache: Option<&'a (SchemaFingerprint, Arc<SharedValidationCache<()>>)>,
+}
+
+/// The outcome of [`PipelineRun::analyze`].
+pub enum PipelineResult<'a, 'b, E: ExecutableDocument, S: SchemaDefinition, O> {
+    /// The document had validation errors, so the analyzers were not run.
+    Invalid(ValidationReport<'a, E, S>),
+    /// The document is valid, but no operation could be selected to analyze.
+    OperationResolutionFailed {
+        error: OperationResolutionError<'b>,
+        warnings: Vec<Error<'a, E, S>>,
+    },
+    /// The document is valid and the selected operation was analyzed.
+    Analyzed {
+        output: O,
+        warnings: Vec<Error<'a, E, S>>,
+    },
+}
+
+impl<E: ExecutableDocument, S: SchemaDefinition, O> PipelineResult<'_, '_, E, S, O> {
+    pub fn output(&self) -> Option<&O> {
+        match self {
+            Self::Analyzed { output, .. } => Some(output),
+            _ => None,
+        }
+    }
+
+    pub fn into_output(self) -> Option<O> {
+        match self {
+            Self::Analyzed { output, .. } => Some(output),
+            _ => None,
+        }
+    }
+
+    /// The validation errors, empty unless the result is [`PipelineResult::Invalid`].
+    pub fn errors(&self) -> &[Error<'_, E, S>] {
+        match self {
+            Self::Invalid(report) => &report.errors,
+            _ => &[],
+        }
+    }
+
+    /// The validation errors of rules configured with [`Severity::Warning`].
+    ///
+    /// [`Severity::Warning`]: crate::executable::document::Severity::Warning
+    pub fn warnings(&self) -> &[Error<'_, E, S>] {
+        match self {
+            Self::Invalid(report) => &report.warnings,
+            Self::OperationResolutionFailed { warnings, .. } | Self::Analyzed { warnings, .. } => {
+                warnings
+            }
+        }
+    }
+}
+
+impl<'a, S: SchemaDefinition> Pipeline<'a, S> {
+    pub fn new(schema_definition: &'a S) -> Self {
+        Self {
+            schema_definition,
+            schema_cache: SchemaCache::new(schema_definition),
+            rule_registry: RuleRegistry::default(),
+            #[cfg(feature = "validation-cache")]
+            validation_cache: None,
+        }
+    }
+
+    pub fn with_rule_registry(mut self, rule_registry: RuleRegistry) -> Self {
+        self.rule_registry = rule_registry;
+        self
+    }
+
+    /// Skips validating documents found in `validation_cache`, and records documents that are
+    /// valid without warnings in it. The cache can be shared by pipelines on several threads;
+    /// entries are keyed by the fingerprint of this pipeline's schema and by its
+    /// [`RuleRegistry`].
+    #[cfg(feature = "validation-cache")]
+    pub fn with_validation_cache(
+        mut self,
+        validation_cache: Arc<SharedValidationCache<()>>,
+    ) -> Self {
+        self.validation_cache = Some((
+            SchemaFingerprint::new(self.schema_definition),
+            validation_cache,
+        ));
+        self
+    }
+
+    pub fn schema_cache(&self) -> &SchemaCache<'a, S> {
+        &self.schema_cache
+    }
+
+    /// Builds the [`Cache`] for `executable_document` and passes the prepared document to `f`.
+    ///
+    /// The validation errors and analyzer outputs borrow from the per-document [`Cache`], which
+    /// lives only for the duration of this call, so `f` analyzes the document with
+    /// [`PipelineRun::analyze`] and maps the [`PipelineResult`] into an owned result.
+    pub fn run<E: ExecutableDocument, T>(
+        &self,
+        executable_document: &E,
+        f: impl for<'b> FnOnce(PipelineRun<'b, E, S>) -> T,
+    ) -> T {
+        let cache = Cache::with_schema_cache(
+            executable_document,
+            self.schema_definition,
+            &self.schema_cache,
+        );
+        f(PipelineRun {
+            executable_document,
+            schema_definition: self.schema_definition,
+            cache: &cache,
+            rule_registry: &self.rule_registry,
+            #[cfg(feature = "validation-cache")]
+            validation_cache: self.validation_cache.as_ref(),
+        })
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition> PipelineRun<'a, E, S> {
+    /// Validates the document and, if there are no validation errors, analyzes the operation
+    /// selected by `operation_name` with `A`, constructed with `extra_info`.
+    ///
+    /// Validation is skipped when the document is found in the pipeline's validation cache.
+    pub fn analyze<'b, VV, A>(
+        self,
+        operation_name: Option<&'b str>,
+        variable_values: &'a VV,
+        extra_info: A::ExtraInfo,
+    ) -> PipelineResult<'a, 'b, E, S, A::Output>
+    where
+        VV: VariableValues,
+        A: Analyzer<'a, E, S, VV>,
+    {
+        let orchestrator = match Orchestrator::<E, S, VV, A>::for_operation(
+            self.executable_document,
+            self.schema_definition,
+            operation_name,
+            variable_values,
+            self.cache,
+            extra_info,
+        ) {
+            Ok(orchestrator) => orchestrator,
+            Err(error) => {
+                let (report, ()) = self.validate(());
+                return if report.is_valid() {
+                    PipelineResult::OperationResolutionFailed {
+                        error,
+                        warnings: report.warnings,
+                    }
+                } else {
+                    PipelineResult::Invalid(report)
+                };
+            }
+        };
+
+        let (report, orchestrator) = self.validate(orchestrator);
+        if report.is_valid() {
+            PipelineResult::Analyzed {
+                output: orchestrator.into_output(),
+                warnings: report.warnings,
+            }
+        } else {
+            PipelineResult::Invalid(report)
+        }
+    }
+
+    /// Validates the document, driving `operation_walk` in the same walk. When the document is
+    /// found in the validation cache, only `operation_walk` is walked.
+    fn validate<O: OperationWalk<'a, E, S>>(
+        &self,
+        operation_walk: O,
+    ) -> (ValidationReport<'a, E, S>, O) {
+        #[cfg(feature = "validation-cache")]
+        let validation_cache_key = self.validation_cache_key();
+        #[cfg(feature = "validation-cache")]
+        if let Some((key, validation_cache)) = &validation_cache_key {
+            if validation_cache.get(key).is_some() {
+                let report = ValidationReport {
+                    errors: Vec::new(),
+                    warnings: Vec::new(),
+                };
+                return (report, operation_walk.walk());
+            }
+        }
+
+        let (report, operation_walk) = self.rule_registry.validate_with_operation_walk(
+            self.executable_document,
+            self.schema_definition,
+            self.cache,
+            operation_walk,
+        );
+
+        // only documents without any errors or warnings are cached, so a hit has nothing to report
+        #[cfg(feature = "validation-cache")]
+        if let Some((key, validation_cache)) = validation_cache_key {
+            if report.errors.is_empty() && report.warnings.is_empty() {
+                validation_cache.insert(key, ());
+            }
+        }
+
+        (report, operation_walk)
+    }
+
+    #[cfg(feature = "validation-cache")]
+    fn validation_cache_key(&self) -> Option<(ValidationCacheKey, &SharedValidationCache<()>)> {
+        self.validation_cache
+            .map(|(schema_fingerprint, validation_cache)| {
+                // validity does not depend on the operation selected, so all operations share
+                // an entry
+                let key = ValidationCacheKey::for_document(
+                    *schema_fingerprint,
+                    self.executable_document,
+                    None,
+                )
+                .with_rule_registry(self.rule_registry);
+                (key, validation_cache.as_ref())
+            })
+    }
+}
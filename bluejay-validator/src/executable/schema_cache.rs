@@ -0,0 +1,63 @@
+use bluejay_core::definition::{
+    BaseInputTypeReference, ObjectTypeDefinition, SchemaDefinition, TypeDefinitionReference,
+    UnionMemberType, UnionTypeDefinition,
+};
+use bluejay_core::AsIter;
+use std::collections::{HashMap, HashSet};
+
+/// Lookups derived only from the schema, so they can be computed once and shared by the
+/// [`Cache`](super::Cache) of every document validated against the same schema.
+pub struct SchemaCache<'a, S: SchemaDefinition> {
+    possible_type_names: HashMap<&'a str, HashSet<&'a str>>,
+    base_input_types: HashMap<&'a str, BaseInputTypeReference<'a, S::InputType>>,
+}
+
+impl<'a, S: SchemaDefinition> SchemaCache<'a, S> {
+    pub fn new(schema_definition: &'a S) -> Self {
+        let mut possible_type_names = HashMap::new();
+        let mut base_input_types = HashMap::new();
+
+        schema_definition
+            .type_definitions()
+            .for_each(|type_definition| match type_definition {
+                TypeDefinitionReference::Interface(itd) => {
+                    possible_type_names.insert(
+                        type_definition.name(),
+                        schema_definition
+                            .get_interface_implementors(itd)
+                            .map(ObjectTypeDefinition::name)
+                            .collect(),
+                    );
+                }
+                TypeDefinitionReference::Union(utd) => {
+                    possible_type_names.insert(
+                        type_definition.name(),
+                        utd.union_member_types()
+                            .iter()
+                            .map(UnionMemberType::name)
+                            .collect(),
+                    );
+                }
+                _ => {
+                    if let Ok(base_input_type) = BaseInputTypeReference::try_from(type_definition) {
+                        base_input_types.insert(type_definition.name(), base_input_type);
+                    }
+                }
+            });
+
+        Self {
+            possible_type_names,
+            base_input_types,
+        }
+    }
+
+    /// The names of the object types that implement the interface, or are members of the union,
+    /// with the given name. Returns `None` for types that are not abstract.
+    pub fn possible_type_names(&self, abstract_type_name: &str) -> Option<&HashSet<&'a str>> {
+        self.possible_type_names.get(abstract_type_name)
+    }
+
+    pub fn base_input_type(&self, name: &str) -> Option<BaseInputTypeReference<'a, S::InputType>> {
+        self.base_input_types.get(name).copied()
+    }
+}
@@ -0,0 +1,448 @@
+use crate::executable::document::{RuleRegistry, Severity};
+use bluejay_core::definition::SchemaDefinition;
+use bluejay_core::executable::ExecutableDocument;
+use bluejay_printer::{definition::SchemaDefinitionPrinter, executable::ExecutableDocumentPrinter};
+use std::collections::{BTreeMap, HashMap};
+use std::num::NonZeroUsize;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// A BLAKE3 hash of the printed schema, identifying the schema that cached results were
+/// computed against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SchemaFingerprint([u8; blake3::OUT_LEN]);
+
+impl SchemaFingerprint {
+    pub fn new<S: SchemaDefinition>(schema_definition: &S) -> Self {
+        let mut hasher = blake3::Hasher::new();
+        std::io::Write::write_fmt(
+            &mut hasher,
+            format_args!("{}", SchemaDefinitionPrinter::new(schema_definition)),
+        )
+        .expect("Writing to a hasher cannot fail");
+        Self(hasher.finalize().into())
+    }
+
+    pub fn as_bytes(&self) -> &[u8; blake3::OUT_LEN] {
+        &self.0
+    }
+}
+
+/// Identifies a cached result by schema, document text and operation name.
+///
+/// The document text is hashed rather than using the signature from `bluejay-operation-normalizer`,
+/// because the normalized form drops argument values and aliases, which validation depends on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ValidationCacheKey {
+    schema_fingerprint: SchemaFingerprint,
+    document_hash: [u8; blake3::OUT_LEN],
+}
+
+impl ValidationCacheKey {
+    pub fn new(
+        schema_fingerprint: SchemaFingerprint,
+        document: &str,
+        operation_name: Option<&str>,
+    ) -> Self {
+        Self::build(schema_fingerprint, operation_name, |hasher| {
+            hasher.update(document.as_bytes());
+        })
+    }
+
+    /// Like [`ValidationCacheKey::new`], but hashes the printed form of an already parsed
+    /// document, so documents differing only in formatting share a key.
+    pub fn for_document<E: ExecutableDocument>(
+        schema_fingerprint: SchemaFingerprint,
+        executable_document: &E,
+        operation_name: Option<&str>,
+    ) -> Self {
+        Self::build(schema_fingerprint, operation_name, |hasher| {
+            std::io::Write::write_fmt(
+                hasher,
+                format_args!("{}", ExecutableDocumentPrinter::new(executable_document)),
+            )
+            .expect("Writing to a hasher cannot fail");
+        })
+    }
+
+    fn build(
+        schema_fingerprint: SchemaFingerprint,
+        operation_name: Option<&str>,
+        hash_document: impl FnOnce(&mut blake3::Hasher),
+    ) -> Self {
+        let mut hasher = blake3::Hasher::new();
+        // the operation name is length-prefixed and tagged with its presence, so that neither
+        // `None` and `Some("")` nor different splits between name and document collide
+        match operation_name {
+            Some(operation_name) => {
+                hasher.update(&[1]);
+                update_str(&mut hasher, operation_name);
+            }
+            None => {
+                hasher.update(&[0]);
+            }
+        }
+        hash_document(&mut hasher);
+        Self {
+            schema_fingerprint,
+            document_hash: hasher.finalize().into(),
+        }
+    }
+
+    /// Includes the configuration of `rule_registry` in the key: the enabled rules, their
+    /// severities and the configuration of the opt-in rules. Keys of validation results should
+    /// include it, so that a cache shared by differently configured registries never reports a
+    /// document as valid to a registry that did not validate it.
+    pub fn with_rule_registry(self, rule_registry: &RuleRegistry) -> Self {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&self.document_hash);
+        hasher.update(&(rule_registry.enabled_rules().count() as u64).to_le_bytes());
+        rule_registry.enabled_rules().for_each(|(name, severity)| {
+            update_str(&mut hasher, name);
+            hasher.update(&[match severity {
+                Severity::Error => 0,
+                Severity::Warning => 1,
+            }]);
+        });
+        let mutation_root_field_config = rule_registry.mutation_root_field_config();
+        match mutation_root_field_config.max_root_fields {
+            Some(max_root_fields) => {
+                hasher.update(&[1]);
+                hasher.update(&(max_root_fields as u64).to_le_bytes());
+            }
+            None => {
+                hasher.update(&[0]);
+            }
+        }
+        hasher
+            .update(&(mutation_root_field_config.exclusive_root_fields.len() as u64).to_le_bytes());
+        mutation_root_field_config
+            .exclusive_root_fields
+            .iter()
+            .for_each(|field_name| update_str(&mut hasher, field_name));
+        Self {
+            schema_fingerprint: self.schema_fingerprint,
+            document_hash: hasher.finalize().into(),
+        }
+    }
+
+    pub fn schema_fingerprint(&self) -> SchemaFingerprint {
+        self.schema_fingerprint
+    }
+}
+
+/// A bounded, least-recently-used cache of results that depend only on the document and the
+/// schema, such as document validation errors and the output of document [`Analyzer`]s.
+/// Results that depend on variable values must not be cached here.
+///
+/// Outputs of operation analyzers are not memoized: fields excluded by `@skip` or `@include`
+/// and the values of arguments generally depend on the variable values, and the outputs borrow
+/// from the document.
+///
+/// Entries are keyed by [`ValidationCacheKey`], which includes the [`SchemaFingerprint`], so a
+/// result is never reused for a different schema. [`ValidationCache::retain_schema`] frees the
+/// entries of previous schemas eagerly.
+///
+/// [`Analyzer`]: crate::executable::document::Analyzer
+pub struct ValidationCache<T> {
+    capacity: NonZeroUsize,
+    entries: HashMap<ValidationCacheKey, (T, u64)>,
+    recency: BTreeMap<u64, ValidationCacheKey>,
+    tick: u64,
+}
+
+impl<T> ValidationCache<T> {
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get(&mut self, key: &ValidationCacheKey) -> Option<&T> {
+        let tick = self.next_tick();
+        let (value, last_used) = self.entries.get_mut(key)?;
+        self.recency.remove(last_used);
+        self.recency.insert(tick, *key);
+        *last_used = tick;
+        Some(value)
+    }
+
+    /// Returns the cached result for `key`, computing and inserting it with `f` on a miss.
+    pub fn get_or_insert_with(&mut self, key: ValidationCacheKey, f: impl FnOnce() -> T) -> &T {
+        if !self.entries.contains_key(&key) {
+            self.insert(key, f());
+        }
+        self.get(&key).expect("Entry was just inserted")
+    }
+
+    pub fn insert(&mut self, key: ValidationCacheKey, value: T) {
+        let tick = self.next_tick();
+        if let Some((_, last_used)) = self.entries.insert(key, (value, tick)) {
+            self.recency.remove(&last_used);
+        }
+        self.recency.insert(tick, key);
+
+        while self.entries.len() > self.capacity.get() {
+            let Some((_, least_recently_used)) = self.recency.pop_first() else {
+                break;
+            };
+            self.entries.remove(&least_recently_used);
+        }
+    }
+
+    /// Removes every entry computed against a schema other than `schema_fingerprint`.
+    pub fn retain_schema(&mut self, schema_fingerprint: SchemaFingerprint) {
+        self.entries
+            .retain(|key, _| key.schema_fingerprint == schema_fingerprint);
+        self.recency
+            .retain(|_, key| key.schema_fingerprint == schema_fingerprint);
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
+
+/// Hashes `s` prefixed with its length, so that consecutive strings cannot collide.
+fn update_str(hasher: &mut blake3::Hasher, s: &str) {
+    hasher.update(&(s.len() as u64).to_le_bytes());
+    hasher.update(s.as_bytes());
+}
+
+/// A [`ValidationCache`] behind a [`Mutex`], to share between worker threads. Values are cloned
+/// out of the cache, so large results are best stored behind an [`Arc`](std::sync::Arc).
+pub struct SharedValidationCache<T>(Mutex<ValidationCache<T>>);
+
+impl<T: Clone> SharedValidationCache<T> {
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self(Mutex::new(ValidationCache::new(capacity)))
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    pub fn get(&self, key: &ValidationCacheKey) -> Option<T> {
+        self.lock().get(key).cloned()
+    }
+
+    /// Returns the cached result for `key`, computing and inserting it with `f` on a miss. The
+    /// lock is not held while `f` runs, so concurrent misses for the same key may each run `f`.
+    pub fn get_or_insert_with(&self, key: ValidationCacheKey, f: impl FnOnce() -> T) -> T {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+        let value = f();
+        self.insert(key, value.clone());
+        value
+    }
+
+    pub fn insert(&self, key: ValidationCacheKey, value: T) {
+        self.lock().insert(key, value);
+    }
+
+    /// See [`ValidationCache::retain_schema`].
+    pub fn retain_schema(&self, schema_fingerprint: SchemaFingerprint) {
+        self.lock().retain_schema(schema_fingerprint);
+    }
+
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ValidationCache<T>> {
+        // the cache is left consistent at every await-free step, so a poisoned lock is usable
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SchemaFingerprint, SharedValidationCache, ValidationCache, ValidationCacheKey};
+    use crate::executable::document::{rules::MutationRootFieldConfig, RuleRegistry, Severity};
+    use bluejay_parser::ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        Parse,
+    };
+    use std::num::NonZeroUsize;
+
+    fn fingerprint(sdl: &str) -> SchemaFingerprint {
+        let definition_document: DefinitionDocument = DefinitionDocument::parse(sdl)
+            .result
+            .expect("Schema had parse errors");
+        let schema_definition =
+            SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+        SchemaFingerprint::new(&schema_definition)
+    }
+
+    #[test]
+    fn test_schema_fingerprint() {
+        assert_eq!(
+            fingerprint("type Query { foo: String }"),
+            fingerprint("type Query {\n  foo: String\n}"),
+        );
+        assert_ne!(
+            fingerprint("type Query { foo: String }"),
+            fingerprint("type Query { foo: String @deprecated }"),
+        );
+    }
+
+    #[test]
+    fn test_key_includes_schema_document_and_operation_name() {
+        let schema = fingerprint("type Query { foo: String }");
+        let other_schema = fingerprint("type Query { foo: Int }");
+        let key = ValidationCacheKey::new(schema, "{ foo }", None);
+
+        assert_eq!(key, ValidationCacheKey::new(schema, "{ foo }", None));
+        assert_ne!(key, ValidationCacheKey::new(other_schema, "{ foo }", None));
+        assert_ne!(key, ValidationCacheKey::new(schema, "{ foo foo }", None));
+        assert_ne!(key, ValidationCacheKey::new(schema, "{ foo }", Some("Foo")));
+        assert_ne!(key, ValidationCacheKey::new(schema, "{ foo }", Some("")));
+        assert_ne!(
+            ValidationCacheKey::new(schema, "b", Some("a")),
+            ValidationCacheKey::new(schema, "", Some("ab")),
+        );
+    }
+
+    #[test]
+    fn test_key_with_rule_registry() {
+        let schema = fingerprint("type Query { foo: String }");
+        let key = ValidationCacheKey::new(schema, "{ foo }", None);
+        let with_registry = |registry: &RuleRegistry| {
+            ValidationCacheKey::new(schema, "{ foo }", None).with_rule_registry(registry)
+        };
+
+        let builtin = RuleRegistry::builtin();
+        let mut with_warning = RuleRegistry::builtin();
+        with_warning
+            .enable("AllVariablesUsed", Severity::Warning)
+            .unwrap();
+        let mut with_opt_in_rule = RuleRegistry::builtin();
+        with_opt_in_rule
+            .enable("MutationRootFields", Severity::Error)
+            .unwrap();
+        let mut with_config = with_opt_in_rule.clone();
+        with_config.set_mutation_root_field_config(MutationRootFieldConfig {
+            max_root_fields: Some(1),
+            exclusive_root_fields: Vec::new(),
+        });
+
+        assert_eq!(
+            with_registry(&builtin),
+            with_registry(&RuleRegistry::default())
+        );
+        assert_ne!(key, with_registry(&builtin));
+        assert_ne!(
+            with_registry(&builtin),
+            with_registry(&RuleRegistry::empty())
+        );
+        assert_ne!(with_registry(&builtin), with_registry(&with_warning));
+        assert_ne!(with_registry(&builtin), with_registry(&with_opt_in_rule));
+        assert_ne!(
+            with_registry(&with_opt_in_rule),
+            with_registry(&with_config)
+        );
+    }
+
+    #[test]
+    fn test_key_for_document_ignores_formatting() {
+        let schema = fingerprint("type Query { foo: String }");
+        let parse = |source| {
+            bluejay_parser::ast::executable::ExecutableDocument::parse(source)
+                .result
+                .expect("Document had parse errors")
+        };
+
+        assert_eq!(
+            ValidationCacheKey::for_document(schema, &parse("{ foo }"), None),
+            ValidationCacheKey::for_document(schema, &parse("query {\n  foo\n}"), None),
+        );
+        assert_ne!(
+            ValidationCacheKey::for_document(schema, &parse("{ foo }"), None),
+            ValidationCacheKey::for_document(schema, &parse("{ bar: foo }"), None),
+        );
+    }
+
+    #[test]
+    fn test_shared_validation_cache() {
+        let schema = fingerprint("type Query { foo: String }");
+        let cache = SharedValidationCache::new(NonZeroUsize::new(2).unwrap());
+        let key = ValidationCacheKey::new(schema, "{ foo }", None);
+
+        std::thread::scope(|scope| {
+            (0..4).for_each(|_| {
+                scope.spawn(|| assert_eq!(1, cache.get_or_insert_with(key, || 1)));
+            });
+        });
+        assert_eq!(Some(1), cache.get(&key));
+        assert_eq!(1, cache.len());
+    }
+
+    #[test]
+    fn test_get_or_insert_with() {
+        let schema = fingerprint("type Query { foo: String }");
+        let mut cache = ValidationCache::new(NonZeroUsize::new(2).unwrap());
+        let key = ValidationCacheKey::new(schema, "{ foo }", None);
+
+        assert_eq!(&1, cache.get_or_insert_with(key, || 1));
+        assert_eq!(&1, cache.get_or_insert_with(key, || unreachable!()));
+        assert_eq!(1, cache.len());
+    }
+
+    #[test]
+    fn test_least_recently_used_eviction() {
+        let schema = fingerprint("type Query { foo: String }");
+        let mut cache = ValidationCache::new(NonZeroUsize::new(2).unwrap());
+        let a = ValidationCacheKey::new(schema, "{ a }", None);
+        let b = ValidationCacheKey::new(schema, "{ b }", None);
+        let c = ValidationCacheKey::new(schema, "{ c }", None);
+
+        cache.insert(a, "a");
+        cache.insert(b, "b");
+        assert_eq!(Some(&"a"), cache.get(&a));
+        cache.insert(c, "c");
+
+        assert_eq!(2, cache.len());
+        assert_eq!(Some(&"a"), cache.get(&a));
+        assert_eq!(None, cache.get(&b));
+        assert_eq!(Some(&"c"), cache.get(&c));
+    }
+
+    #[test]
+    fn test_retain_schema() {
+        let old_schema = fingerprint("type Query { foo: String }");
+        let new_schema = fingerprint("type Query { foo: String bar: String }");
+        let mut cache = ValidationCache::new(NonZeroUsize::new(10).unwrap());
+        let old_key = ValidationCacheKey::new(old_schema, "{ foo }", None);
+        let new_key = ValidationCacheKey::new(new_schema, "{ foo }", None);
+
+        cache.insert(old_key, false);
+        assert_eq!(None, cache.get(&new_key));
+        cache.insert(new_key, true);
+        cache.retain_schema(new_schema);
+
+        assert_eq!(1, cache.len());
+        assert_eq!(None, cache.get(&old_key));
+        assert_eq!(Some(&true), cache.get(&new_key));
+    }
+}
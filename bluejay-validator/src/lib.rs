@@ -5,4 +5,4 @@ mod path;
 pub mod utils;
 pub mod value;
 
-pub use path::{Path, PathElement};
+pub use path::{JsonPath, JsonPathSegment, Path, PathElement};
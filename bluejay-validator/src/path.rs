@@ -1,3 +1,5 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
 use std::rc::Rc;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -97,9 +99,154 @@ impl<'a> Path<'a> {
     }
 }
 
+/// An owned path into a response or an input value, made up of object keys and list indexes.
+/// Serializes the same way as the `path` entry of a GraphQL error, e.g. `["products", 0, "title"]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(transparent))]
+pub struct JsonPath(Vec<JsonPathSegment>);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
+pub enum JsonPathSegment {
+    Key(String),
+    Index(usize),
+}
+
+impl JsonPath {
+    pub fn new(segment: impl Into<JsonPathSegment>) -> Self {
+        Self(vec![segment.into()])
+    }
+
+    pub fn push(&mut self, segment: impl Into<JsonPathSegment>) {
+        self.0.push(segment.into());
+    }
+
+    pub fn pop(&mut self) -> Option<JsonPathSegment> {
+        self.0.pop()
+    }
+
+    pub fn with(&self, segment: impl Into<JsonPathSegment>) -> Self {
+        let mut path = self.clone();
+        path.push(segment);
+        path
+    }
+
+    /// Returns a new path with `suffix` appended to this path.
+    pub fn join(&self, suffix: &Self) -> Self {
+        Self(self.0.iter().chain(&suffix.0).cloned().collect())
+    }
+
+    pub fn segments(&self) -> &[JsonPathSegment] {
+        &self.0
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, JsonPathSegment> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<&str> for JsonPathSegment {
+    fn from(s: &str) -> Self {
+        Self::Key(s.to_owned())
+    }
+}
+
+impl From<String> for JsonPathSegment {
+    fn from(s: String) -> Self {
+        Self::Key(s)
+    }
+}
+
+impl From<usize> for JsonPathSegment {
+    fn from(i: usize) -> Self {
+        Self::Index(i)
+    }
+}
+
+impl From<PathElement<'_>> for JsonPathSegment {
+    fn from(value: PathElement<'_>) -> Self {
+        match value {
+            PathElement::Key(s) => Self::Key(s.to_owned()),
+            PathElement::Index(i) => Self::Index(i),
+        }
+    }
+}
+
+impl std::fmt::Display for JsonPathSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Key(s) => write!(f, "{s}"),
+            Self::Index(i) => write!(f, "{i}"),
+        }
+    }
+}
+
+/// Formats the path with its segments separated by `.`, e.g. `products.0.title`.
+impl std::fmt::Display for JsonPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, segment) in self.0.iter().enumerate() {
+            if idx != 0 {
+                write!(f, ".")?;
+            }
+            write!(f, "{segment}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&Path<'_>> for JsonPath {
+    fn from(value: &Path<'_>) -> Self {
+        Self(value.to_vec())
+    }
+}
+
+impl From<Path<'_>> for JsonPath {
+    fn from(value: Path<'_>) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl FromIterator<JsonPathSegment> for JsonPath {
+    fn from_iter<T: IntoIterator<Item = JsonPathSegment>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<'a> IntoIterator for &'a JsonPath {
+    type Item = &'a JsonPathSegment;
+    type IntoIter = std::slice::Iter<'a, JsonPathSegment>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl From<&JsonPath> for serde_json::Value {
+    fn from(value: &JsonPath) -> Self {
+        Self::Array(
+            value
+                .iter()
+                .map(|segment| match segment {
+                    JsonPathSegment::Key(key) => Self::String(key.clone()),
+                    JsonPathSegment::Index(idx) => Self::from(*idx),
+                })
+                .collect(),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{Path, PathElement};
+    use super::{JsonPath, JsonPathSegment, Path, PathElement};
 
     #[test]
     fn test_len() {
@@ -127,4 +274,43 @@ mod tests {
             Path::new("key").push("nested_key"),
         );
     }
+
+    #[test]
+    fn test_json_path_from_path() {
+        assert_eq!(JsonPath::default(), JsonPath::from(Path::default()));
+        assert_eq!(
+            JsonPath::from_iter([
+                JsonPathSegment::Key("key".to_owned()),
+                JsonPathSegment::Index(0),
+            ]),
+            JsonPath::from(Path::new("key").push(0)),
+        );
+    }
+
+    #[test]
+    fn test_json_path_display() {
+        assert_eq!("", JsonPath::default().to_string());
+        assert_eq!(
+            "products.0.title",
+            JsonPath::new("products").with(0).with("title").to_string(),
+        );
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_json_path_to_json() {
+        assert_eq!(
+            serde_json::json!(["products", 0, "title"]),
+            serde_json::Value::from(&JsonPath::new("products").with(0).with("title")),
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_path_serialize() {
+        assert_eq!(
+            serde_json::json!(["products", 0, "title"]),
+            serde_json::to_value(JsonPath::new("products").with(0).with("title")).unwrap(),
+        );
+    }
 }
@@ -1,6 +1,7 @@
 use itertools::Itertools;
-use std::cmp::{Eq, Ord};
+use std::cmp::{Eq, Ord, Ordering};
 use std::collections::BTreeMap;
+use std::fmt::Display;
 use std::hash::Hash;
 
 pub fn duplicates<T: Copy, I: Iterator<Item = T>, K: Hash + Ord + Eq + Copy>(
@@ -36,3 +37,264 @@ pub fn duplicates<T: Copy, I: Iterator<Item = T>, K: Hash + Ord + Eq + Copy>(
         .collect::<Vec<_>>()
         .into_iter()
 }
+
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Given an invalid input string and a list of valid options, returns the options
+/// that are close enough to the input to be worth suggesting, ordered by similarity.
+/// Mirrors `suggestionList` from graphql-js.
+pub fn suggestion_list<'a>(
+    input: &str,
+    options: impl IntoIterator<Item = &'a str>,
+) -> Vec<&'a str> {
+    let lexical_distance = LexicalDistance::new(input);
+    let threshold = input.chars().count() * 2 / 5 + 1;
+
+    let mut options_with_distance: Vec<(&'a str, usize)> = options
+        .into_iter()
+        .unique()
+        .filter_map(|option| {
+            lexical_distance
+                .measure(option, threshold)
+                .map(|distance| (option, distance))
+        })
+        .collect();
+
+    options_with_distance.sort_by(|(a, a_distance), (b, b_distance)| {
+        a_distance
+            .cmp(b_distance)
+            .then_with(|| natural_compare(a, b))
+    });
+
+    options_with_distance
+        .into_iter()
+        .map(|(option, _)| option)
+        .take(MAX_SUGGESTIONS)
+        .collect()
+}
+
+/// Formats suggestions as a sentence to append to an error message,
+/// e.g. ``Did you mean `a`, `b`, or `c`?``
+pub fn did_you_mean<T: Display>(suggestions: &[T]) -> Option<String> {
+    let quoted: Vec<String> = suggestions.iter().map(|s| format!("`{s}`")).collect();
+    match quoted.as_slice() {
+        [] => None,
+        [only] => Some(format!("Did you mean {only}?")),
+        [first, second] => Some(format!("Did you mean {first} or {second}?")),
+        [init @ .., last] => Some(format!("Did you mean {}, or {last}?", init.join(", "))),
+    }
+}
+
+/// Computes the lexical distance between strings A and B.
+///
+/// The "distance" between two strings is given by counting the minimum number
+/// of edits needed to transform string A into string B. An edit can be an
+/// insertion, deletion, or substitution of a single character, or a swap of two
+/// adjacent characters (optimal string alignment distance).
+///
+/// Includes a custom alteration from Damerau-Levenshtein to treat case changes
+/// as a single edit which helps identify mis-cased values with an edit distance of 1.
+struct LexicalDistance<'a> {
+    input: &'a str,
+    input_lower_case: String,
+    input_chars: Vec<char>,
+}
+
+impl<'a> LexicalDistance<'a> {
+    fn new(input: &'a str) -> Self {
+        let input_lower_case = input.to_lowercase();
+        let input_chars = input_lower_case.chars().collect();
+        Self {
+            input,
+            input_lower_case,
+            input_chars,
+        }
+    }
+
+    fn measure(&self, option: &str, threshold: usize) -> Option<usize> {
+        if self.input == option {
+            return Some(0);
+        }
+
+        let option_lower_case = option.to_lowercase();
+
+        // Any case change counts as a single edit
+        if self.input_lower_case == option_lower_case {
+            return Some(1);
+        }
+
+        let option_chars: Vec<char> = option_lower_case.chars().collect();
+        let (a, b) = if option_chars.len() < self.input_chars.len() {
+            (self.input_chars.as_slice(), option_chars.as_slice())
+        } else {
+            (option_chars.as_slice(), self.input_chars.as_slice())
+        };
+
+        if a.len() - b.len() > threshold {
+            return None;
+        }
+
+        let mut rows = [
+            vec![0; b.len() + 1],
+            vec![0; b.len() + 1],
+            vec![0; b.len() + 1],
+        ];
+        rows[0]
+            .iter_mut()
+            .enumerate()
+            .for_each(|(j, cell)| *cell = j);
+
+        for i in 1..=a.len() {
+            let mut smallest_cell = i;
+            rows[i % 3][0] = i;
+
+            for j in 1..=b.len() {
+                let cost = usize::from(a[i - 1] != b[j - 1]);
+
+                let mut current_cell = (rows[(i - 1) % 3][j] + 1)
+                    .min(rows[i % 3][j - 1] + 1)
+                    .min(rows[(i - 1) % 3][j - 1] + cost);
+
+                if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                    // transposition
+                    current_cell = current_cell.min(rows[(i - 2) % 3][j - 2] + 1);
+                }
+
+                smallest_cell = smallest_cell.min(current_cell);
+                rows[i % 3][j] = current_cell;
+            }
+
+            // Early exit, since distance can't go smaller than smallest element of the previous row.
+            if smallest_cell > threshold {
+                return None;
+            }
+        }
+
+        let distance = rows[a.len() % 3][b.len()];
+        (distance <= threshold).then_some(distance)
+    }
+}
+
+/// Compares strings so that runs of digits are ordered by their numeric value,
+/// e.g. `field2` sorts before `field10`.
+fn natural_compare(a: &str, b: &str) -> Ordering {
+    let mut a_chars = a.chars().peekable();
+    let mut b_chars = b.chars().peekable();
+
+    loop {
+        match (a_chars.peek().copied(), b_chars.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a_char), Some(b_char)) if a_char.is_ascii_digit() && b_char.is_ascii_digit() => {
+                let a_digits: String = a_chars.peeking_take_while(char::is_ascii_digit).collect();
+                let b_digits: String = b_chars.peeking_take_while(char::is_ascii_digit).collect();
+                let a_trimmed = a_digits.trim_start_matches('0');
+                let b_trimmed = b_digits.trim_start_matches('0');
+                let ordering = a_trimmed
+                    .len()
+                    .cmp(&b_trimmed.len())
+                    .then_with(|| a_trimmed.cmp(b_trimmed));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(a_char), Some(b_char)) => {
+                if a_char != b_char {
+                    return a_char.cmp(&b_char);
+                }
+                a_chars.next();
+                b_chars.next();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{did_you_mean, suggestion_list};
+
+    #[test]
+    fn test_suggestion_list_returns_results_when_input_is_empty() {
+        assert_eq!(suggestion_list("", ["a"]), vec!["a"]);
+    }
+
+    #[test]
+    fn test_suggestion_list_returns_empty_array_when_there_are_no_options() {
+        assert_eq!(suggestion_list("input", []), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_suggestion_list_returns_options_with_small_lexical_distance() {
+        assert_eq!(suggestion_list("greenish", ["green"]), vec!["green"]);
+        assert_eq!(suggestion_list("green", ["greenish"]), vec!["greenish"]);
+    }
+
+    #[test]
+    fn test_suggestion_list_rejects_options_with_distance_that_exceeds_threshold() {
+        assert_eq!(suggestion_list("aaaa", ["aaab"]), vec!["aaab"]);
+        assert_eq!(suggestion_list("aaaa", ["aabb"]), vec!["aabb"]);
+        assert_eq!(suggestion_list("aaaa", ["abbb"]), Vec::<&str>::new());
+        assert_eq!(suggestion_list("ab", ["ca"]), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_suggestion_list_returns_options_with_different_case() {
+        assert_eq!(
+            suggestion_list("verylongstring", ["VERYLONGSTRING"]),
+            vec!["VERYLONGSTRING"]
+        );
+        assert_eq!(
+            suggestion_list("VERYLONGSTRING", ["verylongstring"]),
+            vec!["verylongstring"]
+        );
+        assert_eq!(
+            suggestion_list("VERYLONGSTRING", ["VeryLongString"]),
+            vec!["VeryLongString"]
+        );
+    }
+
+    #[test]
+    fn test_suggestion_list_returns_options_with_transpositions() {
+        assert_eq!(suggestion_list("agr", ["arg"]), vec!["arg"]);
+        assert_eq!(
+            suggestion_list("214365879", ["123456789"]),
+            vec!["123456789"]
+        );
+    }
+
+    #[test]
+    fn test_suggestion_list_returns_options_sorted_based_on_lexical_distance() {
+        assert_eq!(
+            suggestion_list("abc", ["a", "ab", "abc"]),
+            vec!["abc", "ab", "a"],
+        );
+    }
+
+    #[test]
+    fn test_suggestion_list_returns_options_with_the_same_lexical_distance_sorted_naturally() {
+        assert_eq!(suggestion_list("a", ["b", "a", "c"]), vec!["a", "b", "c"]);
+        assert_eq!(
+            suggestion_list("GraphQl", ["graphics", "SQL", "GraphQL", "quarks", "mark"]),
+            vec!["GraphQL", "graphics"],
+        );
+        assert_eq!(
+            suggestion_list("field", ["field10", "field2", "field1"]),
+            vec!["field1", "field2", "field10"],
+        );
+    }
+
+    #[test]
+    fn test_did_you_mean() {
+        assert_eq!(did_you_mean::<&str>(&[]), None);
+        assert_eq!(did_you_mean(&["a"]).as_deref(), Some("Did you mean `a`?"));
+        assert_eq!(
+            did_you_mean(&["a", "b"]).as_deref(),
+            Some("Did you mean `a` or `b`?"),
+        );
+        assert_eq!(
+            did_you_mean(&["a", "b", "c"]).as_deref(),
+            Some("Did you mean `a`, `b`, or `c`?"),
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Path;
+use crate::{JsonPath, Path};
 use bluejay_core::definition::{
     BaseInputTypeReference, EnumTypeDefinition, EnumValueDefinition, InputFieldsDefinition,
     InputObjectTypeDefinition, InputType, InputTypeReference, InputValueDefinition,
@@ -9,8 +9,10 @@ use bluejay_core::{
 };
 use std::collections::BTreeMap;
 
+mod coerced_value;
 mod error;
 
+pub use coerced_value::CoercedValue;
 pub use error::Error;
 
 pub trait CoerceInput: SchemaDefinition {
@@ -46,6 +48,25 @@ pub trait CoerceInput: SchemaDefinition {
     ) -> Result<(), Vec<Error<'a, true, V>>> {
         self.coerce_value(input_type, value, path)
     }
+
+    /// Coerces a constant value like [`CoerceInput::coerce_const_value`], returning the coerced
+    /// value on success: input object fields with default values are filled in, single values
+    /// are wrapped into lists, integers are converted to floats and IDs, and strings to enum
+    /// values.
+    fn coerce_const_value_to_owned<
+        'a,
+        I: InputType<
+            CustomScalarTypeDefinition = Self::CustomScalarTypeDefinition,
+            InputObjectTypeDefinition = Self::InputObjectTypeDefinition,
+            EnumTypeDefinition = Self::EnumTypeDefinition,
+        >,
+        V: Value<true>,
+    >(
+        &'a self,
+        input_type: &'a I,
+        value: &'a V,
+        path: Path<'a>,
+    ) -> Result<CoercedValue, Vec<Error<'a, true, V>>>;
 }
 
 impl<S: SchemaDefinition> CoerceInput for S {
@@ -64,13 +85,38 @@ impl<S: SchemaDefinition> CoerceInput for S {
         value: &'a V,
         path: Path<'a>,
     ) -> Result<(), Vec<Error<'a, CONST, V>>> {
-        coerce_value_for_input_type(self, input_type, value, path, true)
+        coerce_value_for_input_type::<CONST, false, _, _, _>(self, input_type, value, path, true)
+            .map(|_| ())
+    }
+
+    fn coerce_const_value_to_owned<
+        'a,
+        I: InputType<
+            CustomScalarTypeDefinition = Self::CustomScalarTypeDefinition,
+            InputObjectTypeDefinition = Self::InputObjectTypeDefinition,
+            EnumTypeDefinition = Self::EnumTypeDefinition,
+        >,
+        V: Value<true>,
+    >(
+        &'a self,
+        input_type: &'a I,
+        value: &'a V,
+        path: Path<'a>,
+    ) -> Result<CoercedValue, Vec<Error<'a, true, V>>> {
+        coerce_value_for_input_type::<true, true, _, _, _>(self, input_type, value, path, true)
+            .map(|coerced| coerced.unwrap_or(CoercedValue::Null))
     }
 }
 
+/// The result of coercing a value. When `BUILD` is false, only validity is checked and the
+/// coerced value is always `None`, so that validation does not pay for building values.
+type CoercionResult<'a, const CONST: bool, V> =
+    Result<Option<CoercedValue>, Vec<Error<'a, CONST, V>>>;
+
 fn coerce_value_for_input_type<
     'a,
     const CONST: bool,
+    const BUILD: bool,
     S: SchemaDefinition,
     T: InputType<
         CustomScalarTypeDefinition = S::CustomScalarTypeDefinition,
@@ -84,50 +130,64 @@ fn coerce_value_for_input_type<
     value: &'a V,
     path: Path<'a>,
     allow_implicit_list: bool,
-) -> Result<(), Vec<Error<'a, CONST, V>>> {
+) -> CoercionResult<'a, CONST, V> {
     let core_type = input_type.as_ref(schema_definition);
     let is_required = core_type.is_required();
     match value.as_ref() {
         ValueReference::Null if is_required => Err(vec![Error::NullValueForRequiredType {
             value,
             input_type_name: input_type.display_name(),
-            path,
+            path: path.into(),
         }]),
-        ValueReference::Null | ValueReference::Variable(_) => Ok(()),
+        ValueReference::Null => Ok(BUILD.then_some(CoercedValue::Null)),
+        ValueReference::Variable(_) => Ok(None),
         core_value => match core_type {
             InputTypeReference::Base(_, _) => {
-                coerce_value_for_base_input_type(schema_definition, input_type, value, path)
+                coerce_value_for_base_input_type::<CONST, BUILD, _, _, _>(
+                    schema_definition,
+                    input_type,
+                    value,
+                    path,
+                )
             }
             InputTypeReference::List(inner, _) => {
                 if let ValueReference::List(values) = core_value {
-                    let errors: Vec<Error<'a, CONST, V>> = values
-                        .iter()
-                        .enumerate()
-                        .flat_map(|(idx, value)| {
-                            coerce_value_for_input_type(
-                                schema_definition,
-                                inner,
-                                value,
-                                path.push(idx),
-                                false,
-                            )
-                            .err()
-                            .unwrap_or_default()
-                        })
-                        .collect();
+                    let mut errors: Vec<Error<'a, CONST, V>> = Vec::new();
+                    let mut coerced_values = Vec::new();
+                    values.iter().enumerate().for_each(|(idx, value)| {
+                        match coerce_value_for_input_type::<CONST, BUILD, _, _, _>(
+                            schema_definition,
+                            inner,
+                            value,
+                            path.push(idx),
+                            false,
+                        ) {
+                            Ok(coerced_value) => coerced_values.extend(coerced_value),
+                            Err(errs) => errors.extend(errs),
+                        }
+                    });
 
                     if errors.is_empty() {
-                        Ok(())
+                        Ok(BUILD.then_some(CoercedValue::List(coerced_values)))
                     } else {
                         Err(errors)
                     }
                 } else if allow_implicit_list {
-                    coerce_value_for_input_type(schema_definition, inner, value, path, true)
+                    coerce_value_for_input_type::<CONST, BUILD, _, _, _>(
+                        schema_definition,
+                        inner,
+                        value,
+                        path,
+                        true,
+                    )
+                    .map(|coerced_value| {
+                        coerced_value.map(|coerced_value| CoercedValue::List(vec![coerced_value]))
+                    })
                 } else {
                     Err(vec![Error::NoImplicitConversion {
                         value,
                         input_type_name: input_type.display_name(),
-                        path,
+                        path: path.into(),
                     }])
                 }
             }
@@ -138,6 +198,7 @@ fn coerce_value_for_input_type<
 fn coerce_value_for_base_input_type<
     'a,
     const CONST: bool,
+    const BUILD: bool,
     S: SchemaDefinition,
     T: InputType<
         CustomScalarTypeDefinition = S::CustomScalarTypeDefinition,
@@ -150,98 +211,126 @@ fn coerce_value_for_base_input_type<
     input_type: &'a T,
     value: &'a V,
     path: Path<'a>,
-) -> Result<(), Vec<Error<'a, CONST, V>>> {
+) -> CoercionResult<'a, CONST, V> {
     let base = input_type.base(schema_definition);
     match base {
         BaseInputTypeReference::BuiltinScalar(bstd) => {
-            coerce_builtin_scalar_value(input_type, bstd, value, path)
+            coerce_builtin_scalar_value::<CONST, BUILD, _, _>(input_type, bstd, value, path)
+        }
+        BaseInputTypeReference::CustomScalar(cstd) => {
+            coerce_custom_scalar_value::<CONST, BUILD, _>(cstd, value, path)
+        }
+        BaseInputTypeReference::Enum(etd) => {
+            coerce_enum_value::<CONST, BUILD, _, _>(input_type, etd, value, path)
         }
-        BaseInputTypeReference::CustomScalar(cstd) => coerce_custom_scalar_value(cstd, value, path),
-        BaseInputTypeReference::Enum(etd) => coerce_enum_value(input_type, etd, value, path),
         BaseInputTypeReference::InputObject(iotd) => {
-            coerce_input_object_value(schema_definition, input_type, iotd, value, path)
+            coerce_input_object_value::<CONST, BUILD, _, _, _>(
+                schema_definition,
+                input_type,
+                iotd,
+                value,
+                path,
+            )
         }
     }
 }
 
-fn coerce_builtin_scalar_value<'a, const CONST: bool, V: Value<CONST>, T: InputType>(
+fn coerce_builtin_scalar_value<
+    'a,
+    const CONST: bool,
+    const BUILD: bool,
+    V: Value<CONST>,
+    T: InputType,
+>(
     input_type: &'a T,
     bstd: BuiltinScalarDefinition,
     value: &'a V,
     path: Path<'a>,
-) -> Result<(), Vec<Error<'a, CONST, V>>> {
+) -> CoercionResult<'a, CONST, V> {
     match (bstd, value.as_ref()) {
-        (BuiltinScalarDefinition::Boolean, ValueReference::Boolean(_)) => Ok(()),
-        (BuiltinScalarDefinition::Float, ValueReference::Float(_)) => Ok(()),
-        (BuiltinScalarDefinition::Float, ValueReference::Integer(_)) => Ok(()),
-        (BuiltinScalarDefinition::ID, ValueReference::Integer(_)) => Ok(()),
+        (BuiltinScalarDefinition::Boolean, ValueReference::Boolean(b)) => {
+            Ok(BUILD.then_some(CoercedValue::Boolean(b)))
+        }
+        (BuiltinScalarDefinition::Float, ValueReference::Float(f)) => {
+            Ok(BUILD.then_some(CoercedValue::Float(f)))
+        }
+        (BuiltinScalarDefinition::Float, ValueReference::Integer(i)) => {
+            Ok(BUILD.then_some(CoercedValue::Float(i.into())))
+        }
+        (BuiltinScalarDefinition::ID, ValueReference::Integer(i)) => {
+            Ok(BUILD.then(|| CoercedValue::String(i.to_string())))
+        }
         (
             BuiltinScalarDefinition::ID | BuiltinScalarDefinition::String,
-            ValueReference::String(_),
-        ) => Ok(()),
-        (BuiltinScalarDefinition::Int, ValueReference::Integer(_)) => Ok(()),
+            ValueReference::String(s),
+        ) => Ok(BUILD.then(|| CoercedValue::String(s.to_owned()))),
+        (BuiltinScalarDefinition::Int, ValueReference::Integer(i)) => {
+            Ok(BUILD.then_some(CoercedValue::Int(i)))
+        }
         _ => Err(vec![Error::NoImplicitConversion {
             value,
             input_type_name: input_type.display_name(),
-            path,
+            path: path.into(),
         }]),
     }
 }
 
-fn coerce_custom_scalar_value<'a, const CONST: bool, V: Value<CONST>>(
+fn coerce_custom_scalar_value<'a, const CONST: bool, const BUILD: bool, V: Value<CONST>>(
     cstd: &'a impl ScalarTypeDefinition,
     value: &'a V,
     path: Path<'a>,
-) -> Result<(), Vec<Error<'a, CONST, V>>> {
-    cstd.coerce_input(value).map_err(|message| {
-        vec![Error::CustomScalarInvalidValue {
-            value,
-            custom_scalar_type_name: cstd.name(),
-            message,
-            path,
-        }]
-    })
+) -> CoercionResult<'a, CONST, V> {
+    cstd.coerce_input(value)
+        .map(|_| BUILD.then(|| CoercedValue::from_value(value)))
+        .map_err(|message| {
+            vec![Error::CustomScalarInvalidValue {
+                value,
+                custom_scalar_type_name: cstd.name(),
+                message,
+                path: path.into(),
+            }]
+        })
 }
 
-fn coerce_enum_value<'a, const CONST: bool, V: Value<CONST>, T: InputType>(
+fn coerce_enum_value<'a, const CONST: bool, const BUILD: bool, V: Value<CONST>, T: InputType>(
     input_type: &'a T,
     enum_type_definition: &'a T::EnumTypeDefinition,
     value: &'a V,
     path: Path<'a>,
-) -> Result<(), Vec<Error<'a, CONST, V>>> {
+) -> CoercionResult<'a, CONST, V> {
     match value.as_ref() {
         ValueReference::Enum(name) => {
-            coerce_enum_value_from_name(enum_type_definition, value, name, path)
+            coerce_enum_value_from_name::<CONST, BUILD, _>(enum_type_definition, value, name, path)
         }
         ValueReference::String(name) if V::can_coerce_string_value_to_enum() => {
-            coerce_enum_value_from_name(enum_type_definition, value, name, path)
+            coerce_enum_value_from_name::<CONST, BUILD, _>(enum_type_definition, value, name, path)
         }
         _ => Err(vec![Error::NoImplicitConversion {
             value,
             input_type_name: input_type.display_name(),
-            path,
+            path: path.into(),
         }]),
     }
 }
 
-fn coerce_enum_value_from_name<'a, const CONST: bool, V: Value<CONST>>(
+fn coerce_enum_value_from_name<'a, const CONST: bool, const BUILD: bool, V: Value<CONST>>(
     enum_type_definition: &'a impl EnumTypeDefinition,
     value: &'a V,
     name: &'a str,
     path: Path<'a>,
-) -> Result<(), Vec<Error<'a, CONST, V>>> {
+) -> CoercionResult<'a, CONST, V> {
     if enum_type_definition
         .enum_value_definitions()
         .iter()
         .any(|evd| evd.name() == name)
     {
-        Ok(())
+        Ok(BUILD.then(|| CoercedValue::Enum(name.to_owned())))
     } else {
         Err(vec![Error::NoEnumMemberWithName {
             name,
             value,
             enum_type_name: enum_type_definition.name(),
-            path,
+            path: path.into(),
         }])
     }
 }
@@ -249,6 +338,7 @@ fn coerce_enum_value_from_name<'a, const CONST: bool, V: Value<CONST>>(
 fn coerce_input_object_value<
     'a,
     const CONST: bool,
+    const BUILD: bool,
     S: SchemaDefinition,
     T: InputType<
         CustomScalarTypeDefinition = S::CustomScalarTypeDefinition,
@@ -262,10 +352,11 @@ fn coerce_input_object_value<
     input_object_type_definition: &'a T::InputObjectTypeDefinition,
     value: &'a V,
     path: Path<'a>,
-) -> Result<(), Vec<Error<'a, CONST, V>>> {
+) -> CoercionResult<'a, CONST, V> {
     if let ValueReference::Object(object) = value.as_ref() {
         let mut errors = Vec::new();
         let mut missing_required_values = Vec::new();
+        let mut coerced_fields = Vec::new();
 
         type Entry<'a, const CONST: bool, V> = (
             &'a <<V as Value<CONST>>::Object as ObjectValue<CONST>>::Key,
@@ -287,7 +378,7 @@ fn coerce_input_object_value<
                     value,
                     field_name,
                     keys: Vec::from_iter(entries.iter().map(|&(key, _)| key)),
-                    path: path.clone(),
+                    path: JsonPath::from(&path),
                 }),
         );
 
@@ -303,7 +394,7 @@ fn coerce_input_object_value<
                 .map(|(field, _)| Error::NoInputFieldWithName {
                     field,
                     input_object_type_name: input_object_type_definition.name(),
-                    path: path.push(field.as_ref()),
+                    path: path.push(field.as_ref()).into(),
                 }),
         );
 
@@ -322,14 +413,34 @@ fn coerce_input_object_value<
                             missing_required_values.push(ivd.name());
                         }
                     }
-                    (None, Some(_)) => {}
+                    (None, Some(default_value)) => {
+                        if BUILD {
+                            let coerced_default_value =
+                                coerce_value_for_input_type::<true, true, _, _, _>(
+                                    schema_definition,
+                                    ivd.r#type(),
+                                    default_value,
+                                    path.push(ivd.name()),
+                                    true,
+                                )
+                                .ok()
+                                .flatten()
+                                .unwrap_or_else(|| CoercedValue::from_value(default_value));
+                            coerced_fields.push((ivd.name().to_owned(), coerced_default_value));
+                        }
+                    }
                     (Some(value), _) => {
-                        match schema_definition.coerce_value(
+                        match coerce_value_for_input_type::<CONST, BUILD, _, _, _>(
+                            schema_definition,
                             ivd.r#type(),
                             value,
                             path.push(ivd.name()),
+                            true,
                         ) {
-                            Ok(_) => {}
+                            Ok(coerced_value) => coerced_fields.extend(
+                                coerced_value
+                                    .map(|coerced_value| (ivd.name().to_owned(), coerced_value)),
+                            ),
                             Err(errs) => errors.extend(errs),
                         }
                     }
@@ -351,12 +462,12 @@ fn coerce_input_object_value<
                 value,
                 field_names: missing_required_values,
                 input_object_type_name: input_object_type_definition.name(),
-                path,
+                path: path.into(),
             });
         }
 
         if errors.is_empty() {
-            Ok(())
+            Ok(BUILD.then_some(CoercedValue::Object(coerced_fields)))
         } else {
             Err(errors)
         }
@@ -364,7 +475,7 @@ fn coerce_input_object_value<
         Err(vec![Error::NoImplicitConversion {
             value,
             input_type_name: input_type.display_name(),
-            path,
+            path: path.into(),
         }])
     }
 }
@@ -396,7 +507,7 @@ fn validate_one_of_input_object_value<'a, const CONST: bool, V: Value<CONST>>(
                 value,
                 input_object_type_name: input_object_type_definition.name(),
                 null_entries,
-                path: path.clone(),
+                path: JsonPath::from(&path),
             });
         }
 
@@ -405,7 +516,7 @@ fn validate_one_of_input_object_value<'a, const CONST: bool, V: Value<CONST>>(
                 value,
                 input_object_type_name: input_object_type_definition.name(),
                 non_null_entries,
-                path,
+                path: path.into(),
             });
         }
 
@@ -422,7 +533,7 @@ fn validate_one_of_input_object_value<'a, const CONST: bool, V: Value<CONST>>(
 #[cfg(test)]
 mod tests {
     use super::{CoerceInput, Error};
-    use crate::Path;
+    use crate::JsonPath;
     use bluejay_core::definition::{
         ArgumentsDefinition, FieldDefinition, FieldsDefinition, InputType, InputValueDefinition,
         ObjectTypeDefinition, ScalarTypeDefinition, SchemaDefinition,
@@ -701,12 +812,12 @@ mod tests {
                 Error::NoImplicitConversion {
                     value: &json!("b"),
                     input_type_name: "Int".to_string(),
-                    path: Path::new(1),
+                    path: JsonPath::new(1),
                 },
                 Error::NoImplicitConversion {
                     value: &json!(true),
                     input_type_name: "Int".to_string(),
-                    path: Path::new(2),
+                    path: JsonPath::new(2),
                 },
             ]),
             SCHEMA_DEFINITION.coerce_const_value(it, &json!([1, "b", true]), Default::default()),
@@ -734,17 +845,17 @@ mod tests {
                 Error::NoImplicitConversion {
                     value: &json!(1),
                     input_type_name: "[Int]".to_string(),
-                    path: Path::new(0),
+                    path: JsonPath::new(0),
                 },
                 Error::NoImplicitConversion {
                     value: &json!(2),
                     input_type_name: "[Int]".to_string(),
-                    path: Path::new(1),
+                    path: JsonPath::new(1),
                 },
                 Error::NoImplicitConversion {
                     value: &json!(3),
                     input_type_name: "[Int]".to_string(),
-                    path: Path::new(2),
+                    path: JsonPath::new(2),
                 },
             ]),
             SCHEMA_DEFINITION.coerce_const_value(it, &json!([1, 2, 3]), Default::default()),
@@ -814,7 +925,7 @@ mod tests {
             Err(vec![Error::NoInputFieldWithName {
                 field: &"notDefined".to_owned(),
                 input_object_type_name: "CustomInput",
-                path: Path::new("notDefined"),
+                path: JsonPath::new("notDefined"),
             }]),
             SCHEMA_DEFINITION.coerce_const_value(
                 it,
@@ -826,7 +937,7 @@ mod tests {
             Err(vec![Error::NullValueForRequiredType {
                 value: &json!(null),
                 input_type_name: "String!".to_owned(),
-                path: Path::new("stringArgWithDefault"),
+                path: JsonPath::new("stringArgWithDefault"),
             }]),
             SCHEMA_DEFINITION.coerce_const_value(
                 it,
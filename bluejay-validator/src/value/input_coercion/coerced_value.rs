@@ -0,0 +1,77 @@
+use bluejay_core::{AsIter, ObjectValue, Value, ValueReference};
+
+/// An owned value produced by input coercion, see
+/// [`CoerceInput::coerce_const_value_to_owned`](super::CoerceInput::coerce_const_value_to_owned).
+///
+/// Input object fields are in the order of their definitions in the schema.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CoercedValue {
+    Null,
+    Int(i32),
+    Float(f64),
+    String(String),
+    Boolean(bool),
+    Enum(String),
+    List(Vec<CoercedValue>),
+    Object(Vec<(String, CoercedValue)>),
+}
+
+impl CoercedValue {
+    /// Converts a value as-is, without coercing it to any input type. Variables are converted
+    /// to null, as they have no value of their own.
+    pub(crate) fn from_value<const CONST: bool, V: Value<CONST>>(value: &V) -> Self {
+        match value.as_ref() {
+            ValueReference::Variable(_) | ValueReference::Null => Self::Null,
+            ValueReference::Integer(i) => Self::Int(i),
+            ValueReference::Float(f) => Self::Float(f),
+            ValueReference::String(s) => Self::String(s.to_owned()),
+            ValueReference::Boolean(b) => Self::Boolean(b),
+            ValueReference::Enum(e) => Self::Enum(e.to_owned()),
+            ValueReference::List(list) => Self::List(list.iter().map(Self::from_value).collect()),
+            ValueReference::Object(object) => Self::Object(
+                object
+                    .iter()
+                    .map(|(key, value)| (key.as_ref().to_owned(), Self::from_value(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl Value<true> for CoercedValue {
+    type List = Vec<Self>;
+    type Object = Vec<(String, Self)>;
+    type Variable = String;
+
+    fn as_ref(&self) -> ValueReference<'_, true, Self> {
+        match self {
+            Self::Null => ValueReference::Null,
+            Self::Int(i) => ValueReference::Integer(*i),
+            Self::Float(f) => ValueReference::Float(*f),
+            Self::String(s) => ValueReference::String(s),
+            Self::Boolean(b) => ValueReference::Boolean(*b),
+            Self::Enum(e) => ValueReference::Enum(e),
+            Self::List(l) => ValueReference::List(l),
+            Self::Object(o) => ValueReference::Object(o),
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl From<CoercedValue> for serde_json::Value {
+    fn from(value: CoercedValue) -> Self {
+        match value {
+            CoercedValue::Null => Self::Null,
+            CoercedValue::Int(i) => Self::from(i),
+            CoercedValue::Float(f) => Self::from(f),
+            CoercedValue::String(s) | CoercedValue::Enum(s) => Self::String(s),
+            CoercedValue::Boolean(b) => Self::Bool(b),
+            CoercedValue::List(l) => Self::Array(l.into_iter().map(Self::from).collect()),
+            CoercedValue::Object(o) => Self::Object(
+                o.into_iter()
+                    .map(|(key, value)| (key, Self::from(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
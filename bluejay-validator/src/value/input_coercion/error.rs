@@ -1,5 +1,5 @@
-use crate::Path;
-use bluejay_core::{ObjectValue, Value};
+use crate::JsonPath;
+use bluejay_core::{ErrorCode, ObjectValue, Value};
 #[cfg(feature = "parser-integration")]
 use bluejay_parser::{
     ast::Value as ParserValue,
@@ -15,59 +15,75 @@ pub enum Error<'a, const CONST: bool, V: Value<CONST>> {
     NullValueForRequiredType {
         value: &'a V,
         input_type_name: String,
-        path: Path<'a>,
+        path: JsonPath,
     },
     NoImplicitConversion {
         value: &'a V,
         input_type_name: String,
-        path: Path<'a>,
+        path: JsonPath,
     },
     NoEnumMemberWithName {
         name: &'a str,
         value: &'a V,
         enum_type_name: &'a str,
-        path: Path<'a>,
+        path: JsonPath,
     },
     NoValueForRequiredFields {
         value: &'a V,
         field_names: Vec<&'a str>,
         input_object_type_name: &'a str,
-        path: Path<'a>,
+        path: JsonPath,
     },
     NonUniqueFieldNames {
         value: &'a V,
         field_name: &'a str,
         keys: Vec<&'a <V::Object as ObjectValue<CONST>>::Key>,
-        path: Path<'a>,
+        path: JsonPath,
     },
     NoInputFieldWithName {
         field: &'a <V::Object as ObjectValue<CONST>>::Key,
         input_object_type_name: &'a str,
-        path: Path<'a>,
+        path: JsonPath,
     },
     CustomScalarInvalidValue {
         value: &'a V,
         custom_scalar_type_name: &'a str,
         message: Cow<'static, str>,
-        path: Path<'a>,
+        path: JsonPath,
     },
     #[cfg(feature = "one-of-input-objects")]
     OneOfInputNullValues {
         value: &'a V,
         input_object_type_name: &'a str,
         null_entries: Vec<(&'a <V::Object as ObjectValue<CONST>>::Key, &'a V)>,
-        path: Path<'a>,
+        path: JsonPath,
     },
     #[cfg(feature = "one-of-input-objects")]
     OneOfInputNotSingleNonNullValue {
         value: &'a V,
         input_object_type_name: &'a str,
         non_null_entries: Vec<(&'a <V::Object as ObjectValue<CONST>>::Key, &'a V)>,
-        path: Path<'a>,
+        path: JsonPath,
     },
 }
 
 impl<const CONST: bool, V: Value<CONST>> Error<'_, CONST, V> {
+    /// Path to the invalid value, relative to the value being coerced.
+    pub fn path(&self) -> &JsonPath {
+        match self {
+            Self::NullValueForRequiredType { path, .. }
+            | Self::NoImplicitConversion { path, .. }
+            | Self::NoEnumMemberWithName { path, .. }
+            | Self::NoValueForRequiredFields { path, .. }
+            | Self::NonUniqueFieldNames { path, .. }
+            | Self::NoInputFieldWithName { path, .. }
+            | Self::CustomScalarInvalidValue { path, .. } => path,
+            #[cfg(feature = "one-of-input-objects")]
+            Self::OneOfInputNullValues { path, .. }
+            | Self::OneOfInputNotSingleNonNullValue { path, .. } => path,
+        }
+    }
+
     pub fn message(&self) -> Cow<'static, str> {
         match self {
             Self::NullValueForRequiredType { input_type_name, .. } => {
@@ -117,9 +133,16 @@ impl<const CONST: bool, V: Value<CONST>> Error<'_, CONST, V> {
     }
 }
 
+impl<const CONST: bool, V: Value<CONST>> ErrorCode for Error<'_, CONST, V> {
+    fn code(&self) -> &'static str {
+        "GRAPHQL_VALIDATION_FAILED/ValuesOfCorrectType"
+    }
+}
+
 #[cfg(feature = "parser-integration")]
-impl<'a, const CONST: bool> From<Error<'a, CONST, ParserValue<'a, CONST>>> for ParserError {
-    fn from(error: Error<'a, CONST, ParserValue<'a, CONST>>) -> Self {
+impl<'a, 'b: 'a, const CONST: bool> From<Error<'a, CONST, ParserValue<'b, CONST>>> for ParserError {
+    fn from(error: Error<'a, CONST, ParserValue<'b, CONST>>) -> Self {
+        let code = error.code();
         match &error {
             Error::NullValueForRequiredType { value, .. } => Self::new(
                 error.message(),
@@ -225,5 +248,6 @@ impl<'a, const CONST: bool> From<Error<'a, CONST, ParserValue<'a, CONST>>> for P
                     .collect(),
             ),
         }
+        .with_code(code)
     }
 }
@@ -0,0 +1,115 @@
+use bluejay_parser::{
+    ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    },
+    Error,
+};
+use bluejay_validator::executable::{
+    document::{BatchValidator, BuiltinRulesValidator, RuleRegistry, Severity},
+    Cache,
+};
+use std::num::NonZeroUsize;
+
+fn read_test_data(directory: &str) -> Vec<String> {
+    let mut paths: Vec<_> = std::fs::read_dir(directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "graphql")
+        })
+        .collect();
+    paths.sort();
+    paths
+        .into_iter()
+        .map(|path| std::fs::read_to_string(path).unwrap())
+        .collect()
+}
+
+fn with_schema(f: fn(SchemaDefinition) -> ()) {
+    let s = std::fs::read_to_string("tests/test_data/executable/schema.graphql").unwrap();
+    let definition_document = DefinitionDocument::parse(s.as_str())
+        .result
+        .expect("Schema had parse errors");
+    let schema_definition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+    f(schema_definition)
+}
+
+#[test]
+fn test_batch_matches_sequential_validation() {
+    with_schema(|schema_definition| {
+        let sources: Vec<String> = read_test_data("tests/test_data/executable/error")
+            .into_iter()
+            .chain(read_test_data("tests/test_data/executable/valid"))
+            .collect();
+        let documents: Vec<ExecutableDocument> = sources
+            .iter()
+            .map(|source| {
+                ExecutableDocument::parse(source)
+                    .result
+                    .expect("Document had parse errors")
+            })
+            .collect();
+
+        let expected: Vec<String> = sources
+            .iter()
+            .zip(&documents)
+            .map(|(source, document)| {
+                let cache = Cache::new(document, &schema_definition);
+                Error::format_errors(
+                    source,
+                    None,
+                    BuiltinRulesValidator::validate(document, &schema_definition, &cache),
+                )
+            })
+            .collect();
+
+        let batch_validator = BatchValidator::new(&schema_definition)
+            .with_worker_count(NonZeroUsize::new(4).unwrap());
+        let actual: Vec<String> = batch_validator.validate(&documents, |document, report| {
+            assert!(report.warnings.is_empty());
+            let index = documents
+                .iter()
+                .position(|d| std::ptr::eq(d, document))
+                .unwrap();
+            Error::format_errors(&sources[index], None, report.errors)
+        });
+
+        assert_eq!(expected, actual);
+    });
+}
+
+#[test]
+fn test_batch_uses_rule_registry() {
+    with_schema(|schema_definition| {
+        let documents: Vec<ExecutableDocument> = [
+            "query { dog { name } }",
+            "query { dog { name } } fragment unused on Dog { name }",
+            "query { dog { nmae } }",
+        ]
+        .into_iter()
+        .map(|source| {
+            ExecutableDocument::parse(source)
+                .result
+                .expect("Document had parse errors")
+        })
+        .collect();
+
+        let mut rule_registry = RuleRegistry::default();
+        rule_registry
+            .enable("FragmentsMustBeUsed", Severity::Warning)
+            .unwrap();
+
+        let batch_validator = BatchValidator::new(&schema_definition)
+            .with_rule_registry(rule_registry)
+            .with_worker_count(NonZeroUsize::new(2).unwrap());
+        let counts = batch_validator.validate(&documents, |_, report| {
+            (report.errors.len(), report.warnings.len())
+        });
+
+        assert_eq!(vec![(0, 0), (0, 1), (1, 0)], counts);
+    });
+}
@@ -1,3 +1,4 @@
+use bluejay_core::ErrorCode;
 use bluejay_parser::{
     ast::{
         definition::{DefinitionDocument, SchemaDefinition},
@@ -12,23 +13,41 @@ use bluejay_validator::executable::{document::BuiltinRulesValidator, Cache};
 fn test_error() {
     with_schema(|schema_definition| {
         insta::glob!("test_data/executable/error/*.graphql", |path| {
-            let input = std::fs::read_to_string(path).unwrap();
-            let executable_document = ExecutableDocument::parse(input.as_str())
-                .result
-                .expect("Document had parse errors");
-            let cache = Cache::new(&executable_document, &schema_definition);
-            let errors =
-                BuiltinRulesValidator::validate(&executable_document, &schema_definition, &cache);
-            let formatted_errors = Error::format_errors(
-                input.as_str(),
-                path.file_name().and_then(|f| f.to_str()),
-                errors,
-            );
+            let formatted_errors = format_errors(&schema_definition, path);
             insta::assert_snapshot!(formatted_errors);
         });
     });
 }
 
+/// The `@defer` and `@stream` rules are only builtin with the `incremental-delivery` feature.
+#[cfg(feature = "incremental-delivery")]
+#[test]
+fn test_incremental_delivery_error() {
+    with_schema(|schema_definition| {
+        insta::glob!(
+            "test_data/executable/error_incremental_delivery/*.graphql",
+            |path| {
+                let formatted_errors = format_errors(&schema_definition, path);
+                insta::assert_snapshot!(formatted_errors);
+            }
+        );
+    });
+}
+
+fn format_errors(schema_definition: &SchemaDefinition, path: &std::path::Path) -> String {
+    let input = std::fs::read_to_string(path).unwrap();
+    let executable_document = ExecutableDocument::parse(input.as_str())
+        .result
+        .expect("Document had parse errors");
+    let cache = Cache::new(&executable_document, schema_definition);
+    let errors = BuiltinRulesValidator::validate(&executable_document, schema_definition, &cache);
+    Error::format_errors(
+        input.as_str(),
+        path.file_name().and_then(|f| f.to_str()),
+        errors,
+    )
+}
+
 #[test]
 fn test_valid() {
     with_schema(|schema_definition| {
@@ -55,6 +74,42 @@ fn test_valid() {
     });
 }
 
+#[test]
+fn test_error_codes() {
+    with_schema(|schema_definition| {
+        let input = r#"
+            query {
+                dog {
+                    nmae
+                    doesKnowCommand(dogCommand: SIT, command: SIT)
+                    ...undefinedFragment
+                }
+            }
+        "#;
+        let executable_document = ExecutableDocument::parse(input)
+            .result
+            .expect("Document had parse errors");
+        let cache = Cache::new(&executable_document, &schema_definition);
+        let errors: Vec<_> =
+            BuiltinRulesValidator::validate(&executable_document, &schema_definition, &cache)
+                .collect();
+        let mut codes: Vec<&str> = errors.iter().map(ErrorCode::code).collect();
+        codes.sort_unstable();
+        assert_eq!(
+            codes,
+            vec![
+                "GRAPHQL_VALIDATION_FAILED/FieldsOnCorrectType",
+                "GRAPHQL_VALIDATION_FAILED/KnownArgumentNames",
+                "GRAPHQL_VALIDATION_FAILED/KnownFragmentNames",
+            ],
+        );
+
+        assert!(errors.into_iter().map(Error::from).all(|error| error
+            .code()
+            .is_some_and(|code| code.starts_with("GRAPHQL_VALIDATION_FAILED/"))));
+    });
+}
+
 fn with_schema(f: fn(SchemaDefinition) -> ()) {
     let s = std::fs::read_to_string("tests/test_data/executable/schema.graphql").unwrap();
     let definition_document = DefinitionDocument::parse(s.as_str())
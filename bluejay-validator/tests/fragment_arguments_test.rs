@@ -0,0 +1,362 @@
+use bluejay_core::ErrorCode;
+use bluejay_parser::{
+    ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse, ParseOptions,
+    },
+    Error,
+};
+use bluejay_validator::executable::{
+    document::BuiltinRulesValidator,
+    operation::{
+        analyzers::QueryDepth,
+        execution_plan::{ExecutionPlan, ExecutionPlanError},
+        OperationResolutionError, Orchestrator,
+    },
+    Cache,
+};
+
+const SCHEMA: &str = r#"
+type Query {
+  user(id: ID!): User
+}
+
+type User {
+  id: ID!
+  avatar(size: Int!): String!
+  friends(first: Int): [User!]!
+}
+"#;
+
+fn validate(document: &str) -> (Vec<&'static str>, String) {
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(SCHEMA)
+        .result
+        .expect("Schema had parse errors");
+    let schema_definition: SchemaDefinition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+    let executable_document = ExecutableDocument::parse_with_options(
+        document,
+        ParseOptions {
+            fragment_arguments: true,
+            ..Default::default()
+        },
+    )
+    .result
+    .expect("Document had parse errors");
+    let cache = Cache::new(&executable_document, &schema_definition);
+    let errors: Vec<_> =
+        BuiltinRulesValidator::validate(&executable_document, &schema_definition, &cache).collect();
+    let codes = errors.iter().map(ErrorCode::code).collect();
+    let messages = Error::format_errors(document, None, errors);
+
+    (codes, messages)
+}
+
+#[test]
+fn test_valid_fragment_arguments() {
+    let (codes, messages) = validate(
+        r#"
+        query($id: ID!, $size: Int!) {
+          user(id: $id) {
+            ...Avatar(size: $size)
+            friends { ...Avatar }
+          }
+        }
+
+        fragment Avatar($size: Int! = 50) on User {
+          avatar(size: $size)
+        }
+        "#,
+    );
+    assert!(
+        codes.is_empty(),
+        "Document had validation errors:\n{messages}"
+    );
+}
+
+#[test]
+fn test_fragment_variables_are_scoped_to_the_fragment() {
+    // `$size` of the operation is unused, as `$size` within the fragment is the fragment's own
+    let (codes, _) = validate(
+        r#"
+        query($size: Int!) {
+          user(id: "1") { ...Avatar(size: 100) }
+        }
+
+        fragment Avatar($size: Int!) on User {
+          avatar(size: $size)
+        }
+        "#,
+    );
+    assert_eq!(vec!["GRAPHQL_VALIDATION_FAILED/NoUnusedVariables"], codes);
+
+    // the fragment's `$size` is not a variable of the operation
+    let (codes, _) = validate(
+        r#"
+        query {
+          user(id: "1") { ...Avatar(size: $size) }
+        }
+
+        fragment Avatar($size: Int!) on User {
+          avatar(size: $size)
+        }
+        "#,
+    );
+    assert_eq!(
+        vec!["GRAPHQL_VALIDATION_FAILED/NoUndefinedVariables"],
+        codes
+    );
+}
+
+#[test]
+fn test_unused_fragment_variable() {
+    let (codes, messages) = validate(
+        r#"
+        query {
+          user(id: "1") { ...Avatar }
+        }
+
+        fragment Avatar($size: Int, $unused: Boolean) on User {
+          avatar(size: 10)
+          friends(first: $size) { id }
+        }
+        "#,
+    );
+    assert_eq!(vec!["GRAPHQL_VALIDATION_FAILED/NoUnusedVariables"], codes);
+    assert!(messages.contains("$unused"), "{messages}");
+}
+
+#[test]
+fn test_fragment_variable_usages_allowed() {
+    // a nullable fragment variable without a default cannot be used for a required argument
+    let (codes, _) = validate(
+        r#"
+        query {
+          user(id: "1") { ...Avatar(size: 10) }
+        }
+
+        fragment Avatar($size: Int) on User {
+          avatar(size: $size)
+        }
+        "#,
+    );
+    assert_eq!(
+        vec!["GRAPHQL_VALIDATION_FAILED/VariablesInAllowedPosition"],
+        codes,
+    );
+
+    // an operation variable passed to a spread must be compatible with the fragment variable
+    let (codes, messages) = validate(
+        r#"
+        query($size: String!, $optionalSize: Int) {
+          user(id: "1") {
+            ...Avatar(size: $size)
+            friends { ...Avatar(size: $optionalSize) }
+          }
+        }
+
+        fragment Avatar($size: Int!) on User {
+          avatar(size: $size)
+        }
+        "#,
+    );
+    assert_eq!(
+        vec![
+            "GRAPHQL_VALIDATION_FAILED/VariablesInAllowedPosition",
+            "GRAPHQL_VALIDATION_FAILED/VariablesInAllowedPosition",
+        ],
+        codes,
+        "{messages}",
+    );
+}
+
+#[test]
+fn test_fragment_variable_definitions_are_validated() {
+    let (mut codes, _) = validate(
+        r#"
+        query {
+          user(id: "1") { ...Avatar }
+        }
+
+        fragment Avatar($size: Int! = "large", $size: Int! = 1) on User {
+          avatar(size: $size)
+        }
+        "#,
+    );
+    codes.sort_unstable();
+    assert_eq!(
+        vec![
+            "GRAPHQL_VALIDATION_FAILED/UniqueVariableNames",
+            "GRAPHQL_VALIDATION_FAILED/ValuesOfCorrectType",
+        ],
+        codes,
+    );
+}
+
+#[test]
+fn test_field_selection_merging_with_fragment_arguments() {
+    let (codes, _) = validate(
+        r#"
+        query {
+          user(id: "1") {
+            ...Avatar(size: 10)
+            ...Avatar(size: 10)
+          }
+        }
+
+        fragment Avatar($size: Int!) on User {
+          avatar(size: $size)
+        }
+        "#,
+    );
+    assert!(codes.is_empty());
+
+    let (codes, _) = validate(
+        r#"
+        query {
+          user(id: "1") {
+            ...Avatar(size: 10)
+            ...Avatar(size: 20)
+          }
+        }
+
+        fragment Avatar($size: Int!) on User {
+          avatar(size: $size)
+        }
+        "#,
+    );
+    assert_eq!(
+        vec!["GRAPHQL_VALIDATION_FAILED/OverlappingFieldsCanBeMerged"],
+        codes,
+    );
+
+    // the same argument text refers to different values inside and outside of the fragment
+    let (codes, _) = validate(
+        r#"
+        query($size: Int!) {
+          user(id: "1") {
+            avatar(size: $size)
+            ...Avatar(size: 10)
+          }
+        }
+
+        fragment Avatar($size: Int!) on User {
+          avatar(size: $size)
+        }
+        "#,
+    );
+    assert_eq!(
+        vec!["GRAPHQL_VALIDATION_FAILED/OverlappingFieldsCanBeMerged"],
+        codes,
+    );
+}
+
+#[test]
+fn test_fragment_spread_arguments_are_validated() {
+    let fragment = r#"
+        fragment Avatar($size: Int!) on User {
+          avatar(size: $size)
+        }
+    "#;
+    let validate_spread = |spread: &str| {
+        validate(&format!(
+            r#"query {{ user(id: "1") {{ {spread} }} }} {fragment}"#
+        ))
+    };
+
+    let (codes, messages) = validate_spread(r#"...Avatar(size: "big")"#);
+    assert_eq!(
+        vec!["GRAPHQL_VALIDATION_FAILED/ValuesOfCorrectType"],
+        codes,
+        "{messages}",
+    );
+
+    let (codes, messages) = validate_spread("...Avatar(sise: 1)");
+    assert_eq!(
+        vec![
+            "GRAPHQL_VALIDATION_FAILED/KnownArgumentNames",
+            "GRAPHQL_VALIDATION_FAILED/ProvidedRequiredArguments",
+        ],
+        codes,
+        "{messages}",
+    );
+    assert!(
+        messages.contains("Fragment `Avatar` does not define a variable named `sise`"),
+        "{messages}",
+    );
+
+    let (codes, messages) = validate_spread("...Avatar");
+    assert_eq!(
+        vec!["GRAPHQL_VALIDATION_FAILED/ProvidedRequiredArguments"],
+        codes,
+        "{messages}",
+    );
+    assert!(
+        messages.contains("Fragment spread `Avatar` missing argument(s): size"),
+        "{messages}",
+    );
+
+    let (codes, messages) = validate_spread("...Avatar(size: 1, size: 2)");
+    assert_eq!(
+        vec!["GRAPHQL_VALIDATION_FAILED/UniqueArgumentNames"],
+        codes,
+        "{messages}",
+    );
+}
+
+#[test]
+fn test_operations_with_fragment_variables_are_not_analyzed_or_planned() {
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(SCHEMA)
+        .result
+        .expect("Schema had parse errors");
+    let schema_definition: SchemaDefinition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+    let executable_document = ExecutableDocument::parse_with_options(
+        r#"
+        query { user(id: "1") { ...Avatar(size: 10) } }
+
+        fragment Avatar($size: Int!) on User {
+          avatar(size: $size)
+        }
+        "#,
+        ParseOptions {
+            fragment_arguments: true,
+            ..Default::default()
+        },
+    )
+    .result
+    .expect("Document had parse errors");
+    let cache = Cache::new(&executable_document, &schema_definition);
+    let variables = serde_json::Map::new();
+    let is_rejected = |error: &OperationResolutionError| {
+        matches!(
+            error,
+            OperationResolutionError::FragmentVariablesNotSupported { fragment_name }
+                if fragment_name == "Avatar"
+        )
+    };
+
+    let analyzed = Orchestrator::<_, _, _, QueryDepth>::analyze(
+        &executable_document,
+        &schema_definition,
+        None,
+        &variables,
+        &cache,
+        (),
+    );
+    assert!(analyzed.is_err_and(|error| is_rejected(&error)));
+
+    let planned = ExecutionPlan::new(
+        &executable_document,
+        &schema_definition,
+        None,
+        &variables,
+        &cache,
+    );
+    assert!(matches!(
+        planned,
+        Err(ExecutionPlanError::OperationResolution(error))
+            if is_rejected(&error)
+    ));
+}
@@ -0,0 +1,175 @@
+use bluejay_core::ErrorCode;
+use bluejay_parser::{
+    ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    },
+    Error,
+};
+use bluejay_validator::executable::{
+    document::{
+        rules::{MutationRootFieldConfig, MutationRootFieldPolicy, MutationRootFields},
+        Orchestrator, RuleRegistry, Severity,
+    },
+    Cache,
+};
+
+const SCHEMA: &str = r#"
+type Query {
+  foo: String!
+}
+
+type Mutation {
+  createFoo: String!
+  updateFoo: String!
+  deleteAllFoos: Boolean!
+}
+"#;
+
+#[derive(Default)]
+struct TestPolicy;
+
+impl MutationRootFieldPolicy for TestPolicy {
+    fn max_root_fields(&self) -> Option<usize> {
+        Some(2)
+    }
+
+    fn is_exclusive_root_field(&self, field_name: &str) -> bool {
+        field_name == "deleteAllFoos"
+    }
+}
+
+type MutationRootFieldsValidator<'a, E, S> =
+    Orchestrator<'a, E, S, MutationRootFields<'a, E, S, TestPolicy>>;
+
+fn parse_schema<T>(f: impl FnOnce(&SchemaDefinition) -> T) -> T {
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(SCHEMA)
+        .result
+        .expect("Schema had parse errors");
+    let schema_definition: SchemaDefinition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+    f(&schema_definition)
+}
+
+fn validate(document: &str) -> (Vec<&'static str>, String) {
+    parse_schema(|schema_definition| {
+        let executable_document = ExecutableDocument::parse(document)
+            .result
+            .expect("Document had parse errors");
+        let cache = Cache::new(&executable_document, schema_definition);
+        let errors: Vec<_> =
+            MutationRootFieldsValidator::validate(&executable_document, schema_definition, &cache)
+                .collect();
+        let codes = errors.iter().map(ErrorCode::code).collect();
+        let messages = Error::format_errors(document, None, errors);
+
+        (codes, messages)
+    })
+}
+
+#[test]
+fn test_valid_mutations() {
+    let (codes, messages) = validate(
+        r#"
+        mutation { createFoo updateFoo }
+        mutation DeleteAll { deleteAllFoos }
+        mutation Aliased { createFoo createFoo ...Create }
+        fragment Create on Mutation { createFoo }
+        "#,
+    );
+    assert!(
+        codes.is_empty(),
+        "Document had validation errors:\n{messages}"
+    );
+}
+
+#[test]
+fn test_root_field_limit() {
+    let (codes, _) = validate(
+        r#"
+        mutation TooMany { createFoo ...Update }
+        fragment Update on Mutation { updateFoo other: updateFoo }
+        "#,
+    );
+    assert_eq!(
+        vec!["GRAPHQL_VALIDATION_FAILED/MutationRootFieldLimit"],
+        codes
+    );
+}
+
+#[test]
+fn test_exclusive_root_field() {
+    let (codes, _) = validate(
+        r#"
+        mutation NotExclusive { createFoo ... on Mutation { deleteAllFoos } }
+        mutation DeleteAllTwice { deleteAllFoos again: deleteAllFoos }
+        "#,
+    );
+    assert_eq!(
+        vec![
+            "GRAPHQL_VALIDATION_FAILED/ExclusiveMutationRootField",
+            "GRAPHQL_VALIDATION_FAILED/ExclusiveMutationRootField",
+            "GRAPHQL_VALIDATION_FAILED/ExclusiveMutationRootField",
+        ],
+        codes,
+    );
+}
+
+#[test]
+fn test_queries_are_ignored() {
+    let (codes, _) = validate("{ a: foo b: foo c: foo }");
+    assert!(codes.is_empty());
+}
+
+#[test]
+fn test_exclusive_root_field_in_shared_fragment_is_reported_once() {
+    let (codes, _) = validate(
+        r#"
+        mutation A { createFoo ...DeleteAll }
+        mutation B { updateFoo ...DeleteAll ... on Mutation { ...DeleteAll } }
+        fragment DeleteAll on Mutation { deleteAllFoos }
+        "#,
+    );
+    assert_eq!(
+        vec!["GRAPHQL_VALIDATION_FAILED/ExclusiveMutationRootField"],
+        codes,
+    );
+}
+
+#[test]
+fn test_runtime_config_in_rule_registry() {
+    let document = "mutation { createFoo updateFoo deleteAllFoos }";
+    let validate_with = |registry: &RuleRegistry| {
+        parse_schema(|schema_definition| {
+            let executable_document = ExecutableDocument::parse(document)
+                .result
+                .expect("Document had parse errors");
+            let cache = Cache::new(&executable_document, schema_definition);
+            registry
+                .validate(&executable_document, schema_definition, &cache)
+                .warnings
+                .iter()
+                .map(ErrorCode::code)
+                .collect::<Vec<_>>()
+        })
+    };
+
+    let mut registry = RuleRegistry::empty();
+    registry
+        .enable("MutationRootFields", Severity::Warning)
+        .unwrap();
+    assert!(validate_with(&registry).is_empty());
+
+    registry.set_mutation_root_field_config(MutationRootFieldConfig {
+        max_root_fields: Some(2),
+        exclusive_root_fields: vec!["deleteAllFoos".to_owned()],
+    });
+    assert_eq!(
+        vec![
+            "GRAPHQL_VALIDATION_FAILED/MutationRootFieldLimit",
+            "GRAPHQL_VALIDATION_FAILED/ExclusiveMutationRootField",
+        ],
+        validate_with(&registry),
+    );
+}
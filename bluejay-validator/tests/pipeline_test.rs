@@ -0,0 +1,297 @@
+use bluejay_core::ErrorCode;
+use bluejay_parser::ast::{
+    definition::{DefinitionDocument, SchemaDefinition},
+    executable::ExecutableDocument,
+    Parse,
+};
+use bluejay_validator::executable::{
+    document::{RuleRegistry, Severity},
+    operation::{
+        analyzers::{ComplexityCost, Deprecation, QueryDepth},
+        OperationResolutionError, Orchestrator,
+    },
+    Cache, Pipeline, PipelineResult,
+};
+use serde_json::{json, Map, Value};
+
+const SCHEMA: &str = r#"
+type Query {
+  foo(arg: String): String!
+  legacy: String @deprecated(reason: "Use foo")
+  nested: Query!
+}
+"#;
+
+type Analyzers<'a, E, S> = (
+    QueryDepth,
+    ComplexityCost<'a, E, S, Map<String, Value>>,
+    Deprecation<'a, E, S, Map<String, Value>>,
+);
+
+fn with_pipeline(registry: RuleRegistry, f: impl FnOnce(&Pipeline<SchemaDefinition>)) {
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(SCHEMA)
+        .result
+        .expect("Schema had parse errors");
+    let schema_definition: SchemaDefinition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+    f(&Pipeline::new(&schema_definition).with_rule_registry(registry))
+}
+
+fn parse(source: &str) -> ExecutableDocument {
+    ExecutableDocument::parse(source)
+        .result
+        .expect("Document had parse errors")
+}
+
+#[test]
+fn test_valid_document_is_analyzed() {
+    with_pipeline(RuleRegistry::default(), |pipeline| {
+        let executable_document =
+            parse("query Q($arg: String) { foo(arg: $arg) nested { legacy } }");
+        let variables = json!({ "arg": "x" });
+        let (errors, warnings, output) = pipeline.run(&executable_document, |run| {
+            let result = run.analyze::<_, Analyzers<_, _>>(
+                Some("Q"),
+                variables.as_object().unwrap(),
+                ((), (), ()),
+            );
+            (
+                result.errors().len(),
+                result.warnings().len(),
+                result
+                    .into_output()
+                    .map(|(depth, complexity_cost, deprecations)| {
+                        (
+                            depth,
+                            complexity_cost,
+                            deprecations
+                                .into_iter()
+                                .map(|offender| {
+                                    (offender.name.to_owned(), offender.reason.to_owned())
+                                })
+                                .collect::<Vec<_>>(),
+                        )
+                    }),
+            )
+        });
+
+        assert_eq!((0, 0), (errors, warnings));
+        assert_eq!(
+            Some((2, 3, vec![("legacy".to_owned(), "Use foo".to_owned())])),
+            output,
+        );
+    });
+}
+
+#[test]
+fn test_analysis_matches_orchestrator() {
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(SCHEMA)
+        .result
+        .expect("Schema had parse errors");
+    let schema_definition: SchemaDefinition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+    let executable_document = parse(
+        r#"
+        query A($skip: Boolean!) {
+          nested {
+            ...Fields
+            legacy @skip(if: $skip)
+            ... on Query { nested { nested { foo } } }
+          }
+        }
+        query B { legacy }
+        fragment Fields on Query { nested { legacy foo(arg: "x") } }
+        "#,
+    );
+    let pipeline = Pipeline::new(&schema_definition);
+
+    [json!({ "skip": true }), json!({ "skip": false })]
+        .iter()
+        .for_each(|variables| {
+            let variables = variables.as_object().unwrap();
+            pipeline.run(&executable_document, |run| {
+                let from_pipeline = run
+                    .analyze::<_, Analyzers<_, _>>(Some("A"), variables, ((), (), ()))
+                    .into_output();
+                let cache = Cache::new(&executable_document, &schema_definition);
+                let from_orchestrator = Orchestrator::<_, _, _, Analyzers<_, _>>::analyze(
+                    &executable_document,
+                    &schema_definition,
+                    Some("A"),
+                    variables,
+                    &cache,
+                    ((), (), ()),
+                )
+                .ok();
+
+                assert!(from_pipeline.is_some());
+                assert_eq!(
+                    from_orchestrator, from_pipeline,
+                    "for variables: {variables:?}"
+                );
+            });
+        });
+}
+
+#[test]
+fn test_invalid_document_is_not_analyzed() {
+    with_pipeline(RuleRegistry::default(), |pipeline| {
+        let executable_document = parse("{ foo bar }");
+        let variables = Map::new();
+        let (invalid, analyzed, codes) = pipeline.run(&executable_document, |run| {
+            let result = run.analyze::<_, Analyzers<_, _>>(None, &variables, ((), (), ()));
+            (
+                matches!(result, PipelineResult::Invalid(_)),
+                result.output().is_some(),
+                result
+                    .errors()
+                    .iter()
+                    .map(ErrorCode::code)
+                    .collect::<Vec<_>>(),
+            )
+        });
+
+        assert!(invalid);
+        assert!(!analyzed);
+        assert_eq!(vec!["GRAPHQL_VALIDATION_FAILED/FieldsOnCorrectType"], codes);
+    });
+}
+
+#[test]
+fn test_warnings_do_not_prevent_analysis() {
+    let mut registry = RuleRegistry::default();
+    registry
+        .enable("AllVariablesUsed", Severity::Warning)
+        .unwrap();
+
+    with_pipeline(registry, |pipeline| {
+        let executable_document = parse("query($unused: String) { foo }");
+        let variables = Map::new();
+        let (output, errors, warnings) = pipeline.run(&executable_document, |run| {
+            let result = run.analyze::<_, QueryDepth>(None, &variables, ());
+            (
+                result.output().copied(),
+                result.errors().len(),
+                result
+                    .warnings()
+                    .iter()
+                    .map(ErrorCode::code)
+                    .collect::<Vec<_>>(),
+            )
+        });
+
+        assert_eq!(Some(1), output);
+        assert_eq!(0, errors);
+        assert_eq!(
+            vec!["GRAPHQL_VALIDATION_FAILED/NoUnusedVariables"],
+            warnings
+        );
+    });
+}
+
+#[test]
+fn test_unknown_operation_name() {
+    with_pipeline(RuleRegistry::default(), |pipeline| {
+        let executable_document = parse("query Q { foo }");
+        let variables = Map::new();
+        let resolution_failed = pipeline.run(&executable_document, |run| {
+            matches!(
+                run.analyze::<_, QueryDepth>(Some("Other"), &variables, ()),
+                PipelineResult::OperationResolutionFailed {
+                    error: OperationResolutionError::NoOperationWithName { name: "Other" },
+                    ..
+                }
+            )
+        });
+
+        assert!(resolution_failed);
+    });
+}
+
+#[cfg(feature = "validation-cache")]
+#[test]
+fn test_validation_cache_hit_skips_validation() {
+    use bluejay_validator::executable::{
+        SchemaFingerprint, SharedValidationCache, ValidationCacheKey,
+    };
+    use std::{num::NonZeroUsize, sync::Arc};
+
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(SCHEMA)
+        .result
+        .expect("Schema had parse errors");
+    let schema_definition: SchemaDefinition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+    let validation_cache = Arc::new(SharedValidationCache::new(NonZeroUsize::new(8).unwrap()));
+    let pipeline =
+        Pipeline::new(&schema_definition).with_validation_cache(Arc::clone(&validation_cache));
+    let variables = Map::new();
+    let is_analyzed = |executable_document: &ExecutableDocument| {
+        pipeline.run(executable_document, |run| {
+            run.analyze::<_, QueryDepth>(None, &variables, ())
+                .output()
+                .is_some()
+        })
+    };
+
+    assert!(is_analyzed(&parse("{ foo }")));
+    assert_eq!(1, validation_cache.len());
+
+    // invalid documents are not cached
+    let invalid_document = parse("{ bar }");
+    assert!(!is_analyzed(&invalid_document));
+    assert_eq!(1, validation_cache.len());
+
+    // a cache hit skips validation entirely, so even an invalid document is analyzed
+    validation_cache.insert(
+        ValidationCacheKey::for_document(
+            SchemaFingerprint::new(&schema_definition),
+            &invalid_document,
+            None,
+        )
+        .with_rule_registry(&RuleRegistry::default()),
+        (),
+    );
+    assert!(is_analyzed(&invalid_document));
+}
+
+#[cfg(feature = "validation-cache")]
+#[test]
+fn test_validation_cache_is_keyed_by_rule_registry() {
+    use bluejay_validator::executable::SharedValidationCache;
+    use std::{num::NonZeroUsize, sync::Arc};
+
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(SCHEMA)
+        .result
+        .expect("Schema had parse errors");
+    let schema_definition: SchemaDefinition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+    let validation_cache = Arc::new(SharedValidationCache::new(NonZeroUsize::new(8).unwrap()));
+    let mut lenient_registry = RuleRegistry::default();
+    lenient_registry.disable("AllVariablesUsed").unwrap();
+    let lenient_pipeline = Pipeline::new(&schema_definition)
+        .with_rule_registry(lenient_registry)
+        .with_validation_cache(Arc::clone(&validation_cache));
+    let default_pipeline =
+        Pipeline::new(&schema_definition).with_validation_cache(Arc::clone(&validation_cache));
+    let executable_document = parse("query($unused: String) { foo }");
+    let variables = Map::new();
+    let error_codes = |pipeline: &Pipeline<SchemaDefinition>| {
+        pipeline.run(&executable_document, |run| {
+            run.analyze::<_, QueryDepth>(None, &variables, ())
+                .errors()
+                .iter()
+                .map(ErrorCode::code)
+                .collect::<Vec<_>>()
+        })
+    };
+
+    assert!(error_codes(&lenient_pipeline).is_empty());
+    assert_eq!(1, validation_cache.len());
+
+    // the entry recorded without `AllVariablesUsed` is not a hit for the default rules
+    assert_eq!(
+        vec!["GRAPHQL_VALIDATION_FAILED/NoUnusedVariables"],
+        error_codes(&default_pipeline),
+    );
+    assert_eq!(1, validation_cache.len());
+}
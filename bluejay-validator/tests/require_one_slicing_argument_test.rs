@@ -0,0 +1,108 @@
+use bluejay_core::ErrorCode;
+use bluejay_parser::{
+    ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    },
+    Error,
+};
+use bluejay_validator::executable::{
+    document::{rules::RequireOneSlicingArgument, Orchestrator},
+    Cache,
+};
+
+const SCHEMA: &str = r#"
+directive @listSize(
+  assumedSize: Int
+  slicingArguments: [String!]
+  sizedFields: [String!]
+  requireOneSlicingArgument: Boolean = true
+) on FIELD_DEFINITION
+
+type Book {
+  title: String
+}
+
+type Query {
+  books(first: Int, last: Int): [Book!]! @listSize(slicingArguments: ["first", "last"])
+  optionalBooks(first: Int, last: Int): [Book!]!
+    @listSize(slicingArguments: ["first", "last"], requireOneSlicingArgument: false)
+  featured(first: Int): [Book!]! @listSize(assumedSize: 3)
+}
+"#;
+
+type RequireOneSlicingArgumentValidator<'a, E, S> =
+    Orchestrator<'a, E, S, RequireOneSlicingArgument<'a, E, S>>;
+
+fn validate(document: &str) -> (Vec<&'static str>, String) {
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(SCHEMA)
+        .result
+        .expect("Schema had parse errors");
+    let schema_definition: SchemaDefinition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+    let executable_document = ExecutableDocument::parse(document)
+        .result
+        .expect("Document had parse errors");
+    let cache = Cache::new(&executable_document, &schema_definition);
+    let errors: Vec<_> = RequireOneSlicingArgumentValidator::validate(
+        &executable_document,
+        &schema_definition,
+        &cache,
+    )
+    .collect();
+    let codes = errors.iter().map(ErrorCode::code).collect();
+    let messages = Error::format_errors(document, None, errors);
+
+    (codes, messages)
+}
+
+#[test]
+fn test_one_slicing_argument() {
+    let (codes, messages) = validate(
+        r#"
+        query($n: Int) {
+          first: books(first: 1) { title }
+          last: books(last: 1, first: null) { title }
+          variable: books(first: $n) { title }
+          optionalBooks { title }
+          both: optionalBooks(first: 1, last: 1) { title }
+          featured { title }
+        }
+        "#,
+    );
+    assert!(
+        codes.is_empty(),
+        "Document had validation errors:\n{messages}"
+    );
+}
+
+#[test]
+fn test_no_slicing_argument() {
+    let (codes, messages) = validate("{ books { title } }");
+    assert_eq!(
+        vec!["GRAPHQL_VALIDATION_FAILED/RequireOneSlicingArgument"],
+        codes
+    );
+    assert!(
+        messages.contains(
+            "Field `books` must be passed exactly one of the slicing arguments `first`, `last`, but was passed 0"
+        ),
+        "{messages}"
+    );
+}
+
+#[test]
+fn test_several_slicing_arguments() {
+    let (codes, messages) = validate(
+        r#"
+        query { ...Books }
+        fragment Books on Query { books(first: 1, last: 2) { title } }
+        "#,
+    );
+    assert_eq!(
+        vec!["GRAPHQL_VALIDATION_FAILED/RequireOneSlicingArgument"],
+        codes
+    );
+    assert!(messages.contains("but was passed 2"), "{messages}");
+}
@@ -0,0 +1,153 @@
+use bluejay_core::ErrorCode;
+use bluejay_parser::ast::{
+    definition::{DefinitionDocument, SchemaDefinition},
+    executable::ExecutableDocument,
+    Parse,
+};
+use bluejay_validator::executable::{
+    document::{
+        BuiltinRulesValidator, RuleRegistry, Severity, UnknownRuleError, BUILTIN_RULE_NAMES,
+        OPT_IN_RULE_NAMES,
+    },
+    Cache,
+};
+
+const SCHEMA: &str = r#"
+type Query {
+  foo(arg: String): String!
+}
+"#;
+
+const DOCUMENT: &str = r#"
+query($unused: String) {
+  foo
+  bar
+}
+
+fragment Unused on Query {
+  foo
+}
+"#;
+
+fn validate_with(registry: &RuleRegistry) -> (Vec<&'static str>, Vec<&'static str>) {
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(SCHEMA)
+        .result
+        .expect("Schema had parse errors");
+    let schema_definition: SchemaDefinition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+    let executable_document = ExecutableDocument::parse(DOCUMENT)
+        .result
+        .expect("Document had parse errors");
+    let cache = Cache::new(&executable_document, &schema_definition);
+    let report = registry.validate(&executable_document, &schema_definition, &cache);
+    let codes = |errors: &[_]| errors.iter().map(ErrorCode::code).collect::<Vec<_>>();
+
+    if registry == &RuleRegistry::default() {
+        let builtin_errors: Vec<_> =
+            BuiltinRulesValidator::validate(&executable_document, &schema_definition, &cache)
+                .collect();
+        assert_eq!(codes(&builtin_errors), codes(&report.errors));
+    }
+
+    (codes(&report.errors), codes(&report.warnings))
+}
+
+#[test]
+fn test_default_registry_matches_builtin_rules() {
+    let (errors, warnings) = validate_with(&RuleRegistry::default());
+    assert_eq!(
+        errors,
+        vec![
+            "GRAPHQL_VALIDATION_FAILED/FieldsOnCorrectType",
+            "GRAPHQL_VALIDATION_FAILED/NoUnusedFragments",
+            "GRAPHQL_VALIDATION_FAILED/NoUnusedVariables",
+        ],
+    );
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_disabled_rules_are_not_run() {
+    let mut registry = RuleRegistry::default();
+    registry
+        .disable("FragmentsMustBeUsed")
+        .and_then(|registry| registry.disable("AllVariablesUsed"))
+        .unwrap();
+    let (errors, warnings) = validate_with(&registry);
+    assert_eq!(
+        errors,
+        vec!["GRAPHQL_VALIDATION_FAILED/FieldsOnCorrectType"],
+    );
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_warning_severity() {
+    let mut registry = RuleRegistry::default();
+    registry
+        .enable("FragmentsMustBeUsed", Severity::Warning)
+        .and_then(|registry| registry.enable("AllVariablesUsed", Severity::Warning))
+        .unwrap();
+    assert_eq!(
+        Some(Severity::Warning),
+        registry.severity("AllVariablesUsed")
+    );
+    let (errors, warnings) = validate_with(&registry);
+    assert_eq!(
+        errors,
+        vec!["GRAPHQL_VALIDATION_FAILED/FieldsOnCorrectType"],
+    );
+    assert_eq!(
+        warnings,
+        vec![
+            "GRAPHQL_VALIDATION_FAILED/NoUnusedFragments",
+            "GRAPHQL_VALIDATION_FAILED/NoUnusedVariables",
+        ],
+    );
+}
+
+#[test]
+fn test_empty_registry() {
+    let mut registry = RuleRegistry::empty();
+    assert_eq!(0, registry.enabled_rules().count());
+    let (errors, warnings) = validate_with(&registry);
+    assert!(errors.is_empty());
+    assert!(warnings.is_empty());
+
+    registry.enable("FieldSelections", Severity::Error).unwrap();
+    let (errors, _) = validate_with(&registry);
+    assert_eq!(
+        errors,
+        vec!["GRAPHQL_VALIDATION_FAILED/FieldsOnCorrectType"],
+    );
+}
+
+#[test]
+fn test_unknown_rule() {
+    let mut registry = RuleRegistry::default();
+    assert_eq!(
+        Err(UnknownRuleError { name: "NoSuchRule" }),
+        registry.disable("NoSuchRule").map(|_| ()),
+    );
+    assert_eq!(None, registry.severity("NoSuchRule"));
+}
+
+#[test]
+fn test_opt_in_rules_are_disabled_by_default() {
+    let mut registry = RuleRegistry::default();
+    assert_eq!(BUILTIN_RULE_NAMES.len(), registry.enabled_rules().count());
+    for name in OPT_IN_RULE_NAMES {
+        assert_eq!(None, registry.severity(name));
+    }
+
+    registry
+        .enable("RequireOneSlicingArgument", Severity::Warning)
+        .unwrap();
+    assert_eq!(
+        Some(("RequireOneSlicingArgument", Severity::Warning)),
+        registry.enabled_rules().last(),
+    );
+    // the schema has no `@listSize` directives, so the rule reports nothing
+    let (_, warnings) = validate_with(&registry);
+    assert!(warnings.is_empty());
+}